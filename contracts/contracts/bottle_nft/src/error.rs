@@ -0,0 +1,15 @@
+use soroban_sdk::{self, contracterror};
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum BottleNftError {
+    InvalidBottleNumber = 1,
+    BottleAlreadyMinted = 2,
+    BottleNotFound = 3,
+    NotOwner = 4,
+    QrCodeAlreadyBound = 5,
+    QrCodeNotFound = 6,
+    QrCodeInactive = 7,
+    BottleHasActiveQrCode = 8,
+}
@@ -0,0 +1,301 @@
+#![no_std]
+
+use common::models::BottleStatus;
+use soroban_sdk::{contract, contractimpl, Address, BytesN, Env, Vec};
+
+mod error;
+pub mod models;
+mod storage;
+mod test;
+mod wine_token;
+
+use models::{Bottle, BottleInfo, QrCode};
+use storage::{
+    decrement_balance, extend_instance_ttl, has_bottle, increment_balance,
+    increment_total_minted, read_administrator, read_balance, read_bottle, read_bottle_qr_code,
+    read_lot_token, read_qr_code, read_total_minted, write_administrator, write_bottle,
+    write_bottle_qr_code, write_lot_token, write_qr_code,
+};
+use wine_token::WineTokenClient;
+
+fn check_bottle_number(e: &Env, bottle_number: u32) -> Result<(), BottleNftError> {
+    let lot_token = WineTokenClient::new(e, &read_lot_token(e));
+    let bottle_count = lot_token.get_wine_lot_metadata().bottle_count;
+    if bottle_number == 0 || bottle_number > bottle_count {
+        return Err(BottleNftError::InvalidBottleNumber);
+    }
+    Ok(())
+}
+
+// A bottle has at most one active QR code: a new one can only be registered
+// once the previous one has been deactivated
+fn register_qr_code(
+    e: &Env,
+    bottle_number: u32,
+    qr_code_hash: BytesN<32>,
+) -> Result<(), BottleNftError> {
+    if read_qr_code(e, qr_code_hash.clone()).is_some() {
+        return Err(BottleNftError::QrCodeAlreadyBound);
+    }
+    if let Some(current) = read_bottle_qr_code(e, bottle_number) {
+        if read_qr_code(e, current).is_some_and(|qr_code| qr_code.active) {
+            return Err(BottleNftError::BottleHasActiveQrCode);
+        }
+    }
+    if let Ok(mut bottle) = read_bottle(e, bottle_number) {
+        bottle.qr_code_hash = qr_code_hash.clone();
+        write_bottle(e, &bottle);
+    }
+    write_bottle_qr_code(e, bottle_number, &qr_code_hash);
+    write_qr_code(
+        e,
+        qr_code_hash.clone(),
+        &QrCode {
+            bottle_number,
+            active: true,
+        },
+    );
+    e.events()
+        .publish(("qr_registered", bottle_number), qr_code_hash);
+    Ok(())
+}
+
+pub use error::BottleNftError;
+
+#[contract]
+pub struct BottleCollection;
+
+#[contractimpl]
+impl BottleCollection {
+    /// Initialize a bottle collection for a wine lot
+    ///
+    /// # Arguments
+    /// * `admin` - Administrator address (typically the winery)
+    /// * `lot_token` - Address of the parent `WineToken`
+    pub fn __constructor(e: Env, admin: Address, lot_token: Address) {
+        write_administrator(&e, &admin);
+        write_lot_token(&e, &lot_token);
+        extend_instance_ttl(&e);
+    }
+
+    /// Mint the NFT for a single bottle (admin/winery and recipient must sign)
+    ///
+    /// Burns one whole unit of the parent lot token from `to`, so every bottle NFT
+    /// replaces exactly one bottle's worth of lot tokens. The QR code is registered
+    /// for the bottle if it was not registered beforehand.
+    ///
+    /// # Arguments
+    /// * `to` - Holder of the lot tokens receiving the bottle
+    /// * `bottle_number` - Bottle number, from 1 up to the lot's `bottle_count`
+    /// * `qr_code_hash` - Hash of the QR code printed on the bottle
+    ///
+    /// # Errors
+    /// * `BottleNftError::InvalidBottleNumber` - If `bottle_number` is outside the lot
+    /// * `BottleNftError::BottleAlreadyMinted` - If the bottle already has an NFT
+    /// * `BottleNftError::QrCodeAlreadyBound` - If the QR code is bound to another bottle
+    /// * `BottleNftError::QrCodeInactive` - If the QR code has been deactivated
+    /// * `BottleNftError::BottleHasActiveQrCode` - If the bottle already has another active QR code
+    pub fn mint(
+        e: Env,
+        to: Address,
+        bottle_number: u32,
+        qr_code_hash: BytesN<32>,
+    ) -> Result<(), BottleNftError> {
+        let admin = read_administrator(&e);
+        admin.require_auth();
+        to.require_auth();
+        extend_instance_ttl(&e);
+
+        check_bottle_number(&e, bottle_number)?;
+        if has_bottle(&e, bottle_number) {
+            return Err(BottleNftError::BottleAlreadyMinted);
+        }
+        match read_qr_code(&e, qr_code_hash.clone()) {
+            Some(qr_code) if qr_code.bottle_number != bottle_number => {
+                return Err(BottleNftError::QrCodeAlreadyBound)
+            }
+            Some(qr_code) if !qr_code.active => return Err(BottleNftError::QrCodeInactive),
+            Some(_) => {}
+            None => register_qr_code(&e, bottle_number, qr_code_hash.clone())?,
+        }
+
+        let lot_token = WineTokenClient::new(&e, &read_lot_token(&e));
+        lot_token.burn(&to, &10i128.pow(lot_token.decimals()));
+
+        write_bottle(
+            &e,
+            &Bottle {
+                bottle_number,
+                owner: to.clone(),
+                qr_code_hash: qr_code_hash.clone(),
+                status: BottleStatus::Bottled,
+            },
+        );
+        increment_balance(&e, to.clone());
+        increment_total_minted(&e);
+
+        e.events()
+            .publish(("mint", bottle_number), (to, qr_code_hash));
+        Ok(())
+    }
+
+    /// Transfer a bottle to a new owner (current owner must sign)
+    ///
+    /// # Errors
+    /// * `BottleNftError::BottleNotFound` - If the bottle has not been minted
+    /// * `BottleNftError::NotOwner` - If `from` does not own the bottle
+    pub fn transfer(
+        e: Env,
+        from: Address,
+        to: Address,
+        bottle_number: u32,
+    ) -> Result<(), BottleNftError> {
+        from.require_auth();
+        extend_instance_ttl(&e);
+
+        let mut bottle = read_bottle(&e, bottle_number)?;
+        if bottle.owner != from {
+            return Err(BottleNftError::NotOwner);
+        }
+
+        bottle.owner = to.clone();
+        write_bottle(&e, &bottle);
+        decrement_balance(&e, from.clone());
+        increment_balance(&e, to.clone());
+
+        e.events()
+            .publish(("transfer", bottle_number), (from, to));
+        Ok(())
+    }
+
+    /// Update the status of a single bottle (only admin/winery can call)
+    ///
+    /// # Errors
+    /// * `BottleNftError::BottleNotFound` - If the bottle has not been minted
+    pub fn set_bottle_status(
+        e: Env,
+        bottle_number: u32,
+        status: BottleStatus,
+    ) -> Result<(), BottleNftError> {
+        let admin = read_administrator(&e);
+        admin.require_auth();
+        extend_instance_ttl(&e);
+
+        let mut bottle = read_bottle(&e, bottle_number)?;
+        let previous_status = bottle.status;
+        bottle.status = status;
+        write_bottle(&e, &bottle);
+
+        e.events()
+            .publish(("status_update", bottle_number), (status, previous_status));
+        Ok(())
+    }
+
+    /// Get a bottle by number
+    pub fn get_bottle(e: Env, bottle_number: u32) -> Result<Bottle, BottleNftError> {
+        extend_instance_ttl(&e);
+        read_bottle(&e, bottle_number)
+    }
+
+    /// Get the owner of a bottle
+    pub fn owner_of(e: Env, bottle_number: u32) -> Result<Address, BottleNftError> {
+        extend_instance_ttl(&e);
+        Ok(read_bottle(&e, bottle_number)?.owner)
+    }
+
+    /// Get the number of bottles held by an address
+    pub fn balance(e: Env, owner: Address) -> u32 {
+        extend_instance_ttl(&e);
+        read_balance(&e, owner)
+    }
+
+    /// Register QR code hashes for bottles of the lot (only admin/winery can call)
+    ///
+    /// A bottle's previous QR code must be deactivated before a new one is
+    /// registered for it, so each bottle has at most one active code.
+    ///
+    /// # Arguments
+    /// * `qr_codes` - Pairs of bottle number and QR code hash
+    ///
+    /// # Errors
+    /// * `BottleNftError::InvalidBottleNumber` - If a bottle number is outside the lot
+    /// * `BottleNftError::QrCodeAlreadyBound` - If a QR code hash is already registered
+    /// * `BottleNftError::BottleHasActiveQrCode` - If a bottle's current QR code is still active
+    pub fn register_qr_codes(
+        e: Env,
+        qr_codes: Vec<(u32, BytesN<32>)>,
+    ) -> Result<(), BottleNftError> {
+        let admin = read_administrator(&e);
+        admin.require_auth();
+        extend_instance_ttl(&e);
+
+        for (bottle_number, qr_code_hash) in qr_codes.iter() {
+            check_bottle_number(&e, bottle_number)?;
+            register_qr_code(&e, bottle_number, qr_code_hash)?;
+        }
+        Ok(())
+    }
+
+    /// Deactivate a compromised QR code (only admin/winery can call)
+    ///
+    /// # Errors
+    /// * `BottleNftError::QrCodeNotFound` - If the QR code hash is not registered
+    pub fn deactivate_qr_code(e: Env, qr_code_hash: BytesN<32>) -> Result<(), BottleNftError> {
+        let admin = read_administrator(&e);
+        admin.require_auth();
+        extend_instance_ttl(&e);
+
+        let mut qr_code =
+            read_qr_code(&e, qr_code_hash.clone()).ok_or(BottleNftError::QrCodeNotFound)?;
+        qr_code.active = false;
+        write_qr_code(&e, qr_code_hash.clone(), &qr_code);
+
+        e.events()
+            .publish(("qr_deactivated", qr_code.bottle_number), qr_code_hash);
+        Ok(())
+    }
+
+    /// Verify a scanned QR code against the chain
+    ///
+    /// Returns `None` for unknown codes. Bottles without a minted NFT report the
+    /// `Bottled` status.
+    pub fn verify_qr(e: Env, qr_code_hash: BytesN<32>) -> Option<BottleInfo> {
+        extend_instance_ttl(&e);
+
+        let qr_code = read_qr_code(&e, qr_code_hash)?;
+        let status = read_bottle(&e, qr_code.bottle_number)
+            .map(|bottle| bottle.status)
+            .unwrap_or(BottleStatus::Bottled);
+
+        Some(BottleInfo {
+            lot_token: read_lot_token(&e),
+            bottle_number: qr_code.bottle_number,
+            status,
+            active: qr_code.active,
+        })
+    }
+
+    /// Get the latest QR code registered for a bottle, active or not
+    pub fn bottle_qr_code(e: Env, bottle_number: u32) -> Option<BytesN<32>> {
+        extend_instance_ttl(&e);
+        read_bottle_qr_code(&e, bottle_number)
+    }
+
+    /// Get the number of minted bottles
+    pub fn total_minted(e: Env) -> u32 {
+        extend_instance_ttl(&e);
+        read_total_minted(&e)
+    }
+
+    /// Get the parent wine lot token
+    pub fn lot_token(e: Env) -> Address {
+        extend_instance_ttl(&e);
+        read_lot_token(&e)
+    }
+
+    /// Get current admin
+    pub fn admin(e: Env) -> Address {
+        extend_instance_ttl(&e);
+        read_administrator(&e)
+    }
+}
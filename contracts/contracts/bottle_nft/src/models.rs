@@ -0,0 +1,30 @@
+use common::models::BottleStatus;
+use soroban_sdk::{contracttype, Address, BytesN};
+
+// Individual Bottle
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Bottle {
+    pub bottle_number: u32,
+    pub owner: Address,
+    pub qr_code_hash: BytesN<32>,
+    pub status: BottleStatus,
+}
+
+// QR Code Registration
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct QrCode {
+    pub bottle_number: u32,
+    pub active: bool,
+}
+
+// Result of verifying a scanned QR code
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BottleInfo {
+    pub lot_token: Address,
+    pub bottle_number: u32,
+    pub status: BottleStatus,
+    pub active: bool,
+}
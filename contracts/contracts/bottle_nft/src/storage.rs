@@ -0,0 +1,145 @@
+use soroban_sdk::{contracttype, Address, BytesN, Env};
+
+use crate::error::BottleNftError;
+use crate::models::{Bottle, QrCode};
+
+pub(crate) const DAY_IN_LEDGERS: u32 = 17280;
+pub(crate) const INSTANCE_BUMP_AMOUNT: u32 = 30 * DAY_IN_LEDGERS;
+pub(crate) const INSTANCE_LIFETIME_THRESHOLD: u32 = INSTANCE_BUMP_AMOUNT - DAY_IN_LEDGERS;
+pub(crate) const PERSISTENT_BUMP_AMOUNT: u32 = 120 * DAY_IN_LEDGERS;
+pub(crate) const PERSISTENT_LIFETIME_THRESHOLD: u32 = PERSISTENT_BUMP_AMOUNT - 20 * DAY_IN_LEDGERS;
+
+#[derive(Clone)]
+#[contracttype]
+pub enum DataKey {
+    Admin,
+    LotToken,
+    TotalMinted,
+    Bottle(u32),
+    Balance(Address),
+    QrCode(BytesN<32>),
+    BottleQrCode(u32),
+}
+
+pub fn extend_instance_ttl(e: &Env) {
+    e.storage()
+        .instance()
+        .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+}
+
+fn extend_persistent_ttl(e: &Env, key: &DataKey) {
+    e.storage()
+        .persistent()
+        .extend_ttl(key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+}
+
+// Admin
+pub fn read_administrator(e: &Env) -> Address {
+    e.storage().instance().get(&DataKey::Admin).unwrap()
+}
+
+pub fn write_administrator(e: &Env, admin: &Address) {
+    e.storage().instance().set(&DataKey::Admin, admin);
+}
+
+// Parent lot token
+pub fn read_lot_token(e: &Env) -> Address {
+    e.storage().instance().get(&DataKey::LotToken).unwrap()
+}
+
+pub fn write_lot_token(e: &Env, lot_token: &Address) {
+    e.storage().instance().set(&DataKey::LotToken, lot_token);
+}
+
+// Total minted bottles
+pub fn read_total_minted(e: &Env) -> u32 {
+    e.storage().instance().get(&DataKey::TotalMinted).unwrap_or(0)
+}
+
+pub fn increment_total_minted(e: &Env) {
+    let total = read_total_minted(e);
+    e.storage()
+        .instance()
+        .set(&DataKey::TotalMinted, &total.checked_add(1).unwrap());
+}
+
+// Bottles
+pub fn has_bottle(e: &Env, bottle_number: u32) -> bool {
+    e.storage().persistent().has(&DataKey::Bottle(bottle_number))
+}
+
+pub fn read_bottle(e: &Env, bottle_number: u32) -> Result<Bottle, BottleNftError> {
+    let key = DataKey::Bottle(bottle_number);
+    let bottle = e
+        .storage()
+        .persistent()
+        .get::<DataKey, Bottle>(&key)
+        .ok_or(BottleNftError::BottleNotFound)?;
+    extend_persistent_ttl(e, &key);
+    Ok(bottle)
+}
+
+pub fn write_bottle(e: &Env, bottle: &Bottle) {
+    let key = DataKey::Bottle(bottle.bottle_number);
+    e.storage().persistent().set(&key, bottle);
+    extend_persistent_ttl(e, &key);
+}
+
+// Balances (number of bottles held)
+pub fn read_balance(e: &Env, owner: Address) -> u32 {
+    let key = DataKey::Balance(owner);
+    if let Some(balance) = e.storage().persistent().get::<DataKey, u32>(&key) {
+        extend_persistent_ttl(e, &key);
+        balance
+    } else {
+        0
+    }
+}
+
+fn write_balance(e: &Env, owner: Address, balance: u32) {
+    let key = DataKey::Balance(owner);
+    e.storage().persistent().set(&key, &balance);
+    extend_persistent_ttl(e, &key);
+}
+
+pub fn increment_balance(e: &Env, owner: Address) {
+    let balance = read_balance(e, owner.clone());
+    write_balance(e, owner, balance.checked_add(1).unwrap());
+}
+
+pub fn decrement_balance(e: &Env, owner: Address) {
+    let balance = read_balance(e, owner.clone());
+    write_balance(e, owner, balance.checked_sub(1).unwrap());
+}
+
+// QR code registry
+pub fn read_qr_code(e: &Env, qr_code_hash: BytesN<32>) -> Option<QrCode> {
+    let key = DataKey::QrCode(qr_code_hash);
+    let qr_code = e.storage().persistent().get::<DataKey, QrCode>(&key);
+    if qr_code.is_some() {
+        extend_persistent_ttl(e, &key);
+    }
+    qr_code
+}
+
+pub fn write_qr_code(e: &Env, qr_code_hash: BytesN<32>, qr_code: &QrCode) {
+    let key = DataKey::QrCode(qr_code_hash);
+    e.storage().persistent().set(&key, qr_code);
+    extend_persistent_ttl(e, &key);
+}
+
+// Latest QR code registered for each bottle
+pub fn read_bottle_qr_code(e: &Env, bottle_number: u32) -> Option<BytesN<32>> {
+    let key = DataKey::BottleQrCode(bottle_number);
+    let qr_code_hash = e.storage().persistent().get::<DataKey, BytesN<32>>(&key);
+    if qr_code_hash.is_some() {
+        extend_persistent_ttl(e, &key);
+    }
+    qr_code_hash
+}
+
+pub fn write_bottle_qr_code(e: &Env, bottle_number: u32, qr_code_hash: &BytesN<32>) {
+    let key = DataKey::BottleQrCode(bottle_number);
+    e.storage().persistent().set(&key, qr_code_hash);
+    extend_persistent_ttl(e, &key);
+}
@@ -0,0 +1,89 @@
+#![cfg(test)]
+extern crate std;
+use common::models::WineLotMetadata;
+use soroban_sdk::{testutils::Address as _, Address, BytesN, Env, String};
+
+// Wine Token Contract, built with `make build` (see the Makefile)
+pub(crate) mod wine_token_contract {
+    soroban_sdk::contractimport!(file = "../../target/wasm32v1-none/release/wine_token.wasm");
+}
+use wine_token_contract::Client as WineTokenClient;
+
+use crate::{BottleCollection, BottleCollectionClient};
+
+pub(crate) fn create_wine_lot_metadata(e: &Env, bottle_count: u32) -> WineLotMetadata {
+    WineLotMetadata {
+        lot_id: String::from_str(e, "MAL-2024-001"),
+        winery_name: String::from_str(e, "Bodega Catena"),
+        region: String::from_str(e, "Mendoza"),
+        country: String::from_str(e, "Argentina"),
+        vintage: 2024,
+        varietal: String::from_str(e, "Malbec"),
+        bottle_count,
+        description: None,
+        token_code: String::from_str(e, "MAL24"),
+    }
+}
+
+pub(crate) fn create_wine_token<'a>(
+    e: &Env,
+    admin: &Address,
+    decimal: u32,
+    bottle_count: u32,
+) -> WineTokenClient<'a> {
+    let args = (
+        admin.clone(),
+        decimal,
+        String::from_str(e, "Malbec Reserve 2024"),
+        String::from_str(e, "MAL24"),
+        create_wine_lot_metadata(e, bottle_count),
+        None::<Address>,
+    );
+    WineTokenClient::new(e, &e.register(wine_token_contract::WASM, args))
+}
+
+pub(crate) fn create_bottle_collection<'a>(
+    e: &Env,
+    admin: &Address,
+    lot_token: &Address,
+) -> BottleCollectionClient<'a> {
+    let args = (admin.clone(), lot_token.clone());
+    BottleCollectionClient::new(e, &e.register(BottleCollection, args))
+}
+
+pub(crate) fn qr_hash(e: &Env, seed: u8) -> BytesN<32> {
+    BytesN::from_array(e, &[seed; 32])
+}
+
+pub struct BottleNftTest<'a> {
+    env: Env,
+    lot_token: WineTokenClient<'a>,
+    collection: BottleCollectionClient<'a>,
+    user: Address,
+    receiver: Address,
+}
+
+impl<'a> BottleNftTest<'a> {
+    fn setup() -> Self {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let admin = Address::generate(&env);
+        let lot_token = create_wine_token(&env, &admin, 7, 10);
+        let collection = create_bottle_collection(&env, &admin, &lot_token.address);
+
+        let user = Address::generate(&env);
+        let receiver = Address::generate(&env);
+        lot_token.mint(&admin, &user, &(3 * 10_000_000));
+
+        BottleNftTest {
+            env,
+            lot_token,
+            collection,
+            user,
+            receiver,
+        }
+    }
+}
+
+mod bottle_nft;
@@ -0,0 +1,79 @@
+use common::models::BottleStatus;
+
+use crate::test::{qr_hash, BottleNftTest};
+use crate::BottleNftError;
+
+#[test]
+fn mint_burns_one_lot_unit() {
+    let test = BottleNftTest::setup();
+
+    test.collection.mint(&test.user, &1, &qr_hash(&test.env, 1));
+
+    assert_eq!(test.lot_token.balance(&test.user), 2 * 10_000_000);
+    assert_eq!(test.lot_token.total_supply(), 2 * 10_000_000);
+    assert_eq!(test.collection.owner_of(&1), test.user);
+    assert_eq!(test.collection.balance(&test.user), 1);
+    assert_eq!(test.collection.total_minted(), 1);
+    assert_eq!(
+        test.collection
+            .verify_qr(&qr_hash(&test.env, 1))
+            .unwrap()
+            .bottle_number,
+        1
+    );
+
+    let bottle = test.collection.get_bottle(&1);
+    assert_eq!(bottle.qr_code_hash, qr_hash(&test.env, 1));
+    assert_eq!(bottle.status, BottleStatus::Bottled);
+}
+
+#[test]
+fn bottle_number_must_be_in_lot() {
+    let test = BottleNftTest::setup();
+
+    let result = test.collection.try_mint(&test.user, &0, &qr_hash(&test.env, 1));
+    assert_eq!(result, Err(Ok(BottleNftError::InvalidBottleNumber)));
+
+    let result = test.collection.try_mint(&test.user, &11, &qr_hash(&test.env, 1));
+    assert_eq!(result, Err(Ok(BottleNftError::InvalidBottleNumber)));
+
+    test.collection.mint(&test.user, &10, &qr_hash(&test.env, 1));
+}
+
+#[test]
+fn bottle_and_qr_code_are_unique() {
+    let test = BottleNftTest::setup();
+
+    test.collection.mint(&test.user, &1, &qr_hash(&test.env, 1));
+
+    let result = test.collection.try_mint(&test.user, &1, &qr_hash(&test.env, 2));
+    assert_eq!(result, Err(Ok(BottleNftError::BottleAlreadyMinted)));
+
+    let result = test.collection.try_mint(&test.user, &2, &qr_hash(&test.env, 1));
+    assert_eq!(result, Err(Ok(BottleNftError::QrCodeAlreadyBound)));
+}
+
+#[test]
+// The lot token fails with InsufficientBalance (#111)
+#[should_panic(expected = "Error(Contract, #111)")]
+fn mint_requires_lot_tokens() {
+    let test = BottleNftTest::setup();
+
+    test.collection
+        .mint(&test.receiver, &1, &qr_hash(&test.env, 1));
+}
+
+#[test]
+fn set_bottle_status() {
+    let test = BottleNftTest::setup();
+
+    test.collection.mint(&test.user, &1, &qr_hash(&test.env, 1));
+    test.collection.set_bottle_status(&1, &BottleStatus::Shipped);
+
+    assert_eq!(test.collection.get_bottle(&1).status, BottleStatus::Shipped);
+
+    let result = test
+        .collection
+        .try_set_bottle_status(&2, &BottleStatus::Shipped);
+    assert_eq!(result, Err(Ok(BottleNftError::BottleNotFound)));
+}
@@ -0,0 +1,3 @@
+mod mint;
+mod qr_codes;
+mod transfer;
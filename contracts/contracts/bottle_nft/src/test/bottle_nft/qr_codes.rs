@@ -0,0 +1,119 @@
+use common::models::BottleStatus;
+use soroban_sdk::vec;
+
+use crate::test::{qr_hash, BottleNftTest};
+use crate::BottleNftError;
+
+#[test]
+fn register_and_verify() {
+    let test = BottleNftTest::setup();
+
+    test.collection.register_qr_codes(&vec![
+        &test.env,
+        (1u32, qr_hash(&test.env, 1)),
+        (2u32, qr_hash(&test.env, 2)),
+    ]);
+
+    let info = test.collection.verify_qr(&qr_hash(&test.env, 2)).unwrap();
+    assert_eq!(info.lot_token, test.lot_token.address);
+    assert_eq!(info.bottle_number, 2);
+    assert_eq!(info.status, BottleStatus::Bottled);
+    assert!(info.active);
+
+    assert_eq!(test.collection.verify_qr(&qr_hash(&test.env, 3)), None);
+}
+
+#[test]
+fn verify_reports_bottle_status() {
+    let test = BottleNftTest::setup();
+
+    test.collection
+        .register_qr_codes(&vec![&test.env, (1u32, qr_hash(&test.env, 1))]);
+    test.collection.mint(&test.user, &1, &qr_hash(&test.env, 1));
+    test.collection.set_bottle_status(&1, &BottleStatus::Delivered);
+
+    let info = test.collection.verify_qr(&qr_hash(&test.env, 1)).unwrap();
+    assert_eq!(info.status, BottleStatus::Delivered);
+}
+
+#[test]
+fn register_rejects_invalid_entries() {
+    let test = BottleNftTest::setup();
+
+    let result = test
+        .collection
+        .try_register_qr_codes(&vec![&test.env, (11u32, qr_hash(&test.env, 1))]);
+    assert_eq!(result, Err(Ok(BottleNftError::InvalidBottleNumber)));
+
+    test.collection
+        .register_qr_codes(&vec![&test.env, (1u32, qr_hash(&test.env, 1))]);
+    let result = test
+        .collection
+        .try_register_qr_codes(&vec![&test.env, (2u32, qr_hash(&test.env, 1))]);
+    assert_eq!(result, Err(Ok(BottleNftError::QrCodeAlreadyBound)));
+}
+
+#[test]
+fn deactivated_code_is_flagged() {
+    let test = BottleNftTest::setup();
+
+    test.collection
+        .register_qr_codes(&vec![&test.env, (1u32, qr_hash(&test.env, 1))]);
+    test.collection.deactivate_qr_code(&qr_hash(&test.env, 1));
+
+    let info = test.collection.verify_qr(&qr_hash(&test.env, 1)).unwrap();
+    assert!(!info.active);
+
+    let result = test.collection.try_mint(&test.user, &1, &qr_hash(&test.env, 1));
+    assert_eq!(result, Err(Ok(BottleNftError::QrCodeInactive)));
+
+    let result = test.collection.try_deactivate_qr_code(&qr_hash(&test.env, 9));
+    assert_eq!(result, Err(Ok(BottleNftError::QrCodeNotFound)));
+}
+
+#[test]
+fn bottle_can_be_relabelled() {
+    let test = BottleNftTest::setup();
+
+    test.collection
+        .register_qr_codes(&vec![&test.env, (1u32, qr_hash(&test.env, 1))]);
+    test.collection.deactivate_qr_code(&qr_hash(&test.env, 1));
+    test.collection
+        .register_qr_codes(&vec![&test.env, (1u32, qr_hash(&test.env, 2))]);
+
+    test.collection.mint(&test.user, &1, &qr_hash(&test.env, 2));
+    assert_eq!(test.collection.get_bottle(&1).qr_code_hash, qr_hash(&test.env, 2));
+    assert_eq!(test.collection.bottle_qr_code(&1), Some(qr_hash(&test.env, 2)));
+}
+
+#[test]
+fn bottle_has_one_active_code() {
+    let test = BottleNftTest::setup();
+
+    test.collection
+        .register_qr_codes(&vec![&test.env, (1u32, qr_hash(&test.env, 1))]);
+    let result = test
+        .collection
+        .try_register_qr_codes(&vec![&test.env, (1u32, qr_hash(&test.env, 2))]);
+    assert_eq!(result, Err(Ok(BottleNftError::BottleHasActiveQrCode)));
+
+    let result = test.collection.try_mint(&test.user, &1, &qr_hash(&test.env, 2));
+    assert_eq!(result, Err(Ok(BottleNftError::BottleHasActiveQrCode)));
+
+    assert!(test.collection.verify_qr(&qr_hash(&test.env, 1)).unwrap().active);
+    assert_eq!(test.collection.verify_qr(&qr_hash(&test.env, 2)), None);
+}
+
+#[test]
+fn relabelling_minted_bottle_retires_old_code() {
+    let test = BottleNftTest::setup();
+
+    test.collection.mint(&test.user, &1, &qr_hash(&test.env, 1));
+    test.collection.deactivate_qr_code(&qr_hash(&test.env, 1));
+    test.collection
+        .register_qr_codes(&vec![&test.env, (1u32, qr_hash(&test.env, 2))]);
+
+    assert!(!test.collection.verify_qr(&qr_hash(&test.env, 1)).unwrap().active);
+    assert!(test.collection.verify_qr(&qr_hash(&test.env, 2)).unwrap().active);
+    assert_eq!(test.collection.get_bottle(&1).qr_code_hash, qr_hash(&test.env, 2));
+}
@@ -0,0 +1,31 @@
+use crate::test::{qr_hash, BottleNftTest};
+use crate::BottleNftError;
+
+#[test]
+fn transfer_moves_ownership() {
+    let test = BottleNftTest::setup();
+
+    test.collection.mint(&test.user, &1, &qr_hash(&test.env, 1));
+    test.collection.transfer(&test.user, &test.receiver, &1);
+
+    assert_eq!(test.collection.owner_of(&1), test.receiver);
+    assert_eq!(test.collection.balance(&test.user), 0);
+    assert_eq!(test.collection.balance(&test.receiver), 1);
+}
+
+#[test]
+fn only_owner_can_transfer() {
+    let test = BottleNftTest::setup();
+
+    test.collection.mint(&test.user, &1, &qr_hash(&test.env, 1));
+
+    let result = test
+        .collection
+        .try_transfer(&test.receiver, &test.user, &1);
+    assert_eq!(result, Err(Ok(BottleNftError::NotOwner)));
+
+    let result = test
+        .collection
+        .try_transfer(&test.user, &test.receiver, &2);
+    assert_eq!(result, Err(Ok(BottleNftError::BottleNotFound)));
+}
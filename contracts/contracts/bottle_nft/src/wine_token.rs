@@ -0,0 +1,11 @@
+use common::models::WineLotMetadata;
+use soroban_sdk::{contractclient, Address, Env};
+
+/// Subset of the `WineToken` interface used by the bottle collection.
+#[allow(dead_code)]
+#[contractclient(name = "WineTokenClient")]
+pub trait WineTokenInterface {
+    fn get_wine_lot_metadata(e: Env) -> WineLotMetadata;
+    fn decimals(e: Env) -> u32;
+    fn burn(e: Env, from: Address, amount: i128);
+}
@@ -1,4 +1,4 @@
-use soroban_sdk::{contracttype, Address, String, Vec};
+use soroban_sdk::{contracterror, contracttype, Address, BytesN, String, Vec};
 
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -28,4 +28,132 @@ pub struct WineLotMetadata {
     pub bottle_count: u32,
     pub description: Option<String>,
     pub token_code: String,
-}
\ No newline at end of file
+}
+
+/// Current layout version of `WineLotDetails`.
+pub const WINE_LOT_DETAILS_VERSION: u32 = 1;
+/// Blend shares are expressed in basis points and must add up to 100%.
+pub const BLEND_TOTAL_BPS: u32 = 10_000;
+/// Highest accepted alcohol by volume, in basis points (25%).
+pub const MAX_ABV_BPS: u32 = 2_500;
+
+// Grape Varietal Share in a Blend
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BlendComponent {
+    pub varietal: String,
+    pub bps: u32,
+}
+
+// Extended Wine Lot Details, companion to WineLotMetadata
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct WineLotDetails {
+    pub version: u32,                  // layout version, see WINE_LOT_DETAILS_VERSION
+    pub appellation: Option<String>,   // appellation / DOC
+    pub abv_bps: u32,                  // alcohol by volume, 1350 = 13.5%
+    pub blend: Vec<BlendComponent>,
+    pub barrel_regime: Option<String>,
+    pub lab_analysis_hash: Option<BytesN<32>>,
+    pub certificate_hashes: Vec<BytesN<32>>,
+}
+
+// Wine Lot Details Validation Errors, shared by every contract storing WineLotDetails.
+// The codes match the WineTokenError variants of the same name.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum WineLotDetailsError {
+    UnsupportedVersion = 153,
+    InvalidAbv = 154,
+    InvalidBlend = 155,
+}
+
+impl WineLotDetails {
+    /// Checks the layout version, the ABV range and that the blend shares are
+    /// all non-zero and add up to `BLEND_TOTAL_BPS`.
+    pub fn validate(&self) -> Result<(), WineLotDetailsError> {
+        if self.version != WINE_LOT_DETAILS_VERSION {
+            return Err(WineLotDetailsError::UnsupportedVersion);
+        }
+        if self.abv_bps == 0 || self.abv_bps > MAX_ABV_BPS {
+            return Err(WineLotDetailsError::InvalidAbv);
+        }
+
+        let mut total: u32 = 0;
+        for component in self.blend.iter() {
+            if component.bps == 0 {
+                return Err(WineLotDetailsError::InvalidBlend);
+            }
+            total = total
+                .checked_add(component.bps)
+                .ok_or(WineLotDetailsError::InvalidBlend)?;
+        }
+        if total != BLEND_TOTAL_BPS {
+            return Err(WineLotDetailsError::InvalidBlend);
+        }
+        Ok(())
+    }
+}
+
+// Wine Lot Lifecycle Status
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum LotStatus {
+    Harvested,
+    Fermented,
+    Aged,
+    Bottled,
+    Shipped,
+    Available,
+    SoldOut,
+    Recalled,
+}
+
+// Wine Lot Status History Entry
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StatusRecord {
+    pub status: LotStatus,
+    pub location: Option<String>,
+    pub handler: Address,
+    pub handler_key: Option<BytesN<32>>, // ed25519 key, for signed off-chain updates
+    pub timestamp: u64,
+    pub sequence: u32, // ledger sequence
+}
+
+// Individual Bottle Status
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum BottleStatus {
+    Bottled,
+    InWarehouse,
+    Shipped,
+    InTransit,
+    Delivered,
+    Scanned,
+    Consumed,
+    Lost,
+    Damaged,
+}
+
+// Pending Two-Step Admin Transfer
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PendingAdmin {
+    pub new_admin: Address,
+    pub unlock_ledger: u32,
+}
+
+// Wine Token Access Control Role
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Role {
+    Admin,          // Token administrator, holds every role
+    Minter,         // Can mint lot tokens
+    StatusUpdater,  // Warehouse/logistics partners updating the lot status
+    Certifier,      // Third parties attesting to the lot
+    MetadataEditor, // Winery staff correcting the lot metadata
+    Sensor,         // Cellar sensors reporting storage conditions
+    Upgrader,       // Factory the winery lets upgrade the token code
+}
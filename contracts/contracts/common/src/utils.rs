@@ -16,6 +16,6 @@ impl StringExtensions for String {
         self.copy_into_slice(&mut slice[..len_0]);
         other.copy_into_slice(&mut slice[len_0..combined_len]);
 
-        String::from_str(&e, core::str::from_utf8(&slice[..combined_len]).unwrap())
+        String::from_str(e, core::str::from_utf8(&slice[..combined_len]).unwrap())
     }
 }
\ No newline at end of file
@@ -0,0 +1,14 @@
+use soroban_sdk::{self, contracterror};
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum ComplianceError {
+    Unauthorized = 1,
+    OfficerNotFound = 2,
+
+    // Raised through the lot tokens' mints and transfers, so the codes match
+    // the compliance codes of `WineTokenError`
+    NotAllowlisted = 142,
+    CountryBlocked = 143,
+}
@@ -0,0 +1,88 @@
+use soroban_sdk::{contracttype, symbol_short, Address, Env, String};
+
+// ACCOUNT ALLOWED EVENT
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AccountAllowedEvent {
+    pub officer: Address,
+    pub account: Address,
+    pub country: String,
+}
+
+/// Publishes an `AccountAllowedEvent` to the event stream.
+pub(crate) fn emit_account_allowed_event(
+    e: &Env,
+    officer: Address,
+    account: Address,
+    country: String,
+) {
+    let event = AccountAllowedEvent {
+        officer,
+        account,
+        country,
+    };
+
+    e.events()
+        .publish(("compliance", symbol_short!("allowed")), event);
+}
+
+// ACCOUNT DISALLOWED EVENT
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AccountDisallowedEvent {
+    pub officer: Address,
+    pub account: Address,
+}
+
+/// Publishes an `AccountDisallowedEvent` to the event stream.
+pub(crate) fn emit_account_disallowed_event(e: &Env, officer: Address, account: Address) {
+    let event = AccountDisallowedEvent { officer, account };
+
+    e.events()
+        .publish(("compliance", symbol_short!("removed")), event);
+}
+
+// COUNTRY RULE SET EVENT
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CountryRuleSetEvent {
+    pub officer: Address,
+    pub origin_country: String,
+    pub holder_country: String,
+    pub blocked: bool,
+}
+
+/// Publishes a `CountryRuleSetEvent` to the event stream.
+pub(crate) fn emit_country_rule_set_event(
+    e: &Env,
+    officer: Address,
+    origin_country: String,
+    holder_country: String,
+    blocked: bool,
+) {
+    let event = CountryRuleSetEvent {
+        officer,
+        origin_country,
+        holder_country,
+        blocked,
+    };
+
+    e.events()
+        .publish(("compliance", symbol_short!("rule_set")), event);
+}
+
+// OFFICER CHANGED EVENT
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OfficerChangedEvent {
+    pub officer: Address,
+    pub active: bool,
+}
+
+/// Publishes an `OfficerChangedEvent` to the event stream.
+pub(crate) fn emit_officer_changed_event(e: &Env, officer: Address, active: bool) {
+    let event = OfficerChangedEvent { officer, active };
+
+    e.events()
+        .publish(("compliance", symbol_short!("officer")), event);
+}
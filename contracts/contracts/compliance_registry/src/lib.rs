@@ -0,0 +1,195 @@
+#![no_std]
+
+use soroban_sdk::{contract, contractimpl, Address, Env, String, Vec};
+
+mod error;
+mod events;
+mod storage;
+mod test;
+
+use storage::{
+    extend_instance_ttl, is_country_blocked, is_officer, read_administrator,
+    read_allowlist_country, write_administrator, write_allowlist_country, write_country_blocked,
+    write_officer,
+};
+
+pub use error::ComplianceError;
+
+/// Checks that `officer` signed and is the admin or a compliance officer.
+fn require_officer(e: &Env, officer: &Address) -> Result<(), ComplianceError> {
+    officer.require_auth();
+    if *officer != read_administrator(e) && !is_officer(e, officer) {
+        return Err(ComplianceError::Unauthorized);
+    }
+    Ok(())
+}
+
+#[contract]
+pub struct ComplianceRegistry;
+
+#[contractimpl]
+impl ComplianceRegistry {
+    /// Initialize a compliance registry shared by the lot tokens that point to it
+    ///
+    /// The registry keeps a KYC allowlist recording the country each holder was
+    /// verified in, and a rule table keyed by the lot's origin country (its
+    /// `WineLotMetadata::country`): a rule bars holders verified in one country
+    /// from holding lots produced in another. A lot token created with this
+    /// registry only mints to and transfers between allowlisted holders that no
+    /// rule bars from its origin country.
+    ///
+    /// # Arguments
+    /// * `admin` - Administrator managing the compliance officers
+    pub fn __constructor(e: Env, admin: Address) {
+        write_administrator(&e, &admin);
+        extend_instance_ttl(&e);
+    }
+
+    /// Let an account run the allowlist and country rules (only admin can call)
+    pub fn add_officer(e: Env, officer: Address) {
+        read_administrator(&e).require_auth();
+        extend_instance_ttl(&e);
+
+        write_officer(&e, &officer, true);
+        events::emit_officer_changed_event(&e, officer, true);
+    }
+
+    /// Remove a compliance officer (only admin can call)
+    ///
+    /// # Errors
+    /// * `ComplianceError::OfficerNotFound` - If `officer` is not a compliance officer
+    pub fn remove_officer(e: Env, officer: Address) -> Result<(), ComplianceError> {
+        read_administrator(&e).require_auth();
+        extend_instance_ttl(&e);
+
+        if !is_officer(&e, &officer) {
+            return Err(ComplianceError::OfficerNotFound);
+        }
+        write_officer(&e, &officer, false);
+        events::emit_officer_changed_event(&e, officer, false);
+        Ok(())
+    }
+
+    /// Check whether an account is a compliance officer
+    pub fn is_officer(e: Env, officer: Address) -> bool {
+        extend_instance_ttl(&e);
+        is_officer(&e, &officer)
+    }
+
+    /// Add an account to the KYC allowlist (only admin or a compliance officer can call)
+    ///
+    /// Re-allowlisting an account overwrites its recorded country.
+    ///
+    /// # Arguments
+    /// * `officer` - Admin or compliance officer recording the check
+    /// * `account` - Account that passed KYC
+    /// * `country` - Country the account was verified in
+    ///
+    /// # Errors
+    /// * `ComplianceError::Unauthorized` - If `officer` is neither the admin nor a compliance officer
+    pub fn allow_account(
+        e: Env,
+        officer: Address,
+        account: Address,
+        country: String,
+    ) -> Result<(), ComplianceError> {
+        require_officer(&e, &officer)?;
+        extend_instance_ttl(&e);
+
+        write_allowlist_country(&e, &account, Some(country.clone()));
+        events::emit_account_allowed_event(&e, officer, account, country);
+        Ok(())
+    }
+
+    /// Remove an account from the KYC allowlist (only admin or a compliance officer can call)
+    ///
+    /// Existing balances stay put but can no longer move on lots using this registry.
+    ///
+    /// # Errors
+    /// * `ComplianceError::Unauthorized` - If `officer` is neither the admin nor a compliance officer
+    pub fn disallow_account(
+        e: Env,
+        officer: Address,
+        account: Address,
+    ) -> Result<(), ComplianceError> {
+        require_officer(&e, &officer)?;
+        extend_instance_ttl(&e);
+
+        write_allowlist_country(&e, &account, None);
+        events::emit_account_disallowed_event(&e, officer, account);
+        Ok(())
+    }
+
+    /// Check whether an account is on the KYC allowlist
+    pub fn is_allowlisted(e: Env, account: Address) -> bool {
+        extend_instance_ttl(&e);
+        read_allowlist_country(&e, &account).is_some()
+    }
+
+    /// Get the country an allowlisted account was verified in
+    pub fn get_allowlist_country(e: Env, account: Address) -> Option<String> {
+        extend_instance_ttl(&e);
+        read_allowlist_country(&e, &account)
+    }
+
+    /// Bar or re-allow holders from a country for lots of an origin country
+    /// (only admin or a compliance officer can call)
+    ///
+    /// # Arguments
+    /// * `officer` - Admin or compliance officer setting the rule
+    /// * `origin_country` - Origin country of the lots the rule applies to, matched against `WineLotMetadata::country`
+    /// * `holder_country` - Holder country the rule applies to, matched against allowlist entries
+    /// * `blocked` - Whether those holders are barred from those lots
+    ///
+    /// # Errors
+    /// * `ComplianceError::Unauthorized` - If `officer` is neither the admin nor a compliance officer
+    pub fn set_country_blocked(
+        e: Env,
+        officer: Address,
+        origin_country: String,
+        holder_country: String,
+        blocked: bool,
+    ) -> Result<(), ComplianceError> {
+        require_officer(&e, &officer)?;
+        extend_instance_ttl(&e);
+
+        write_country_blocked(&e, &origin_country, &holder_country, blocked);
+        events::emit_country_rule_set_event(&e, officer, origin_country, holder_country, blocked);
+        Ok(())
+    }
+
+    /// Check whether holders from `holder_country` are barred from lots of `origin_country`
+    pub fn is_country_blocked(e: Env, origin_country: String, holder_country: String) -> bool {
+        extend_instance_ttl(&e);
+        is_country_blocked(&e, &origin_country, &holder_country)
+    }
+
+    /// Check that every account may hold a lot of `origin_country`
+    ///
+    /// Called by lot tokens before each mint and transfer.
+    ///
+    /// # Errors
+    /// * `ComplianceError::NotAllowlisted` - If an account is not on the allowlist
+    /// * `ComplianceError::CountryBlocked` - If a rule bars an account's country from `origin_country`
+    pub fn check(
+        e: Env,
+        origin_country: String,
+        accounts: Vec<Address>,
+    ) -> Result<(), ComplianceError> {
+        extend_instance_ttl(&e);
+        for account in accounts.iter() {
+            let country =
+                read_allowlist_country(&e, &account).ok_or(ComplianceError::NotAllowlisted)?;
+            if is_country_blocked(&e, &origin_country, &country) {
+                return Err(ComplianceError::CountryBlocked);
+            }
+        }
+        Ok(())
+    }
+
+    /// Get current admin
+    pub fn admin(e: Env) -> Address {
+        extend_instance_ttl(&e);
+        read_administrator(&e)
+    }
+}
@@ -0,0 +1,107 @@
+use soroban_sdk::{contracttype, Address, Env, String};
+
+pub(crate) const DAY_IN_LEDGERS: u32 = 17280;
+pub(crate) const INSTANCE_BUMP_AMOUNT: u32 = 30 * DAY_IN_LEDGERS;
+pub(crate) const INSTANCE_LIFETIME_THRESHOLD: u32 = INSTANCE_BUMP_AMOUNT - DAY_IN_LEDGERS;
+pub(crate) const PERSISTENT_BUMP_AMOUNT: u32 = 120 * DAY_IN_LEDGERS;
+pub(crate) const PERSISTENT_LIFETIME_THRESHOLD: u32 = PERSISTENT_BUMP_AMOUNT - 20 * DAY_IN_LEDGERS;
+
+#[derive(Clone)]
+#[contracttype]
+pub enum DataKey {
+    Admin,
+    Officer(Address),
+    Allowlisted(Address),
+    BlockedCountry(String, String), // (origin country, holder country)
+}
+
+pub fn extend_instance_ttl(e: &Env) {
+    e.storage()
+        .instance()
+        .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+}
+
+fn extend_persistent_ttl(e: &Env, key: &DataKey) {
+    e.storage()
+        .persistent()
+        .extend_ttl(key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+}
+
+/// Sets a persistent flag entry, removing it when `value` is false.
+fn write_flag(e: &Env, key: &DataKey, value: bool) {
+    if value {
+        e.storage().persistent().set(key, &true);
+        extend_persistent_ttl(e, key);
+    } else {
+        e.storage().persistent().remove(key);
+    }
+}
+
+fn read_flag(e: &Env, key: &DataKey) -> bool {
+    let set = e.storage().persistent().has(key);
+    if set {
+        extend_persistent_ttl(e, key);
+    }
+    set
+}
+
+// Admin
+pub fn read_administrator(e: &Env) -> Address {
+    e.storage().instance().get(&DataKey::Admin).unwrap()
+}
+
+pub fn write_administrator(e: &Env, admin: &Address) {
+    e.storage().instance().set(&DataKey::Admin, admin);
+}
+
+// Officers
+pub fn is_officer(e: &Env, officer: &Address) -> bool {
+    read_flag(e, &DataKey::Officer(officer.clone()))
+}
+
+pub fn write_officer(e: &Env, officer: &Address, active: bool) {
+    write_flag(e, &DataKey::Officer(officer.clone()), active);
+}
+
+// Allowlist
+/// Country an allowlisted account was verified in, `None` if it is not allowlisted.
+pub fn read_allowlist_country(e: &Env, account: &Address) -> Option<String> {
+    let key = DataKey::Allowlisted(account.clone());
+    let country = e.storage().persistent().get::<DataKey, String>(&key);
+    if country.is_some() {
+        extend_persistent_ttl(e, &key);
+    }
+    country
+}
+
+pub fn write_allowlist_country(e: &Env, account: &Address, country: Option<String>) {
+    let key = DataKey::Allowlisted(account.clone());
+    match country {
+        Some(country) => {
+            e.storage().persistent().set(&key, &country);
+            extend_persistent_ttl(e, &key);
+        }
+        None => e.storage().persistent().remove(&key),
+    }
+}
+
+// Country rules
+pub fn is_country_blocked(e: &Env, origin_country: &String, holder_country: &String) -> bool {
+    read_flag(
+        e,
+        &DataKey::BlockedCountry(origin_country.clone(), holder_country.clone()),
+    )
+}
+
+pub fn write_country_blocked(
+    e: &Env,
+    origin_country: &String,
+    holder_country: &String,
+    blocked: bool,
+) {
+    write_flag(
+        e,
+        &DataKey::BlockedCountry(origin_country.clone(), holder_country.clone()),
+        blocked,
+    );
+}
@@ -0,0 +1,42 @@
+#![cfg(test)]
+extern crate std;
+use soroban_sdk::{testutils::Address as _, Address, Env, String};
+
+use crate::{ComplianceRegistry, ComplianceRegistryClient};
+
+pub struct ComplianceTest<'a> {
+    env: Env,
+    admin: Address,
+    registry: ComplianceRegistryClient<'a>,
+    user: Address,
+    argentina: String,
+    chile: String,
+}
+
+impl<'a> ComplianceTest<'a> {
+    fn setup() -> Self {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let admin = Address::generate(&env);
+        let registry = ComplianceRegistryClient::new(
+            &env,
+            &env.register(ComplianceRegistry, (admin.clone(),)),
+        );
+
+        let user = Address::generate(&env);
+        let argentina = String::from_str(&env, "Argentina");
+        let chile = String::from_str(&env, "Chile");
+
+        ComplianceTest {
+            env,
+            admin,
+            registry,
+            user,
+            argentina,
+            chile,
+        }
+    }
+}
+
+mod compliance_registry;
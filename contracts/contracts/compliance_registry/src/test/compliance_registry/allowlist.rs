@@ -0,0 +1,97 @@
+extern crate std;
+
+use soroban_sdk::testutils::{Address as _, AuthorizedFunction, AuthorizedInvocation};
+use soroban_sdk::{Address, IntoVal, Symbol};
+
+use crate::test::ComplianceTest;
+use crate::ComplianceError;
+
+#[test]
+fn allow_and_disallow_account() {
+    let test = ComplianceTest::setup();
+
+    assert!(!test.registry.is_allowlisted(&test.user));
+    test.registry
+        .allow_account(&test.admin, &test.user, &test.argentina);
+    assert!(test.registry.is_allowlisted(&test.user));
+    assert_eq!(
+        test.registry.get_allowlist_country(&test.user),
+        Some(test.argentina.clone())
+    );
+
+    test.registry
+        .allow_account(&test.admin, &test.user, &test.chile);
+    assert_eq!(
+        test.registry.get_allowlist_country(&test.user),
+        Some(test.chile.clone())
+    );
+
+    test.registry.disallow_account(&test.admin, &test.user);
+    assert!(!test.registry.is_allowlisted(&test.user));
+    assert_eq!(test.registry.get_allowlist_country(&test.user), None);
+}
+
+#[test]
+fn officer_manages_allowlist_and_rules() {
+    let test = ComplianceTest::setup();
+    let officer = Address::generate(&test.env);
+
+    assert_eq!(
+        test.registry
+            .try_allow_account(&officer, &test.user, &test.argentina),
+        Err(Ok(ComplianceError::Unauthorized))
+    );
+    assert_eq!(
+        test.registry
+            .try_set_country_blocked(&officer, &test.argentina, &test.chile, &true),
+        Err(Ok(ComplianceError::Unauthorized))
+    );
+
+    test.registry.add_officer(&officer);
+    assert!(test.registry.is_officer(&officer));
+    test.registry
+        .allow_account(&officer, &test.user, &test.argentina);
+    test.registry
+        .set_country_blocked(&officer, &test.argentina, &test.chile, &true);
+    assert!(test.registry.is_allowlisted(&test.user));
+    assert!(test
+        .registry
+        .is_country_blocked(&test.argentina, &test.chile));
+
+    test.registry.remove_officer(&officer);
+    assert!(!test.registry.is_officer(&officer));
+    assert_eq!(
+        test.registry.try_disallow_account(&officer, &test.user),
+        Err(Ok(ComplianceError::Unauthorized))
+    );
+}
+
+#[test]
+fn removing_unknown_officer_fails() {
+    let test = ComplianceTest::setup();
+
+    let result = test.registry.try_remove_officer(&test.user);
+    assert_eq!(result, Err(Ok(ComplianceError::OfficerNotFound)));
+}
+
+#[test]
+fn add_officer_requires_admin() {
+    let test = ComplianceTest::setup();
+    let officer = Address::generate(&test.env);
+    test.registry.add_officer(&officer);
+
+    assert_eq!(
+        test.env.auths(),
+        std::vec![(
+            test.admin.clone(),
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    test.registry.address.clone(),
+                    Symbol::new(&test.env, "add_officer"),
+                    (officer,).into_val(&test.env),
+                )),
+                sub_invocations: std::vec![],
+            }
+        )]
+    );
+}
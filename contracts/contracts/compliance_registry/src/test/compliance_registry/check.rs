@@ -0,0 +1,68 @@
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{vec, Address, String};
+
+use crate::test::ComplianceTest;
+use crate::ComplianceError;
+
+#[test]
+fn allowlisted_accounts_pass() {
+    let test = ComplianceTest::setup();
+    let receiver = Address::generate(&test.env);
+
+    test.registry
+        .allow_account(&test.admin, &test.user, &test.argentina);
+    test.registry
+        .allow_account(&test.admin, &receiver, &test.chile);
+
+    let accounts = vec![&test.env, test.user.clone(), receiver];
+    test.registry.check(&test.argentina, &accounts);
+}
+
+#[test]
+fn unlisted_account_fails() {
+    let test = ComplianceTest::setup();
+    let receiver = Address::generate(&test.env);
+
+    test.registry
+        .allow_account(&test.admin, &test.user, &test.argentina);
+
+    let accounts = vec![&test.env, test.user.clone(), receiver];
+    let result = test.registry.try_check(&test.argentina, &accounts);
+    assert_eq!(result, Err(Ok(ComplianceError::NotAllowlisted)));
+}
+
+#[test]
+fn rules_apply_per_origin_country() {
+    let test = ComplianceTest::setup();
+    let france = String::from_str(&test.env, "France");
+
+    test.registry
+        .allow_account(&test.admin, &test.user, &test.chile);
+    test.registry
+        .set_country_blocked(&test.admin, &test.argentina, &test.chile, &true);
+
+    // Chilean holders are barred from Argentinian lots only
+    let accounts = vec![&test.env, test.user.clone()];
+    let result = test.registry.try_check(&test.argentina, &accounts);
+    assert_eq!(result, Err(Ok(ComplianceError::CountryBlocked)));
+    test.registry.check(&france, &accounts);
+    assert!(!test.registry.is_country_blocked(&france, &test.chile));
+}
+
+#[test]
+fn unblocking_a_country_restores_access() {
+    let test = ComplianceTest::setup();
+
+    test.registry
+        .allow_account(&test.admin, &test.user, &test.chile);
+    test.registry
+        .set_country_blocked(&test.admin, &test.argentina, &test.chile, &true);
+    test.registry
+        .set_country_blocked(&test.admin, &test.argentina, &test.chile, &false);
+
+    assert!(!test
+        .registry
+        .is_country_blocked(&test.argentina, &test.chile));
+    test.registry
+        .check(&test.argentina, &vec![&test.env, test.user.clone()]);
+}
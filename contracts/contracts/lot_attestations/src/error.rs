@@ -0,0 +1,11 @@
+use soroban_sdk::{self, contracterror};
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum AttestationError {
+    Unauthorized = 1,
+    AttestationNotFound = 2,
+    AttestationRevoked = 3,
+    InvalidExpiry = 4,
+}
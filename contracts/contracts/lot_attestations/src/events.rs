@@ -0,0 +1,54 @@
+use soroban_sdk::{contracttype, symbol_short, Address, BytesN, Env, Symbol};
+
+// ATTESTATION ADDED EVENT
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AttestationAddedEvent {
+    pub id: u32,
+    pub certifier: Address,
+    pub cert_type: Symbol,
+    pub doc_hash: BytesN<32>,
+    pub expiry_ledger: u32,
+}
+
+/// Publishes an `AttestationAddedEvent` to the event stream.
+pub(crate) fn emit_attestation_added_event(
+    e: &Env,
+    id: u32,
+    certifier: Address,
+    cert_type: Symbol,
+    doc_hash: BytesN<32>,
+    expiry_ledger: u32,
+) {
+    let event = AttestationAddedEvent {
+        id,
+        certifier,
+        cert_type,
+        doc_hash,
+        expiry_ledger,
+    };
+
+    e.events()
+        .publish(("lotAttestations", symbol_short!("added")), event);
+}
+
+// ATTESTATION REVOKED EVENT
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AttestationRevokedEvent {
+    pub id: u32,
+    pub caller: Address,
+    pub cert_type: Symbol,
+}
+
+/// Publishes an `AttestationRevokedEvent` to the event stream.
+pub(crate) fn emit_attestation_revoked_event(e: &Env, id: u32, caller: Address, cert_type: Symbol) {
+    let event = AttestationRevokedEvent {
+        id,
+        caller,
+        cert_type,
+    };
+
+    e.events()
+        .publish(("lotAttestations", symbol_short!("revoked")), event);
+}
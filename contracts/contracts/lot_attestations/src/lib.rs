@@ -0,0 +1,171 @@
+#![no_std]
+
+use common::models::Role;
+use soroban_sdk::{contract, contractimpl, Address, BytesN, Env, Symbol, Vec};
+
+mod error;
+mod events;
+pub mod models;
+mod storage;
+mod test;
+mod wine_token;
+
+use models::Attestation;
+use storage::{
+    extend_instance_ttl, next_attestation_id, read_attestation, read_attestation_count,
+    read_attestations, read_current_attestation, read_current_attestation_id, read_lot_token,
+    remove_current_attestation_id, write_attestation, write_current_attestation_id,
+    write_lot_token,
+};
+use wine_token::WineTokenClient;
+
+pub use error::AttestationError;
+
+/// An attestation counts while it is not revoked, has not expired and its
+/// certifier still holds the `Certifier` role on the lot token.
+fn is_attestation_valid(e: &Env, attestation: &Attestation) -> bool {
+    !attestation.revoked
+        && e.ledger().sequence() < attestation.expiry_ledger
+        && WineTokenClient::new(e, &read_lot_token(e))
+            .has_role(&Role::Certifier, &attestation.certifier)
+}
+
+#[contract]
+pub struct LotAttestations;
+
+#[contractimpl]
+impl LotAttestations {
+    /// Initialize the attestation registry of a wine lot
+    ///
+    /// Certifiers are the accounts holding the `Certifier` role on the lot
+    /// token, so the winery manages them there with `grant_role`.
+    ///
+    /// # Arguments
+    /// * `lot_token` - Address of the `WineToken` the attestations are about
+    pub fn __constructor(e: Env, lot_token: Address) {
+        write_lot_token(&e, &lot_token);
+        extend_instance_ttl(&e);
+    }
+
+    /// Attach a certification attestation to the lot (only the token admin or a `Certifier` can call)
+    ///
+    /// The new attestation supersedes any earlier one of the same `cert_type`.
+    ///
+    /// # Arguments
+    /// * `certifier` - Certifying body signing the attestation
+    /// * `cert_type` - Kind of certification (e.g. `organic`, `doc`, `lab_audit`)
+    /// * `doc_hash` - Hash of the certificate document
+    /// * `expiry_ledger` - Ledger sequence at which the attestation stops counting
+    ///
+    /// # Returns
+    /// * Id of the new attestation
+    ///
+    /// # Errors
+    /// * `AttestationError::Unauthorized` - If `certifier` is neither the token admin nor a `Certifier`
+    /// * `AttestationError::InvalidExpiry` - If `expiry_ledger` is not in the future
+    pub fn attest(
+        e: Env,
+        certifier: Address,
+        cert_type: Symbol,
+        doc_hash: BytesN<32>,
+        expiry_ledger: u32,
+    ) -> Result<u32, AttestationError> {
+        certifier.require_auth();
+        extend_instance_ttl(&e);
+
+        let lot_token = WineTokenClient::new(&e, &read_lot_token(&e));
+        if !lot_token.has_role(&Role::Certifier, &certifier) {
+            return Err(AttestationError::Unauthorized);
+        }
+        if expiry_ledger <= e.ledger().sequence() {
+            return Err(AttestationError::InvalidExpiry);
+        }
+
+        let id = next_attestation_id(&e);
+        write_attestation(
+            &e,
+            &Attestation {
+                id,
+                certifier: certifier.clone(),
+                cert_type: cert_type.clone(),
+                doc_hash: doc_hash.clone(),
+                issued_at: e.ledger().timestamp(),
+                expiry_ledger,
+                revoked: false,
+            },
+        );
+        write_current_attestation_id(&e, &cert_type, id);
+
+        events::emit_attestation_added_event(&e, id, certifier, cert_type, doc_hash, expiry_ledger);
+        Ok(id)
+    }
+
+    /// Revoke an attestation (only its certifier or the token admin can call)
+    ///
+    /// # Errors
+    /// * `AttestationError::AttestationNotFound` - If no attestation exists with `id`
+    /// * `AttestationError::Unauthorized` - If `caller` is neither the certifier nor the token admin
+    /// * `AttestationError::AttestationRevoked` - If the attestation was already revoked
+    pub fn revoke_attestation(e: Env, caller: Address, id: u32) -> Result<(), AttestationError> {
+        caller.require_auth();
+        extend_instance_ttl(&e);
+
+        let mut attestation = read_attestation(&e, id)?;
+        if caller != attestation.certifier
+            && caller != WineTokenClient::new(&e, &read_lot_token(&e)).admin()
+        {
+            return Err(AttestationError::Unauthorized);
+        }
+        if attestation.revoked {
+            return Err(AttestationError::AttestationRevoked);
+        }
+
+        attestation.revoked = true;
+        write_attestation(&e, &attestation);
+        if read_current_attestation_id(&e, &attestation.cert_type) == Some(id) {
+            remove_current_attestation_id(&e, &attestation.cert_type);
+        }
+
+        events::emit_attestation_revoked_event(&e, id, caller, attestation.cert_type);
+        Ok(())
+    }
+
+    /// Get a page of the attestations attached to the lot, oldest first,
+    /// including revoked, expired and superseded ones
+    ///
+    /// # Arguments
+    /// * `start` - Id of the first attestation to return
+    /// * `limit` - Maximum number of attestations to return
+    pub fn get_attestations(e: Env, start: u32, limit: u32) -> Vec<Attestation> {
+        extend_instance_ttl(&e);
+        read_attestations(&e, start, limit)
+    }
+
+    /// Get the number of attestations ever attached to the lot
+    pub fn attestation_count(e: Env) -> u32 {
+        extend_instance_ttl(&e);
+        read_attestation_count(&e)
+    }
+
+    /// Get the latest unrevoked attestation of `cert_type`, even if it has expired
+    pub fn get_current_attestation(e: Env, cert_type: Symbol) -> Option<Attestation> {
+        extend_instance_ttl(&e);
+        read_current_attestation(&e, &cert_type)
+    }
+
+    /// Check whether the lot holds a live attestation of `cert_type`
+    ///
+    /// The latest attestation of the type is live while it is not revoked, has
+    /// not expired and its certifier still holds the `Certifier` role.
+    pub fn is_certified(e: Env, cert_type: Symbol) -> bool {
+        extend_instance_ttl(&e);
+        read_current_attestation(&e, &cert_type)
+            .is_some_and(|attestation| is_attestation_valid(&e, &attestation))
+    }
+
+    /// Get the lot token the attestations are about
+    pub fn lot_token(e: Env) -> Address {
+        extend_instance_ttl(&e);
+        read_lot_token(&e)
+    }
+}
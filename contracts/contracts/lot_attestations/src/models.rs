@@ -0,0 +1,14 @@
+use soroban_sdk::{contracttype, Address, BytesN, Symbol};
+
+// Third-Party Certification Attestation
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Attestation {
+    pub id: u32,
+    pub certifier: Address,
+    pub cert_type: Symbol, // e.g. "organic", "doc", "lab_audit"
+    pub doc_hash: BytesN<32>,
+    pub issued_at: u64,
+    pub expiry_ledger: u32,
+    pub revoked: bool,
+}
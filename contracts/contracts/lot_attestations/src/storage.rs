@@ -0,0 +1,114 @@
+use soroban_sdk::{contracttype, Address, Env, Symbol, Vec};
+
+use crate::error::AttestationError;
+use crate::models::Attestation;
+
+pub(crate) const DAY_IN_LEDGERS: u32 = 17280;
+pub(crate) const INSTANCE_BUMP_AMOUNT: u32 = 30 * DAY_IN_LEDGERS;
+pub(crate) const INSTANCE_LIFETIME_THRESHOLD: u32 = INSTANCE_BUMP_AMOUNT - DAY_IN_LEDGERS;
+pub(crate) const PERSISTENT_BUMP_AMOUNT: u32 = 120 * DAY_IN_LEDGERS;
+pub(crate) const PERSISTENT_LIFETIME_THRESHOLD: u32 = PERSISTENT_BUMP_AMOUNT - 20 * DAY_IN_LEDGERS;
+
+#[derive(Clone)]
+#[contracttype]
+pub enum DataKey {
+    LotToken,
+    AttestationCount,
+    Attestation(u32),
+    CurrentAttestation(Symbol),
+}
+
+pub fn extend_instance_ttl(e: &Env) {
+    e.storage()
+        .instance()
+        .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+}
+
+fn extend_persistent_ttl(e: &Env, key: &DataKey) {
+    e.storage()
+        .persistent()
+        .extend_ttl(key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+}
+
+// Lot token
+pub fn read_lot_token(e: &Env) -> Address {
+    e.storage().instance().get(&DataKey::LotToken).unwrap()
+}
+
+pub fn write_lot_token(e: &Env, lot_token: &Address) {
+    e.storage().instance().set(&DataKey::LotToken, lot_token);
+}
+
+// Attestations
+pub fn read_attestation_count(e: &Env) -> u32 {
+    e.storage()
+        .instance()
+        .get(&DataKey::AttestationCount)
+        .unwrap_or(0)
+}
+
+/// Returns the next attestation id and bumps the counter.
+pub fn next_attestation_id(e: &Env) -> u32 {
+    let id = read_attestation_count(e);
+    e.storage()
+        .instance()
+        .set(&DataKey::AttestationCount, &id.checked_add(1).unwrap());
+    id
+}
+
+pub fn read_attestation(e: &Env, id: u32) -> Result<Attestation, AttestationError> {
+    let key = DataKey::Attestation(id);
+    let attestation = e
+        .storage()
+        .persistent()
+        .get::<DataKey, Attestation>(&key)
+        .ok_or(AttestationError::AttestationNotFound)?;
+    extend_persistent_ttl(e, &key);
+    Ok(attestation)
+}
+
+pub fn write_attestation(e: &Env, attestation: &Attestation) {
+    let key = DataKey::Attestation(attestation.id);
+    e.storage().persistent().set(&key, attestation);
+    extend_persistent_ttl(e, &key);
+}
+
+/// Reads a page of attestations in id order, including revoked and expired ones.
+pub fn read_attestations(e: &Env, start: u32, limit: u32) -> Vec<Attestation> {
+    let end = start.saturating_add(limit).min(read_attestation_count(e));
+    let mut attestations = Vec::new(e);
+    for id in start..end {
+        if let Ok(attestation) = read_attestation(e, id) {
+            attestations.push_back(attestation);
+        }
+    }
+    attestations
+}
+
+// Current attestation per certification type
+/// Id of the attestation currently standing for `cert_type`, if any.
+pub fn read_current_attestation_id(e: &Env, cert_type: &Symbol) -> Option<u32> {
+    let key = DataKey::CurrentAttestation(cert_type.clone());
+    let id = e.storage().persistent().get::<DataKey, u32>(&key);
+    if id.is_some() {
+        extend_persistent_ttl(e, &key);
+    }
+    id
+}
+
+pub fn write_current_attestation_id(e: &Env, cert_type: &Symbol, id: u32) {
+    let key = DataKey::CurrentAttestation(cert_type.clone());
+    e.storage().persistent().set(&key, &id);
+    extend_persistent_ttl(e, &key);
+}
+
+pub fn remove_current_attestation_id(e: &Env, cert_type: &Symbol) {
+    e.storage()
+        .persistent()
+        .remove(&DataKey::CurrentAttestation(cert_type.clone()));
+}
+
+/// The attestation currently standing for `cert_type`, valid or not.
+pub fn read_current_attestation(e: &Env, cert_type: &Symbol) -> Option<Attestation> {
+    read_current_attestation_id(e, cert_type).and_then(|id| read_attestation(e, id).ok())
+}
@@ -0,0 +1,119 @@
+use soroban_sdk::testutils::Ledger;
+use soroban_sdk::{symbol_short, BytesN};
+
+use crate::test::AttestationsTest;
+use crate::AttestationError;
+
+#[test]
+fn certifier_can_attest() {
+    let test = AttestationsTest::setup();
+    let doc_hash = BytesN::from_array(&test.env, &[7u8; 32]);
+
+    assert!(!test.registry.is_certified(&symbol_short!("organic")));
+    let id = test
+        .registry
+        .attest(&test.certifier, &symbol_short!("organic"), &doc_hash, &1000);
+    assert_eq!(id, 0);
+
+    let attestations = test.registry.get_attestations(&0, &10);
+    assert_eq!(attestations.len(), 1);
+    let attestation = attestations.get(0).unwrap();
+    assert_eq!(attestation.certifier, test.certifier);
+    assert_eq!(attestation.doc_hash, doc_hash);
+    assert!(!attestation.revoked);
+
+    assert!(test.registry.is_certified(&symbol_short!("organic")));
+    assert!(!test.registry.is_certified(&symbol_short!("doc")));
+}
+
+#[test]
+fn non_certifier_cannot_attest() {
+    let test = AttestationsTest::setup();
+    let doc_hash = BytesN::from_array(&test.env, &[7u8; 32]);
+
+    let result = test
+        .registry
+        .try_attest(&test.user, &symbol_short!("organic"), &doc_hash, &1000);
+    assert_eq!(result, Err(Ok(AttestationError::Unauthorized)));
+}
+
+#[test]
+fn expiry_must_be_in_the_future() {
+    let test = AttestationsTest::setup();
+    let doc_hash = BytesN::from_array(&test.env, &[7u8; 32]);
+    let now = test.env.ledger().sequence();
+
+    let result = test
+        .registry
+        .try_attest(&test.admin, &symbol_short!("organic"), &doc_hash, &now);
+    assert_eq!(result, Err(Ok(AttestationError::InvalidExpiry)));
+}
+
+#[test]
+fn expired_attestation_no_longer_certifies() {
+    let test = AttestationsTest::setup();
+    let doc_hash = BytesN::from_array(&test.env, &[7u8; 32]);
+    let now = test.env.ledger().sequence();
+
+    test.registry
+        .attest(&test.admin, &symbol_short!("doc"), &doc_hash, &(now + 10));
+    assert!(test.registry.is_certified(&symbol_short!("doc")));
+
+    test.env.ledger().set_sequence_number(now + 10);
+    assert!(!test.registry.is_certified(&symbol_short!("doc")));
+}
+
+#[test]
+fn attestations_are_paginated() {
+    let test = AttestationsTest::setup();
+    let doc_hash = BytesN::from_array(&test.env, &[7u8; 32]);
+
+    for cert_type in [
+        symbol_short!("organic"),
+        symbol_short!("doc"),
+        symbol_short!("lab"),
+    ] {
+        test.registry
+            .attest(&test.admin, &cert_type, &doc_hash, &1000);
+    }
+    assert_eq!(test.registry.attestation_count(), 3);
+
+    let page = test.registry.get_attestations(&1, &5);
+    assert_eq!(page.len(), 2);
+    assert_eq!(page.get(0).unwrap().cert_type, symbol_short!("doc"));
+    assert_eq!(page.get(1).unwrap().id, 2);
+    assert_eq!(test.registry.get_attestations(&3, &5).len(), 0);
+}
+
+#[test]
+fn new_attestation_supersedes_previous() {
+    let test = AttestationsTest::setup();
+    let old_hash = BytesN::from_array(&test.env, &[1u8; 32]);
+    let new_hash = BytesN::from_array(&test.env, &[2u8; 32]);
+
+    let old_id = test
+        .registry
+        .attest(&test.certifier, &symbol_short!("organic"), &old_hash, &1000);
+    let new_id = test
+        .registry
+        .attest(&test.admin, &symbol_short!("organic"), &new_hash, &2000);
+
+    let current = test
+        .registry
+        .get_current_attestation(&symbol_short!("organic"))
+        .unwrap();
+    assert_eq!(current.id, new_id);
+    assert_eq!(current.doc_hash, new_hash);
+
+    // Revoking the superseded attestation leaves the current one standing
+    test.registry.revoke_attestation(&test.certifier, &old_id);
+    assert!(test.registry.is_certified(&symbol_short!("organic")));
+
+    test.registry.revoke_attestation(&test.admin, &new_id);
+    assert!(!test.registry.is_certified(&symbol_short!("organic")));
+    assert_eq!(
+        test.registry
+            .get_current_attestation(&symbol_short!("organic")),
+        None
+    );
+}
@@ -0,0 +1,2 @@
+mod attest;
+mod revoke;
@@ -0,0 +1,50 @@
+use soroban_sdk::{symbol_short, BytesN};
+
+use crate::test::{wine_token_contract::Role, AttestationsTest};
+use crate::AttestationError;
+
+#[test]
+fn revoked_attestation_no_longer_certifies() {
+    let test = AttestationsTest::setup();
+    let doc_hash = BytesN::from_array(&test.env, &[7u8; 32]);
+
+    let id = test
+        .registry
+        .attest(&test.certifier, &symbol_short!("organic"), &doc_hash, &1000);
+
+    let result = test.registry.try_revoke_attestation(&test.user, &id);
+    assert_eq!(result, Err(Ok(AttestationError::Unauthorized)));
+
+    test.registry.revoke_attestation(&test.certifier, &id);
+    assert!(
+        test.registry
+            .get_attestations(&0, &10)
+            .get(0)
+            .unwrap()
+            .revoked
+    );
+    assert!(!test.registry.is_certified(&symbol_short!("organic")));
+
+    let result = test.registry.try_revoke_attestation(&test.admin, &id);
+    assert_eq!(result, Err(Ok(AttestationError::AttestationRevoked)));
+}
+
+#[test]
+fn revoke_unknown_attestation_fails() {
+    let test = AttestationsTest::setup();
+
+    let result = test.registry.try_revoke_attestation(&test.admin, &0);
+    assert_eq!(result, Err(Ok(AttestationError::AttestationNotFound)));
+}
+
+#[test]
+fn revoking_certifier_role_voids_attestations() {
+    let test = AttestationsTest::setup();
+    let doc_hash = BytesN::from_array(&test.env, &[7u8; 32]);
+
+    test.registry
+        .attest(&test.certifier, &symbol_short!("organic"), &doc_hash, &1000);
+    test.lot_token
+        .revoke_role(&Role::Certifier, &test.certifier);
+    assert!(!test.registry.is_certified(&symbol_short!("organic")));
+}
@@ -0,0 +1,10 @@
+use common::models::Role;
+use soroban_sdk::{contractclient, Address, Env};
+
+/// Subset of the `WineToken` interface used by the attestation registry.
+#[allow(dead_code)]
+#[contractclient(name = "WineTokenClient")]
+pub trait WineTokenInterface {
+    fn admin(e: Env) -> Address;
+    fn has_role(e: Env, role: Role, account: Address) -> bool;
+}
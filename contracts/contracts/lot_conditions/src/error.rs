@@ -0,0 +1,11 @@
+use soroban_sdk::{self, contracterror};
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum ConditionsError {
+    Unauthorized = 1,
+    InvalidConditionReport = 2,
+    ConditionReportNotFound = 3,
+    InvalidStorageRange = 4,
+}
@@ -0,0 +1,76 @@
+use soroban_sdk::{contracttype, symbol_short, Address, Env, Symbol};
+
+use crate::models::StorageRange;
+
+// CONDITIONS REPORTED EVENT
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ConditionsReportedEvent {
+    pub id: u32,
+    pub reporter: Address,
+    pub cellar_id: Symbol,
+}
+
+/// Publishes a `ConditionsReportedEvent` to the event stream.
+pub(crate) fn emit_conditions_reported_event(
+    e: &Env,
+    id: u32,
+    reporter: Address,
+    cellar_id: Symbol,
+) {
+    let event = ConditionsReportedEvent {
+        id,
+        reporter,
+        cellar_id,
+    };
+
+    e.events()
+        .publish(("lotConditions", symbol_short!("reported")), event);
+}
+
+// CONDITION ALERT EVENT
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ConditionAlertEvent {
+    pub id: u32,
+    pub cellar_id: Symbol,
+    pub min_temp: i32,
+    pub max_temp: i32,
+    pub humidity_bps: u32,
+}
+
+/// Publishes a `ConditionAlertEvent` to the event stream.
+pub(crate) fn emit_condition_alert_event(
+    e: &Env,
+    id: u32,
+    cellar_id: Symbol,
+    min_temp: i32,
+    max_temp: i32,
+    humidity_bps: u32,
+) {
+    let event = ConditionAlertEvent {
+        id,
+        cellar_id,
+        min_temp,
+        max_temp,
+        humidity_bps,
+    };
+
+    e.events()
+        .publish(("lotConditions", symbol_short!("alert")), event);
+}
+
+// STORAGE RANGE SET EVENT
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StorageRangeSetEvent {
+    pub range: StorageRange,
+}
+
+/// Publishes a `StorageRangeSetEvent` to the event stream.
+pub(crate) fn emit_storage_range_set_event(e: &Env, range: StorageRange) {
+    let event = StorageRangeSetEvent { range };
+
+    e.events()
+        .publish(("lotConditions", symbol_short!("range_set")), event);
+}
@@ -0,0 +1,154 @@
+#![no_std]
+
+use common::models::Role;
+use soroban_sdk::{contract, contractimpl, Address, BytesN, Env, Symbol, Vec};
+
+mod error;
+mod events;
+pub mod models;
+mod storage;
+mod test;
+mod wine_token;
+
+use models::{ConditionReport, ConditionSummary, StorageRange};
+use storage::{
+    extend_instance_ttl, read_condition_report, read_condition_reports, read_condition_summary,
+    read_lot_token, read_storage_range, record_condition_report, write_lot_token,
+    write_storage_range,
+};
+use wine_token::WineTokenClient;
+
+pub use error::ConditionsError;
+
+const MAX_HUMIDITY_BPS: u32 = 10_000;
+
+#[contract]
+pub struct LotConditions;
+
+#[contractimpl]
+impl LotConditions {
+    /// Initialize the storage conditions log of a wine lot
+    ///
+    /// Reporters are the accounts holding the `Sensor` or `StatusUpdater` role
+    /// on the lot token, so the winery manages them there with `grant_role`.
+    ///
+    /// # Arguments
+    /// * `lot_token` - Address of the `WineToken` whose bottles are monitored
+    pub fn __constructor(e: Env, lot_token: Address) {
+        write_lot_token(&e, &lot_token);
+        extend_instance_ttl(&e);
+    }
+
+    /// Record a storage condition report (only the token admin, a `Sensor` or a `StatusUpdater` can call)
+    ///
+    /// Readings outside the lot's storage range are counted and emit a
+    /// `ConditionAlertEvent`.
+    ///
+    /// # Arguments
+    /// * `reporter` - Sensor or handler submitting the reading
+    /// * `cellar_id` - Cellar or storage location identifier
+    /// * `min_temp` - Lowest temperature over the period, in tenths of a degree Celsius
+    /// * `max_temp` - Highest temperature over the period, in tenths of a degree Celsius
+    /// * `humidity_bps` - Relative humidity in basis points
+    /// * `payload_hash` - Hash of the raw sensor payload
+    ///
+    /// # Returns
+    /// * Id of the new report
+    ///
+    /// # Errors
+    /// * `ConditionsError::Unauthorized` - If `reporter` holds neither `Sensor` nor `StatusUpdater`
+    /// * `ConditionsError::InvalidConditionReport` - If `min_temp > max_temp` or humidity is above 100%
+    pub fn report_conditions(
+        e: Env,
+        reporter: Address,
+        cellar_id: Symbol,
+        min_temp: i32,
+        max_temp: i32,
+        humidity_bps: u32,
+        payload_hash: BytesN<32>,
+    ) -> Result<u32, ConditionsError> {
+        reporter.require_auth();
+        extend_instance_ttl(&e);
+
+        let lot_token = WineTokenClient::new(&e, &read_lot_token(&e));
+        if !lot_token.has_role(&Role::Sensor, &reporter)
+            && !lot_token.has_role(&Role::StatusUpdater, &reporter)
+        {
+            return Err(ConditionsError::Unauthorized);
+        }
+        if min_temp > max_temp || humidity_bps > MAX_HUMIDITY_BPS {
+            return Err(ConditionsError::InvalidConditionReport);
+        }
+
+        let report = ConditionReport {
+            reporter: reporter.clone(),
+            cellar_id: cellar_id.clone(),
+            min_temp,
+            max_temp,
+            humidity_bps,
+            payload_hash,
+            timestamp: e.ledger().timestamp(),
+        };
+        let (id, breached) = record_condition_report(&e, &report);
+
+        events::emit_conditions_reported_event(&e, id, reporter, cellar_id.clone());
+        if breached {
+            events::emit_condition_alert_event(&e, id, cellar_id, min_temp, max_temp, humidity_bps);
+        }
+        Ok(id)
+    }
+
+    /// Set the acceptable storage range for the lot (only the token admin can call)
+    ///
+    /// # Errors
+    /// * `ConditionsError::InvalidStorageRange` - If a minimum exceeds its maximum or humidity is above 100%
+    pub fn set_storage_range(e: Env, range: StorageRange) -> Result<(), ConditionsError> {
+        let admin = WineTokenClient::new(&e, &read_lot_token(&e)).admin();
+        admin.require_auth();
+        extend_instance_ttl(&e);
+
+        if range.min_temp > range.max_temp
+            || range.min_humidity_bps > range.max_humidity_bps
+            || range.max_humidity_bps > MAX_HUMIDITY_BPS
+        {
+            return Err(ConditionsError::InvalidStorageRange);
+        }
+
+        write_storage_range(&e, &range);
+        events::emit_storage_range_set_event(&e, range);
+        Ok(())
+    }
+
+    /// Get the acceptable storage range (defaults to 10–18 °C and 50–80% humidity)
+    pub fn storage_range(e: Env) -> StorageRange {
+        extend_instance_ttl(&e);
+        read_storage_range(&e)
+    }
+
+    /// Get the all-time condition aggregates, if any report has been recorded
+    pub fn get_condition_summary(e: Env) -> Option<ConditionSummary> {
+        extend_instance_ttl(&e);
+        read_condition_summary(&e)
+    }
+
+    /// Get a condition report by id
+    ///
+    /// # Errors
+    /// * `ConditionsError::ConditionReportNotFound` - If no report exists with `id`
+    pub fn get_condition_report(e: Env, id: u32) -> Result<ConditionReport, ConditionsError> {
+        extend_instance_ttl(&e);
+        read_condition_report(&e, id)
+    }
+
+    /// Get condition reports in ascending order, starting at `start` and returning at most `limit`
+    pub fn get_condition_reports(e: Env, start: u32, limit: u32) -> Vec<ConditionReport> {
+        extend_instance_ttl(&e);
+        read_condition_reports(&e, start, limit)
+    }
+
+    /// Get the lot token whose bottles are monitored
+    pub fn lot_token(e: Env) -> Address {
+        extend_instance_ttl(&e);
+        read_lot_token(&e)
+    }
+}
@@ -0,0 +1,34 @@
+use soroban_sdk::{contracttype, Address, BytesN, Symbol};
+
+// Storage Condition Report from a Cellar Sensor or Handler
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ConditionReport {
+    pub reporter: Address,
+    pub cellar_id: Symbol,
+    pub min_temp: i32,     // tenths of a degree Celsius
+    pub max_temp: i32,     // tenths of a degree Celsius
+    pub humidity_bps: u32, // relative humidity, 6500 = 65%
+    pub payload_hash: BytesN<32>,
+    pub timestamp: u64,
+}
+
+// Acceptable Storage Conditions for the Lot
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StorageRange {
+    pub min_temp: i32, // tenths of a degree Celsius
+    pub max_temp: i32,
+    pub min_humidity_bps: u32,
+    pub max_humidity_bps: u32,
+}
+
+// All-Time Aggregates over Condition Reports
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ConditionSummary {
+    pub report_count: u32,
+    pub out_of_range_count: u32,
+    pub min_temp: i32,
+    pub max_temp: i32,
+}
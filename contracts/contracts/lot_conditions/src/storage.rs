@@ -0,0 +1,137 @@
+use soroban_sdk::{contracttype, Address, Env, Vec};
+
+use crate::error::ConditionsError;
+use crate::models::{ConditionReport, ConditionSummary, StorageRange};
+
+pub(crate) const DAY_IN_LEDGERS: u32 = 17280;
+pub(crate) const INSTANCE_BUMP_AMOUNT: u32 = 30 * DAY_IN_LEDGERS;
+pub(crate) const INSTANCE_LIFETIME_THRESHOLD: u32 = INSTANCE_BUMP_AMOUNT - DAY_IN_LEDGERS;
+pub(crate) const PERSISTENT_BUMP_AMOUNT: u32 = 120 * DAY_IN_LEDGERS;
+pub(crate) const PERSISTENT_LIFETIME_THRESHOLD: u32 = PERSISTENT_BUMP_AMOUNT - 20 * DAY_IN_LEDGERS;
+
+/// Classic cellar conditions: 10–18 °C and 50–80% relative humidity.
+const DEFAULT_STORAGE_RANGE: StorageRange = StorageRange {
+    min_temp: 100,
+    max_temp: 180,
+    min_humidity_bps: 5000,
+    max_humidity_bps: 8000,
+};
+
+#[derive(Clone)]
+#[contracttype]
+pub enum DataKey {
+    LotToken,
+    StorageRange,
+    ConditionSummary,
+    ConditionReport(u32),
+}
+
+pub fn extend_instance_ttl(e: &Env) {
+    e.storage()
+        .instance()
+        .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+}
+
+fn extend_persistent_ttl(e: &Env, key: &DataKey) {
+    e.storage()
+        .persistent()
+        .extend_ttl(key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+}
+
+// Lot token
+pub fn read_lot_token(e: &Env) -> Address {
+    e.storage().instance().get(&DataKey::LotToken).unwrap()
+}
+
+pub fn write_lot_token(e: &Env, lot_token: &Address) {
+    e.storage().instance().set(&DataKey::LotToken, lot_token);
+}
+
+// Storage range
+pub fn read_storage_range(e: &Env) -> StorageRange {
+    e.storage()
+        .instance()
+        .get(&DataKey::StorageRange)
+        .unwrap_or(DEFAULT_STORAGE_RANGE)
+}
+
+pub fn write_storage_range(e: &Env, range: &StorageRange) {
+    e.storage().instance().set(&DataKey::StorageRange, range);
+}
+
+// Condition reports
+pub fn read_condition_summary(e: &Env) -> Option<ConditionSummary> {
+    e.storage().instance().get(&DataKey::ConditionSummary)
+}
+
+fn write_condition_summary(e: &Env, summary: &ConditionSummary) {
+    e.storage()
+        .instance()
+        .set(&DataKey::ConditionSummary, summary);
+}
+
+pub fn read_condition_report_count(e: &Env) -> u32 {
+    read_condition_summary(e).map_or(0, |summary| summary.report_count)
+}
+
+pub fn read_condition_report(e: &Env, id: u32) -> Result<ConditionReport, ConditionsError> {
+    let key = DataKey::ConditionReport(id);
+    let report = e
+        .storage()
+        .persistent()
+        .get::<DataKey, ConditionReport>(&key)
+        .ok_or(ConditionsError::ConditionReportNotFound)?;
+    extend_persistent_ttl(e, &key);
+    Ok(report)
+}
+
+/// Reads a page of condition reports in id order.
+pub fn read_condition_reports(e: &Env, start: u32, limit: u32) -> Vec<ConditionReport> {
+    let end = start
+        .saturating_add(limit)
+        .min(read_condition_report_count(e));
+    let mut reports = Vec::new(e);
+    for id in start..end {
+        if let Ok(report) = read_condition_report(e, id) {
+            reports.push_back(report);
+        }
+    }
+    reports
+}
+
+fn is_within_range(report: &ConditionReport, range: &StorageRange) -> bool {
+    report.min_temp >= range.min_temp
+        && report.max_temp <= range.max_temp
+        && report.humidity_bps >= range.min_humidity_bps
+        && report.humidity_bps <= range.max_humidity_bps
+}
+
+/// Stores a report and folds it into the all-time summary.
+///
+/// # Returns
+/// * The report id and whether it breached the storage range
+pub fn record_condition_report(e: &Env, report: &ConditionReport) -> (u32, bool) {
+    let in_range = is_within_range(report, &read_storage_range(e));
+    let summary = match read_condition_summary(e) {
+        Some(summary) => ConditionSummary {
+            report_count: summary.report_count.checked_add(1).unwrap(),
+            out_of_range_count: summary.out_of_range_count + !in_range as u32,
+            min_temp: summary.min_temp.min(report.min_temp),
+            max_temp: summary.max_temp.max(report.max_temp),
+        },
+        None => ConditionSummary {
+            report_count: 1,
+            out_of_range_count: !in_range as u32,
+            min_temp: report.min_temp,
+            max_temp: report.max_temp,
+        },
+    };
+
+    let id = summary.report_count - 1;
+    let key = DataKey::ConditionReport(id);
+    e.storage().persistent().set(&key, report);
+    extend_persistent_ttl(e, &key);
+    write_condition_summary(e, &summary);
+
+    (id, !in_range)
+}
@@ -0,0 +1,99 @@
+extern crate std;
+
+use soroban_sdk::testutils::{AuthorizedFunction, AuthorizedInvocation};
+use soroban_sdk::{symbol_short, BytesN, IntoVal, Symbol};
+
+use crate::models::StorageRange;
+use crate::test::ConditionsTest;
+use crate::ConditionsError;
+
+#[test]
+fn default_storage_range() {
+    let test = ConditionsTest::setup();
+
+    let range = test.conditions.storage_range();
+    assert_eq!(range.min_temp, 100);
+    assert_eq!(range.max_temp, 180);
+    assert_eq!(range.min_humidity_bps, 5000);
+    assert_eq!(range.max_humidity_bps, 8000);
+}
+
+#[test]
+fn custom_storage_range() {
+    let test = ConditionsTest::setup();
+    let hash = BytesN::from_array(&test.env, &[3u8; 32]);
+    let range = StorageRange {
+        min_temp: 120,
+        max_temp: 220,
+        min_humidity_bps: 4000,
+        max_humidity_bps: 9000,
+    };
+    test.conditions.set_storage_range(&range);
+    assert_eq!(test.conditions.storage_range(), range);
+
+    test.conditions.report_conditions(
+        &test.sensor,
+        &symbol_short!("cellar1"),
+        &150,
+        &210,
+        &8500,
+        &hash,
+    );
+    assert_eq!(
+        test.conditions
+            .get_condition_summary()
+            .unwrap()
+            .out_of_range_count,
+        0
+    );
+}
+
+#[test]
+fn set_storage_range_requires_token_admin() {
+    let test = ConditionsTest::setup();
+    let range = StorageRange {
+        min_temp: 120,
+        max_temp: 220,
+        min_humidity_bps: 4000,
+        max_humidity_bps: 9000,
+    };
+    test.conditions.set_storage_range(&range);
+
+    assert_eq!(
+        test.env.auths(),
+        std::vec![(
+            test.admin.clone(),
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    test.conditions.address.clone(),
+                    Symbol::new(&test.env, "set_storage_range"),
+                    (range,).into_val(&test.env),
+                )),
+                sub_invocations: std::vec![],
+            }
+        )]
+    );
+}
+
+#[test]
+fn invalid_storage_range_is_rejected() {
+    let test = ConditionsTest::setup();
+
+    let invalid = StorageRange {
+        min_temp: 200,
+        max_temp: 100,
+        min_humidity_bps: 4000,
+        max_humidity_bps: 9000,
+    };
+    let result = test.conditions.try_set_storage_range(&invalid);
+    assert_eq!(result, Err(Ok(ConditionsError::InvalidStorageRange)));
+
+    let invalid = StorageRange {
+        min_temp: 100,
+        max_temp: 200,
+        min_humidity_bps: 4000,
+        max_humidity_bps: 10_001,
+    };
+    let result = test.conditions.try_set_storage_range(&invalid);
+    assert_eq!(result, Err(Ok(ConditionsError::InvalidStorageRange)));
+}
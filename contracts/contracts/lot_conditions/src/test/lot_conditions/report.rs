@@ -0,0 +1,149 @@
+use soroban_sdk::testutils::{Address as _, Events};
+use soroban_sdk::{symbol_short, Address, BytesN, FromVal, IntoVal};
+
+use crate::events::ConditionAlertEvent;
+use crate::test::{wine_token_contract, ConditionsTest};
+use crate::ConditionsError;
+
+#[test]
+fn report_within_range() {
+    let test = ConditionsTest::setup();
+    let hash = BytesN::from_array(&test.env, &[3u8; 32]);
+
+    let id = test.conditions.report_conditions(
+        &test.sensor,
+        &symbol_short!("cellar1"),
+        &120,
+        &140,
+        &6500,
+        &hash,
+    );
+    assert_eq!(id, 0);
+
+    let report = test.conditions.get_condition_report(&0);
+    assert_eq!(report.reporter, test.sensor);
+    assert_eq!(report.cellar_id, symbol_short!("cellar1"));
+    assert_eq!(report.payload_hash, hash);
+
+    let summary = test.conditions.get_condition_summary().unwrap();
+    assert_eq!(summary.report_count, 1);
+    assert_eq!(summary.out_of_range_count, 0);
+    assert_eq!(summary.min_temp, 120);
+    assert_eq!(summary.max_temp, 140);
+}
+
+#[test]
+fn summary_tracks_extremes_and_breaches() {
+    let test = ConditionsTest::setup();
+    let hash = BytesN::from_array(&test.env, &[3u8; 32]);
+    let cellar = symbol_short!("cellar1");
+
+    test.conditions
+        .report_conditions(&test.admin, &cellar, &120, &140, &6500, &hash);
+    test.conditions
+        .report_conditions(&test.admin, &cellar, &150, &210, &6500, &hash);
+    test.conditions
+        .report_conditions(&test.admin, &cellar, &90, &130, &6000, &hash);
+    test.conditions
+        .report_conditions(&test.admin, &cellar, &110, &130, &8500, &hash);
+
+    let summary = test.conditions.get_condition_summary().unwrap();
+    assert_eq!(summary.report_count, 4);
+    assert_eq!(summary.out_of_range_count, 3);
+    assert_eq!(summary.min_temp, 90);
+    assert_eq!(summary.max_temp, 210);
+    assert_eq!(test.conditions.get_condition_reports(&1, &2).len(), 2);
+    assert_eq!(test.conditions.get_condition_reports(&3, &10).len(), 1);
+}
+
+#[test]
+fn breach_emits_alert() {
+    let test = ConditionsTest::setup();
+    let hash = BytesN::from_array(&test.env, &[3u8; 32]);
+    let cellar = symbol_short!("cellar1");
+
+    test.conditions
+        .report_conditions(&test.sensor, &cellar, &150, &210, &6500, &hash);
+
+    let event = test.env.events().all().last().unwrap();
+    assert_eq!(event.0, test.conditions.address);
+    assert_eq!(
+        event.1,
+        ("lotConditions", symbol_short!("alert")).into_val(&test.env)
+    );
+    let expected = ConditionAlertEvent {
+        id: 0,
+        cellar_id: cellar,
+        min_temp: 150,
+        max_temp: 210,
+        humidity_bps: 6500,
+    };
+    let data: ConditionAlertEvent = FromVal::from_val(&test.env, &event.2);
+    assert_eq!(data, expected);
+}
+
+#[test]
+fn invalid_report_is_rejected() {
+    let test = ConditionsTest::setup();
+    let hash = BytesN::from_array(&test.env, &[3u8; 32]);
+    let cellar = symbol_short!("cellar1");
+
+    let result =
+        test.conditions
+            .try_report_conditions(&test.sensor, &cellar, &150, &140, &6500, &hash);
+    assert_eq!(result, Err(Ok(ConditionsError::InvalidConditionReport)));
+
+    let result =
+        test.conditions
+            .try_report_conditions(&test.sensor, &cellar, &120, &140, &10_001, &hash);
+    assert_eq!(result, Err(Ok(ConditionsError::InvalidConditionReport)));
+}
+
+#[test]
+fn status_updater_can_report_but_stranger_cannot() {
+    let test = ConditionsTest::setup();
+    let handler = Address::generate(&test.env);
+    let hash = BytesN::from_array(&test.env, &[3u8; 32]);
+    let cellar = symbol_short!("cellar1");
+
+    let result = test
+        .conditions
+        .try_report_conditions(&handler, &cellar, &120, &140, &6500, &hash);
+    assert_eq!(result, Err(Ok(ConditionsError::Unauthorized)));
+
+    test.lot_token
+        .grant_role(&wine_token_contract::Role::StatusUpdater, &handler);
+    test.conditions
+        .report_conditions(&handler, &cellar, &120, &140, &6500, &hash);
+    assert_eq!(
+        test.conditions
+            .get_condition_summary()
+            .unwrap()
+            .report_count,
+        1
+    );
+}
+
+#[test]
+fn revoked_sensor_cannot_report() {
+    let test = ConditionsTest::setup();
+    let hash = BytesN::from_array(&test.env, &[3u8; 32]);
+    let cellar = symbol_short!("cellar1");
+
+    test.lot_token
+        .revoke_role(&wine_token_contract::Role::Sensor, &test.sensor);
+    let result =
+        test.conditions
+            .try_report_conditions(&test.sensor, &cellar, &120, &140, &6500, &hash);
+    assert_eq!(result, Err(Ok(ConditionsError::Unauthorized)));
+}
+
+#[test]
+fn unknown_report_is_not_found() {
+    let test = ConditionsTest::setup();
+
+    let result = test.conditions.try_get_condition_report(&0);
+    assert_eq!(result, Err(Ok(ConditionsError::ConditionReportNotFound)));
+    assert_eq!(test.conditions.get_condition_summary(), None);
+    assert_eq!(test.conditions.get_condition_reports(&0, &10).len(), 0);
+}
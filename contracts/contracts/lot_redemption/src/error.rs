@@ -0,0 +1,10 @@
+use soroban_sdk::{self, contracterror};
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum RedemptionError {
+    InvalidBottleCount = 1,
+    RedemptionNotFound = 2,
+    RedemptionNotPending = 3,
+}
@@ -0,0 +1,67 @@
+use soroban_sdk::{contracttype, symbol_short, Address, BytesN, Env};
+
+// REDEMPTION REQUESTED EVENT
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RedemptionRequestedEvent {
+    pub id: u32,
+    pub holder: Address,
+    pub bottles: u32,
+    pub amount: i128,
+    pub shipping_ref_hash: BytesN<32>,
+}
+
+/// Publishes a `RedemptionRequestedEvent` to the event stream.
+pub(crate) fn emit_redemption_requested_event(
+    e: &Env,
+    id: u32,
+    holder: Address,
+    bottles: u32,
+    amount: i128,
+    shipping_ref_hash: BytesN<32>,
+) {
+    let event = RedemptionRequestedEvent {
+        id,
+        holder,
+        bottles,
+        amount,
+        shipping_ref_hash,
+    };
+
+    e.events()
+        .publish(("lotRedemption", symbol_short!("requested")), event);
+}
+
+// REDEMPTION FULFILLED EVENT
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RedemptionFulfilledEvent {
+    pub id: u32,
+    pub holder: Address,
+    pub amount: i128,
+}
+
+/// Publishes a `RedemptionFulfilledEvent` to the event stream.
+pub(crate) fn emit_redemption_fulfilled_event(e: &Env, id: u32, holder: Address, amount: i128) {
+    let event = RedemptionFulfilledEvent { id, holder, amount };
+
+    e.events()
+        .publish(("lotRedemption", symbol_short!("fulfilled")), event);
+}
+
+// REDEMPTION REJECTED EVENT
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RedemptionRejectedEvent {
+    pub id: u32,
+    pub holder: Address,
+    pub amount: i128,
+}
+
+/// Publishes a `RedemptionRejectedEvent` to the event stream.
+pub(crate) fn emit_redemption_rejected_event(e: &Env, id: u32, holder: Address, amount: i128) {
+    let event = RedemptionRejectedEvent { id, holder, amount };
+
+    e.events()
+        .publish(("lotRedemption", symbol_short!("rejected")), event);
+}
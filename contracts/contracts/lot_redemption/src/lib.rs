@@ -0,0 +1,168 @@
+#![no_std]
+
+use soroban_sdk::{contract, contractimpl, token, Address, BytesN, Env};
+
+mod error;
+mod events;
+pub mod models;
+mod storage;
+mod test;
+mod wine_token;
+
+use models::{Redemption, RedemptionStatus};
+use storage::{
+    extend_instance_ttl, next_redemption_id, read_lot_token, read_redemption,
+    read_redemption_count, write_lot_token, write_redemption,
+};
+use wine_token::WineTokenClient;
+
+pub use error::RedemptionError;
+
+/// Reads a pending redemption after checking the token admin's signature.
+fn read_pending_redemption(e: &Env, id: u32) -> Result<Redemption, RedemptionError> {
+    WineTokenClient::new(e, &read_lot_token(e))
+        .admin()
+        .require_auth();
+    extend_instance_ttl(e);
+
+    let redemption = read_redemption(e, id)?;
+    if redemption.status != RedemptionStatus::Pending {
+        return Err(RedemptionError::RedemptionNotPending);
+    }
+    Ok(redemption)
+}
+
+#[contract]
+pub struct LotRedemption;
+
+#[contractimpl]
+impl LotRedemption {
+    /// Initialize the bottle redemption desk of a wine lot
+    ///
+    /// Escrowed tokens move with the token's own `transfer` and `burn`, so
+    /// every balance change publishes the SEP-41 events and follows the
+    /// token's freeze, recall and compliance checks. When compliance is
+    /// enabled on the token, this contract must be allowlisted.
+    ///
+    /// # Arguments
+    /// * `lot_token` - Address of the `WineToken` redeemed for bottles
+    pub fn __constructor(e: Env, lot_token: Address) {
+        write_lot_token(&e, &lot_token);
+        extend_instance_ttl(&e);
+    }
+
+    /// Request redemption of whole bottles (holder must sign)
+    ///
+    /// Transfers `bottles * 10^decimals` tokens from the holder into escrow held
+    /// by this contract until the winery fulfils or rejects the request.
+    ///
+    /// # Arguments
+    /// * `holder` - Token holder claiming the bottles
+    /// * `bottles` - Number of whole bottles to redeem
+    /// * `shipping_ref_hash` - Hash of the off-chain shipping reference
+    ///
+    /// # Returns
+    /// * Id of the new redemption request
+    ///
+    /// # Errors
+    /// * `RedemptionError::InvalidBottleCount` - If `bottles` is zero or too large
+    pub fn request_redemption(
+        e: Env,
+        holder: Address,
+        bottles: u32,
+        shipping_ref_hash: BytesN<32>,
+    ) -> Result<u32, RedemptionError> {
+        holder.require_auth();
+        extend_instance_ttl(&e);
+
+        if bottles == 0 {
+            return Err(RedemptionError::InvalidBottleCount);
+        }
+
+        let lot_token = token::Client::new(&e, &read_lot_token(&e));
+        let amount = (bottles as i128)
+            .checked_mul(10i128.pow(lot_token.decimals()))
+            .ok_or(RedemptionError::InvalidBottleCount)?;
+        lot_token.transfer(&holder, &e.current_contract_address(), &amount);
+
+        let id = next_redemption_id(&e);
+        write_redemption(
+            &e,
+            &Redemption {
+                id,
+                holder: holder.clone(),
+                bottles,
+                amount,
+                shipping_ref_hash: shipping_ref_hash.clone(),
+                status: RedemptionStatus::Pending,
+                requested_at: e.ledger().timestamp(),
+                resolved_at: None,
+            },
+        );
+
+        events::emit_redemption_requested_event(&e, id, holder, bottles, amount, shipping_ref_hash);
+        Ok(id)
+    }
+
+    /// Fulfil a pending redemption by burning the escrowed tokens (only the token admin can call)
+    ///
+    /// # Errors
+    /// * `RedemptionError::RedemptionNotFound` - If no redemption exists with `id`
+    /// * `RedemptionError::RedemptionNotPending` - If the redemption was already resolved
+    pub fn fulfill_redemption(e: Env, id: u32) -> Result<(), RedemptionError> {
+        let mut redemption = read_pending_redemption(&e, id)?;
+
+        token::Client::new(&e, &read_lot_token(&e))
+            .burn(&e.current_contract_address(), &redemption.amount);
+
+        redemption.status = RedemptionStatus::Fulfilled;
+        redemption.resolved_at = Some(e.ledger().timestamp());
+        write_redemption(&e, &redemption);
+
+        events::emit_redemption_fulfilled_event(&e, id, redemption.holder, redemption.amount);
+        Ok(())
+    }
+
+    /// Reject a pending redemption and refund the escrowed tokens (only the token admin can call)
+    ///
+    /// # Errors
+    /// * `RedemptionError::RedemptionNotFound` - If no redemption exists with `id`
+    /// * `RedemptionError::RedemptionNotPending` - If the redemption was already resolved
+    pub fn reject_redemption(e: Env, id: u32) -> Result<(), RedemptionError> {
+        let mut redemption = read_pending_redemption(&e, id)?;
+
+        token::Client::new(&e, &read_lot_token(&e)).transfer(
+            &e.current_contract_address(),
+            &redemption.holder,
+            &redemption.amount,
+        );
+
+        redemption.status = RedemptionStatus::Rejected;
+        redemption.resolved_at = Some(e.ledger().timestamp());
+        write_redemption(&e, &redemption);
+
+        events::emit_redemption_rejected_event(&e, id, redemption.holder, redemption.amount);
+        Ok(())
+    }
+
+    /// Get a redemption request by id
+    ///
+    /// # Errors
+    /// * `RedemptionError::RedemptionNotFound` - If no redemption exists with `id`
+    pub fn get_redemption(e: Env, id: u32) -> Result<Redemption, RedemptionError> {
+        extend_instance_ttl(&e);
+        read_redemption(&e, id)
+    }
+
+    /// Get the number of redemption requests
+    pub fn redemption_count(e: Env) -> u32 {
+        extend_instance_ttl(&e);
+        read_redemption_count(&e)
+    }
+
+    /// Get the lot token redeemed for bottles
+    pub fn lot_token(e: Env) -> Address {
+        extend_instance_ttl(&e);
+        read_lot_token(&e)
+    }
+}
@@ -0,0 +1,23 @@
+use soroban_sdk::{contracttype, Address, BytesN};
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum RedemptionStatus {
+    Pending,
+    Fulfilled,
+    Rejected,
+}
+
+// Bottle Redemption Request
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Redemption {
+    pub id: u32,
+    pub holder: Address,
+    pub bottles: u32,
+    pub amount: i128, // tokens held in escrow
+    pub shipping_ref_hash: BytesN<32>,
+    pub status: RedemptionStatus,
+    pub requested_at: u64,
+    pub resolved_at: Option<u64>,
+}
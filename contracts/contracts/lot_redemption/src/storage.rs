@@ -0,0 +1,73 @@
+use soroban_sdk::{contracttype, Address, Env};
+
+use crate::error::RedemptionError;
+use crate::models::Redemption;
+
+pub(crate) const DAY_IN_LEDGERS: u32 = 17280;
+pub(crate) const INSTANCE_BUMP_AMOUNT: u32 = 30 * DAY_IN_LEDGERS;
+pub(crate) const INSTANCE_LIFETIME_THRESHOLD: u32 = INSTANCE_BUMP_AMOUNT - DAY_IN_LEDGERS;
+pub(crate) const PERSISTENT_BUMP_AMOUNT: u32 = 120 * DAY_IN_LEDGERS;
+pub(crate) const PERSISTENT_LIFETIME_THRESHOLD: u32 = PERSISTENT_BUMP_AMOUNT - 20 * DAY_IN_LEDGERS;
+
+#[derive(Clone)]
+#[contracttype]
+pub enum DataKey {
+    LotToken,
+    RedemptionCount,
+    Redemption(u32),
+}
+
+pub fn extend_instance_ttl(e: &Env) {
+    e.storage()
+        .instance()
+        .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+}
+
+fn extend_persistent_ttl(e: &Env, key: &DataKey) {
+    e.storage()
+        .persistent()
+        .extend_ttl(key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+}
+
+// Lot token
+pub fn read_lot_token(e: &Env) -> Address {
+    e.storage().instance().get(&DataKey::LotToken).unwrap()
+}
+
+pub fn write_lot_token(e: &Env, lot_token: &Address) {
+    e.storage().instance().set(&DataKey::LotToken, lot_token);
+}
+
+// Redemptions
+pub fn read_redemption_count(e: &Env) -> u32 {
+    e.storage()
+        .instance()
+        .get(&DataKey::RedemptionCount)
+        .unwrap_or(0)
+}
+
+/// Returns the next redemption id and bumps the counter.
+pub fn next_redemption_id(e: &Env) -> u32 {
+    let id = read_redemption_count(e);
+    e.storage()
+        .instance()
+        .set(&DataKey::RedemptionCount, &id.checked_add(1).unwrap());
+    id
+}
+
+pub fn read_redemption(e: &Env, id: u32) -> Result<Redemption, RedemptionError> {
+    let key = DataKey::Redemption(id);
+    let redemption = e
+        .storage()
+        .persistent()
+        .get::<DataKey, Redemption>(&key)
+        .ok_or(RedemptionError::RedemptionNotFound)?;
+    extend_persistent_ttl(e, &key);
+    Ok(redemption)
+}
+
+pub fn write_redemption(e: &Env, redemption: &Redemption) {
+    let key = DataKey::Redemption(redemption.id);
+    e.storage().persistent().set(&key, redemption);
+    extend_persistent_ttl(e, &key);
+}
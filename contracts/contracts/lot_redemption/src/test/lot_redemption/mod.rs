@@ -0,0 +1,2 @@
+mod request;
+mod resolve;
@@ -0,0 +1,115 @@
+use soroban_sdk::testutils::{Address as _, Events};
+use soroban_sdk::{symbol_short, Address, BytesN, Env, FromVal, IntoVal};
+
+use crate::models::RedemptionStatus;
+use crate::test::{create_lot_redemption, create_wine_token, wine_token_contract, RedemptionTest};
+use crate::RedemptionError;
+
+#[test]
+fn request_moves_tokens_to_escrow() {
+    let test = RedemptionTest::setup();
+    let shipping_ref_hash = BytesN::from_array(&test.env, &[7u8; 32]);
+
+    let id = test
+        .desk
+        .request_redemption(&test.user, &3, &shipping_ref_hash);
+
+    assert_eq!(id, 0);
+    assert_eq!(test.desk.redemption_count(), 1);
+    assert_eq!(test.lot_token.balance(&test.user), 7);
+    assert_eq!(test.lot_token.balance(&test.desk.address), 3);
+
+    let redemption = test.desk.get_redemption(&id);
+    assert_eq!(redemption.holder, test.user);
+    assert_eq!(redemption.bottles, 3);
+    assert_eq!(redemption.amount, 3);
+    assert_eq!(redemption.shipping_ref_hash, shipping_ref_hash);
+    assert_eq!(redemption.status, RedemptionStatus::Pending);
+    assert_eq!(redemption.resolved_at, None);
+}
+
+#[test]
+fn request_emits_token_transfer() {
+    let test = RedemptionTest::setup();
+    let shipping_ref_hash = BytesN::from_array(&test.env, &[7u8; 32]);
+
+    test.desk
+        .request_redemption(&test.user, &3, &shipping_ref_hash);
+
+    let events = test.env.events().all();
+    let transfer = events.get(events.len() - 2).unwrap();
+    assert_eq!(transfer.0, test.lot_token.address);
+    assert_eq!(
+        transfer.1,
+        (
+            symbol_short!("transfer"),
+            test.user.clone(),
+            test.desk.address.clone()
+        )
+            .into_val(&test.env)
+    );
+    assert_eq!(i128::from_val(&test.env, &transfer.2), 3);
+
+    let requested = events.last().unwrap();
+    assert_eq!(requested.0, test.desk.address);
+    assert_eq!(
+        requested.1,
+        ("lotRedemption", symbol_short!("requested")).into_val(&test.env)
+    );
+}
+
+#[test]
+fn invalid_requests() {
+    let test = RedemptionTest::setup();
+    let shipping_ref_hash = BytesN::from_array(&test.env, &[7u8; 32]);
+
+    let result = test
+        .desk
+        .try_request_redemption(&test.user, &0, &shipping_ref_hash);
+    assert_eq!(result, Err(Ok(RedemptionError::InvalidBottleCount)));
+
+    let result = test.desk.try_get_redemption(&5);
+    assert_eq!(result, Err(Ok(RedemptionError::RedemptionNotFound)));
+}
+
+#[test]
+fn redemption_uses_whole_bottle_units() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let user = Address::generate(&e);
+    let token = create_wine_token(&e, &admin, 7);
+    let desk = create_lot_redemption(&e, &token.address);
+    let shipping_ref_hash = BytesN::from_array(&e, &[1u8; 32]);
+
+    token.mint(&admin, &user, &25_000_000);
+    let id = desk.request_redemption(&user, &2, &shipping_ref_hash);
+
+    assert_eq!(desk.get_redemption(&id).amount, 20_000_000);
+    assert_eq!(token.balance(&user), 5_000_000);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #141)")]
+fn recall_pauses_redemptions() {
+    let test = RedemptionTest::setup();
+
+    test.lot_token.set_status(
+        &test.admin,
+        &wine_token_contract::LotStatus::Recalled,
+        &None,
+    );
+    test.desk
+        .request_redemption(&test.user, &1, &BytesN::from_array(&test.env, &[0u8; 32]));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #140)")]
+fn frozen_holder_cannot_request() {
+    let test = RedemptionTest::setup();
+
+    test.lot_token.freeze(&test.user);
+    test.desk
+        .request_redemption(&test.user, &1, &BytesN::from_array(&test.env, &[0u8; 32]));
+}
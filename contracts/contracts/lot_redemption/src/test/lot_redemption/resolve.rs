@@ -0,0 +1,110 @@
+extern crate std;
+
+use soroban_sdk::testutils::{AuthorizedFunction, AuthorizedInvocation, Events};
+use soroban_sdk::{symbol_short, BytesN, FromVal, IntoVal, Symbol};
+
+use crate::models::RedemptionStatus;
+use crate::test::RedemptionTest;
+use crate::RedemptionError;
+
+#[test]
+fn fulfill_burns_escrow() {
+    let test = RedemptionTest::setup();
+    let shipping_ref_hash = BytesN::from_array(&test.env, &[7u8; 32]);
+
+    let id = test
+        .desk
+        .request_redemption(&test.user, &4, &shipping_ref_hash);
+    test.desk.fulfill_redemption(&id);
+
+    let events = test.env.events().all();
+    let burn = events.get(events.len() - 2).unwrap();
+    assert_eq!(burn.0, test.lot_token.address);
+    assert_eq!(
+        burn.1,
+        (symbol_short!("burn"), test.desk.address.clone()).into_val(&test.env)
+    );
+    assert_eq!(i128::from_val(&test.env, &burn.2), 4);
+
+    assert_eq!(test.lot_token.balance(&test.desk.address), 0);
+    assert_eq!(test.lot_token.balance(&test.user), 6);
+    assert_eq!(test.lot_token.total_supply(), 6);
+    let redemption = test.desk.get_redemption(&id);
+    assert_eq!(redemption.status, RedemptionStatus::Fulfilled);
+    assert!(redemption.resolved_at.is_some());
+}
+
+#[test]
+fn reject_refunds_holder() {
+    let test = RedemptionTest::setup();
+    let shipping_ref_hash = BytesN::from_array(&test.env, &[7u8; 32]);
+
+    let id = test
+        .desk
+        .request_redemption(&test.user, &4, &shipping_ref_hash);
+    test.desk.reject_redemption(&id);
+
+    let events = test.env.events().all();
+    let refund = events.get(events.len() - 2).unwrap();
+    assert_eq!(refund.0, test.lot_token.address);
+    assert_eq!(
+        refund.1,
+        (
+            symbol_short!("transfer"),
+            test.desk.address.clone(),
+            test.user.clone()
+        )
+            .into_val(&test.env)
+    );
+    assert_eq!(i128::from_val(&test.env, &refund.2), 4);
+
+    assert_eq!(test.lot_token.balance(&test.desk.address), 0);
+    assert_eq!(test.lot_token.balance(&test.user), 10);
+    assert_eq!(test.lot_token.total_supply(), 10);
+    assert_eq!(
+        test.desk.get_redemption(&id).status,
+        RedemptionStatus::Rejected
+    );
+}
+
+#[test]
+fn resolved_redemption_cannot_be_resolved_again() {
+    let test = RedemptionTest::setup();
+    let shipping_ref_hash = BytesN::from_array(&test.env, &[7u8; 32]);
+
+    let id = test
+        .desk
+        .request_redemption(&test.user, &4, &shipping_ref_hash);
+    test.desk.fulfill_redemption(&id);
+
+    let result = test.desk.try_reject_redemption(&id);
+    assert_eq!(result, Err(Ok(RedemptionError::RedemptionNotPending)));
+    let result = test.desk.try_fulfill_redemption(&id);
+    assert_eq!(result, Err(Ok(RedemptionError::RedemptionNotPending)));
+}
+
+#[test]
+fn fulfill_requires_token_admin() {
+    let test = RedemptionTest::setup();
+    let shipping_ref_hash = BytesN::from_array(&test.env, &[7u8; 32]);
+
+    let id = test
+        .desk
+        .request_redemption(&test.user, &4, &shipping_ref_hash);
+    test.desk.fulfill_redemption(&id);
+
+    assert_eq!(
+        test.env.auths(),
+        std::vec![(
+            test.admin.clone(),
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    test.desk.address.clone(),
+                    Symbol::new(&test.env, "fulfill_redemption"),
+                    (id,).into_val(&test.env),
+                )),
+                sub_invocations: std::vec![],
+            }
+        )]
+    );
+}
@@ -0,0 +1,9 @@
+use soroban_sdk::{contractclient, Address, Env};
+
+/// Subset of the `WineToken` interface used by the redemption desk, on top of
+/// the SEP-41 token interface.
+#[allow(dead_code)]
+#[contractclient(name = "WineTokenClient")]
+pub trait WineTokenInterface {
+    fn admin(e: Env) -> Address;
+}
@@ -0,0 +1,16 @@
+use soroban_sdk::{self, contracterror};
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum DistributorError {
+    DistributionNotFound = 1,
+    InvalidAmount = 2,
+    InvalidExpiry = 3,
+    EmptySnapshot = 4,
+    AlreadyClaimed = 5,
+    NothingToClaim = 6,
+    DistributionExpired = 7,
+    DistributionNotExpired = 8,
+    AlreadyReclaimed = 9,
+}
@@ -0,0 +1,241 @@
+#![no_std]
+
+use soroban_sdk::{contract, contractimpl, token, Address, Env, U256};
+
+mod error;
+pub mod models;
+mod storage;
+mod test;
+mod wine_token;
+
+use models::Distribution;
+use storage::{
+    extend_instance_ttl, has_claimed, next_distribution_id, read_administrator,
+    read_distribution, read_distribution_count, read_lot_token, read_payment_token,
+    write_administrator, write_claimed, write_distribution, write_lot_token, write_payment_token,
+};
+use wine_token::WineTokenClient;
+
+/// Holder's pro rata share of a distribution, rounded down. The rounding dust
+/// stays in the contract and goes back to the depositor with the unclaimed funds.
+///
+/// The product is taken in 256 bits so large deposits cannot overflow; the
+/// result never exceeds `amount` because the balance is at most the supply.
+fn share_of(e: &Env, distribution: &Distribution, holder: &Address) -> i128 {
+    let lot_token = WineTokenClient::new(e, &read_lot_token(e));
+    let balance = lot_token.balance_of_at(holder, &distribution.snapshot_id);
+    let to_u256 = |value: i128| U256::from_u128(e, u128::try_from(value).unwrap());
+    let share = to_u256(distribution.amount)
+        .mul(&to_u256(balance))
+        .div(&to_u256(distribution.total_supply));
+    i128::try_from(share.to_u128().unwrap()).unwrap()
+}
+
+pub use error::DistributorError;
+
+#[contract]
+pub struct ProceedsDistributor;
+
+#[contractimpl]
+impl ProceedsDistributor {
+    /// Initialize a distributor for a wine lot
+    ///
+    /// # Arguments
+    /// * `admin` - Administrator address (typically the winery)
+    /// * `lot_token` - Address of the `WineToken` whose holders are paid
+    /// * `payment_token` - Address of the asset proceeds are paid in (e.g. a stablecoin)
+    pub fn __constructor(e: Env, admin: Address, lot_token: Address, payment_token: Address) {
+        write_administrator(&e, &admin);
+        write_lot_token(&e, &lot_token);
+        write_payment_token(&e, &payment_token);
+        extend_instance_ttl(&e);
+    }
+
+    /// Deposit proceeds for the holders of a lot token snapshot (only admin/winery can call)
+    ///
+    /// The snapshot must have been taken on the lot token beforehand with
+    /// `snapshot`. Holders can claim until `expiry_ledger`; afterwards the admin
+    /// can reclaim whatever is left.
+    ///
+    /// # Arguments
+    /// * `snapshot_id` - Lot token snapshot the proceeds are split against
+    /// * `amount` - Amount of the payment asset to distribute
+    /// * `expiry_ledger` - Last ledger on which holders can claim
+    ///
+    /// # Returns
+    /// * Id of the new distribution
+    ///
+    /// # Errors
+    /// * `DistributorError::InvalidAmount` - If `amount` is not positive
+    /// * `DistributorError::InvalidExpiry` - If `expiry_ledger` is not in the future
+    /// * `DistributorError::EmptySnapshot` - If no lot tokens existed at the snapshot
+    pub fn deposit(
+        e: Env,
+        snapshot_id: u32,
+        amount: i128,
+        expiry_ledger: u32,
+    ) -> Result<u32, DistributorError> {
+        let admin = read_administrator(&e);
+        admin.require_auth();
+        extend_instance_ttl(&e);
+
+        if amount <= 0 {
+            return Err(DistributorError::InvalidAmount);
+        }
+        if expiry_ledger <= e.ledger().sequence() {
+            return Err(DistributorError::InvalidExpiry);
+        }
+        let lot_token = WineTokenClient::new(&e, &read_lot_token(&e));
+        let total_supply = lot_token.total_supply_at(&snapshot_id);
+        if total_supply <= 0 {
+            return Err(DistributorError::EmptySnapshot);
+        }
+
+        let payment_token = token::Client::new(&e, &read_payment_token(&e));
+        payment_token.transfer(&admin, &e.current_contract_address(), &amount);
+
+        let id = next_distribution_id(&e);
+        write_distribution(
+            &e,
+            &Distribution {
+                id,
+                snapshot_id,
+                amount,
+                total_supply,
+                claimed: 0,
+                expiry_ledger,
+                reclaimed: false,
+            },
+        );
+
+        e.events()
+            .publish(("deposit", id), (snapshot_id, amount, expiry_ledger));
+        Ok(id)
+    }
+
+    /// Claim a holder's share of a distribution (holder must sign)
+    ///
+    /// # Returns
+    /// * Amount of the payment asset paid out
+    ///
+    /// # Errors
+    /// * `DistributorError::DistributionNotFound` - If the distribution does not exist
+    /// * `DistributorError::DistributionExpired` - If the claim window has closed
+    /// * `DistributorError::AlreadyClaimed` - If `holder` already claimed
+    /// * `DistributorError::NothingToClaim` - If the holder's share rounds down to zero
+    pub fn claim(e: Env, holder: Address, distribution_id: u32) -> Result<i128, DistributorError> {
+        holder.require_auth();
+        extend_instance_ttl(&e);
+
+        let mut distribution = read_distribution(&e, distribution_id)?;
+        if e.ledger().sequence() > distribution.expiry_ledger {
+            return Err(DistributorError::DistributionExpired);
+        }
+        if has_claimed(&e, distribution_id, holder.clone()) {
+            return Err(DistributorError::AlreadyClaimed);
+        }
+        let share = share_of(&e, &distribution, &holder);
+        if share == 0 {
+            return Err(DistributorError::NothingToClaim);
+        }
+
+        write_claimed(&e, distribution_id, holder.clone());
+        distribution.claimed += share;
+        write_distribution(&e, &distribution);
+
+        let payment_token = token::Client::new(&e, &read_payment_token(&e));
+        payment_token.transfer(&e.current_contract_address(), &holder, &share);
+
+        e.events()
+            .publish(("claim", distribution_id), (holder, share));
+        Ok(share)
+    }
+
+    /// Return unclaimed funds and rounding dust to the admin once a distribution expires
+    /// (only admin/winery can call)
+    ///
+    /// # Returns
+    /// * Amount of the payment asset returned
+    ///
+    /// # Errors
+    /// * `DistributorError::DistributionNotFound` - If the distribution does not exist
+    /// * `DistributorError::DistributionNotExpired` - If holders can still claim
+    /// * `DistributorError::AlreadyReclaimed` - If the funds were already returned
+    pub fn reclaim(e: Env, distribution_id: u32) -> Result<i128, DistributorError> {
+        let admin = read_administrator(&e);
+        admin.require_auth();
+        extend_instance_ttl(&e);
+
+        let mut distribution = read_distribution(&e, distribution_id)?;
+        if e.ledger().sequence() <= distribution.expiry_ledger {
+            return Err(DistributorError::DistributionNotExpired);
+        }
+        if distribution.reclaimed {
+            return Err(DistributorError::AlreadyReclaimed);
+        }
+
+        let remaining = distribution.amount - distribution.claimed;
+        distribution.reclaimed = true;
+        write_distribution(&e, &distribution);
+
+        if remaining > 0 {
+            let payment_token = token::Client::new(&e, &read_payment_token(&e));
+            payment_token.transfer(&e.current_contract_address(), &admin, &remaining);
+        }
+
+        e.events()
+            .publish(("reclaim", distribution_id), (admin, remaining));
+        Ok(remaining)
+    }
+
+    /// Get the amount a holder can still claim from a distribution
+    ///
+    /// Returns 0 once the holder has claimed or the distribution has expired.
+    pub fn claimable(e: Env, holder: Address, distribution_id: u32) -> Result<i128, DistributorError> {
+        extend_instance_ttl(&e);
+
+        let distribution = read_distribution(&e, distribution_id)?;
+        if e.ledger().sequence() > distribution.expiry_ledger
+            || has_claimed(&e, distribution_id, holder.clone())
+        {
+            return Ok(0);
+        }
+        Ok(share_of(&e, &distribution, &holder))
+    }
+
+    /// Check whether a holder has claimed from a distribution
+    pub fn has_claimed(e: Env, holder: Address, distribution_id: u32) -> bool {
+        extend_instance_ttl(&e);
+        has_claimed(&e, distribution_id, holder)
+    }
+
+    /// Get a distribution by id
+    pub fn get_distribution(e: Env, distribution_id: u32) -> Result<Distribution, DistributorError> {
+        extend_instance_ttl(&e);
+        read_distribution(&e, distribution_id)
+    }
+
+    /// Get the number of distributions
+    pub fn distribution_count(e: Env) -> u32 {
+        extend_instance_ttl(&e);
+        read_distribution_count(&e)
+    }
+
+    /// Get the wine lot token whose holders are paid
+    pub fn lot_token(e: Env) -> Address {
+        extend_instance_ttl(&e);
+        read_lot_token(&e)
+    }
+
+    /// Get the asset proceeds are paid in
+    pub fn payment_token(e: Env) -> Address {
+        extend_instance_ttl(&e);
+        read_payment_token(&e)
+    }
+
+    /// Get current admin
+    pub fn admin(e: Env) -> Address {
+        extend_instance_ttl(&e);
+        read_administrator(&e)
+    }
+}
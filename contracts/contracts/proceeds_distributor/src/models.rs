@@ -0,0 +1,14 @@
+use soroban_sdk::contracttype;
+
+// Proceeds deposited against a lot token snapshot
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Distribution {
+    pub id: u32,
+    pub snapshot_id: u32,
+    pub amount: i128,       // Payment asset deposited
+    pub total_supply: i128, // Lot token supply at the snapshot
+    pub claimed: i128,      // Payment asset paid out so far
+    pub expiry_ledger: u32, // Last ledger on which holders can claim
+    pub reclaimed: bool,
+}
@@ -0,0 +1,104 @@
+use soroban_sdk::{contracttype, Address, Env};
+
+use crate::error::DistributorError;
+use crate::models::Distribution;
+
+pub(crate) const DAY_IN_LEDGERS: u32 = 17280;
+pub(crate) const INSTANCE_BUMP_AMOUNT: u32 = 30 * DAY_IN_LEDGERS;
+pub(crate) const INSTANCE_LIFETIME_THRESHOLD: u32 = INSTANCE_BUMP_AMOUNT - DAY_IN_LEDGERS;
+pub(crate) const PERSISTENT_BUMP_AMOUNT: u32 = 120 * DAY_IN_LEDGERS;
+pub(crate) const PERSISTENT_LIFETIME_THRESHOLD: u32 = PERSISTENT_BUMP_AMOUNT - 20 * DAY_IN_LEDGERS;
+
+#[derive(Clone)]
+#[contracttype]
+pub enum DataKey {
+    Admin,
+    LotToken,
+    PaymentToken,
+    DistributionCount,
+    Distribution(u32),
+    Claimed(u32, Address),
+}
+
+pub fn extend_instance_ttl(e: &Env) {
+    e.storage()
+        .instance()
+        .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+}
+
+fn extend_persistent_ttl(e: &Env, key: &DataKey) {
+    e.storage()
+        .persistent()
+        .extend_ttl(key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+}
+
+// Admin
+pub fn read_administrator(e: &Env) -> Address {
+    e.storage().instance().get(&DataKey::Admin).unwrap()
+}
+
+pub fn write_administrator(e: &Env, admin: &Address) {
+    e.storage().instance().set(&DataKey::Admin, admin);
+}
+
+// Lot token and payment asset
+pub fn read_lot_token(e: &Env) -> Address {
+    e.storage().instance().get(&DataKey::LotToken).unwrap()
+}
+
+pub fn write_lot_token(e: &Env, lot_token: &Address) {
+    e.storage().instance().set(&DataKey::LotToken, lot_token);
+}
+
+pub fn read_payment_token(e: &Env) -> Address {
+    e.storage().instance().get(&DataKey::PaymentToken).unwrap()
+}
+
+pub fn write_payment_token(e: &Env, payment_token: &Address) {
+    e.storage().instance().set(&DataKey::PaymentToken, payment_token);
+}
+
+// Distributions
+pub fn read_distribution_count(e: &Env) -> u32 {
+    e.storage()
+        .instance()
+        .get(&DataKey::DistributionCount)
+        .unwrap_or(0)
+}
+
+/// Returns the next distribution id and bumps the counter.
+pub fn next_distribution_id(e: &Env) -> u32 {
+    let id = read_distribution_count(e);
+    e.storage()
+        .instance()
+        .set(&DataKey::DistributionCount, &id.checked_add(1).unwrap());
+    id
+}
+
+pub fn read_distribution(e: &Env, id: u32) -> Result<Distribution, DistributorError> {
+    let key = DataKey::Distribution(id);
+    let distribution = e
+        .storage()
+        .persistent()
+        .get::<DataKey, Distribution>(&key)
+        .ok_or(DistributorError::DistributionNotFound)?;
+    extend_persistent_ttl(e, &key);
+    Ok(distribution)
+}
+
+pub fn write_distribution(e: &Env, distribution: &Distribution) {
+    let key = DataKey::Distribution(distribution.id);
+    e.storage().persistent().set(&key, distribution);
+    extend_persistent_ttl(e, &key);
+}
+
+// Claims
+pub fn has_claimed(e: &Env, id: u32, holder: Address) -> bool {
+    e.storage().persistent().has(&DataKey::Claimed(id, holder))
+}
+
+pub fn write_claimed(e: &Env, id: u32, holder: Address) {
+    let key = DataKey::Claimed(id, holder);
+    e.storage().persistent().set(&key, &true);
+    extend_persistent_ttl(e, &key);
+}
@@ -0,0 +1,93 @@
+#![cfg(test)]
+extern crate std;
+use common::models::WineLotMetadata;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{token, Address, Env, String};
+
+// Wine Token Contract, built with `make build` (see the Makefile)
+pub(crate) mod wine_token_contract {
+    soroban_sdk::contractimport!(file = "../../target/wasm32v1-none/release/wine_token.wasm");
+}
+use wine_token_contract::Client as WineTokenClient;
+
+use crate::{ProceedsDistributor, ProceedsDistributorClient};
+
+pub(crate) fn create_wine_lot_metadata(e: &Env, bottle_count: u32) -> WineLotMetadata {
+    WineLotMetadata {
+        lot_id: String::from_str(e, "MAL-2024-001"),
+        winery_name: String::from_str(e, "Bodega Catena"),
+        region: String::from_str(e, "Mendoza"),
+        country: String::from_str(e, "Argentina"),
+        vintage: 2024,
+        varietal: String::from_str(e, "Malbec"),
+        bottle_count,
+        description: None,
+        token_code: String::from_str(e, "MAL24"),
+    }
+}
+
+pub(crate) fn create_wine_token<'a>(e: &Env, admin: &Address) -> WineTokenClient<'a> {
+    let args = (
+        admin.clone(),
+        0u32,
+        String::from_str(e, "Malbec Reserve 2024"),
+        String::from_str(e, "MAL24"),
+        create_wine_lot_metadata(e, 1000),
+        None::<Address>,
+    );
+    WineTokenClient::new(e, &e.register(wine_token_contract::WASM, args))
+}
+
+pub(crate) fn create_distributor<'a>(
+    e: &Env,
+    admin: &Address,
+    lot_token: &Address,
+    payment_token: &Address,
+) -> ProceedsDistributorClient<'a> {
+    let args = (admin.clone(), lot_token.clone(), payment_token.clone());
+    ProceedsDistributorClient::new(e, &e.register(ProceedsDistributor, args))
+}
+
+pub struct DistributorTest<'a> {
+    env: Env,
+    admin: Address,
+    lot_token: WineTokenClient<'a>,
+    payment_token: token::Client<'a>,
+    distributor: ProceedsDistributorClient<'a>,
+    alice: Address,
+    bob: Address,
+}
+
+impl<'a> DistributorTest<'a> {
+    /// Alice holds 600 and Bob 400 lot tokens; the admin holds 1_000_000 of the
+    /// payment asset.
+    fn setup() -> Self {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let admin = Address::generate(&env);
+        let lot_token = create_wine_token(&env, &admin);
+        let payment = env.register_stellar_asset_contract_v2(admin.clone());
+        token::StellarAssetClient::new(&env, &payment.address()).mint(&admin, &1_000_000);
+        let payment_token = token::Client::new(&env, &payment.address());
+        let distributor =
+            create_distributor(&env, &admin, &lot_token.address, &payment_token.address);
+
+        let alice = Address::generate(&env);
+        let bob = Address::generate(&env);
+        lot_token.mint(&admin, &alice, &600);
+        lot_token.mint(&admin, &bob, &400);
+
+        DistributorTest {
+            env,
+            admin,
+            lot_token,
+            payment_token,
+            distributor,
+            alice,
+            bob,
+        }
+    }
+}
+
+mod proceeds_distributor;
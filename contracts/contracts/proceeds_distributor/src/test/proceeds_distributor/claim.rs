@@ -0,0 +1,108 @@
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{token, Address};
+
+use crate::test::DistributorTest;
+use crate::DistributorError;
+
+#[test]
+fn holders_claim_pro_rata() {
+    let test = DistributorTest::setup();
+
+    let snapshot_id = test.lot_token.snapshot();
+    let id = test.distributor.deposit(&snapshot_id, &10_000, &1_000);
+
+    assert_eq!(test.distributor.claimable(&test.alice, &id), 6_000);
+    assert_eq!(test.distributor.claim(&test.alice, &id), 6_000);
+    assert_eq!(test.distributor.claim(&test.bob, &id), 4_000);
+
+    assert_eq!(test.payment_token.balance(&test.alice), 6_000);
+    assert_eq!(test.payment_token.balance(&test.bob), 4_000);
+    assert_eq!(test.distributor.get_distribution(&id).claimed, 10_000);
+    assert!(test.distributor.has_claimed(&test.alice, &id));
+    assert_eq!(test.distributor.claimable(&test.alice, &id), 0);
+}
+
+#[test]
+fn transfers_after_snapshot_do_not_change_shares() {
+    let test = DistributorTest::setup();
+    let buyer = Address::generate(&test.env);
+
+    let snapshot_id = test.lot_token.snapshot();
+    test.lot_token.transfer(&test.alice, &buyer, &600);
+    let id = test.distributor.deposit(&snapshot_id, &10_000, &1_000);
+
+    assert_eq!(
+        test.distributor.try_claim(&buyer, &id),
+        Err(Ok(DistributorError::NothingToClaim))
+    );
+    assert_eq!(test.distributor.claim(&test.alice, &id), 6_000);
+}
+
+#[test]
+fn holder_cannot_claim_twice() {
+    let test = DistributorTest::setup();
+
+    let snapshot_id = test.lot_token.snapshot();
+    let id = test.distributor.deposit(&snapshot_id, &10_000, &1_000);
+
+    test.distributor.claim(&test.alice, &id);
+    assert_eq!(
+        test.distributor.try_claim(&test.alice, &id),
+        Err(Ok(DistributorError::AlreadyClaimed))
+    );
+}
+
+#[test]
+fn shares_round_down() {
+    let test = DistributorTest::setup();
+
+    let snapshot_id = test.lot_token.snapshot();
+    let id = test.distributor.deposit(&snapshot_id, &999, &1_000);
+
+    // 999 * 600 / 1000 = 599.4, 999 * 400 / 1000 = 399.6
+    assert_eq!(test.distributor.claim(&test.alice, &id), 599);
+    assert_eq!(test.distributor.claim(&test.bob, &id), 399);
+    assert_eq!(test.payment_token.balance(&test.distributor.address), 1);
+}
+
+#[test]
+fn claim_fails_after_expiry() {
+    let test = DistributorTest::setup();
+
+    let snapshot_id = test.lot_token.snapshot();
+    let id = test.distributor.deposit(&snapshot_id, &10_000, &1_000);
+
+    test.env.ledger().with_mut(|li| li.sequence_number = 1_001);
+    assert_eq!(test.distributor.claimable(&test.alice, &id), 0);
+    assert_eq!(
+        test.distributor.try_claim(&test.alice, &id),
+        Err(Ok(DistributorError::DistributionExpired))
+    );
+}
+
+#[test]
+fn claim_from_unknown_distribution_fails() {
+    let test = DistributorTest::setup();
+
+    assert_eq!(
+        test.distributor.try_claim(&test.alice, &0),
+        Err(Ok(DistributorError::DistributionNotFound))
+    );
+}
+
+#[test]
+fn large_deposits_do_not_overflow() {
+    let test = DistributorTest::setup();
+    let amount = 10i128.pow(36);
+    let (alice_share, bob_share) = (6 * 10i128.pow(35), 4 * 10i128.pow(35));
+    token::StellarAssetClient::new(&test.env, &test.payment_token.address)
+        .mint(&test.admin, &amount);
+
+    let snapshot_id = test.lot_token.snapshot();
+    let id = test.distributor.deposit(&snapshot_id, &amount, &1_000);
+
+    assert_eq!(test.distributor.claimable(&test.alice, &id), alice_share);
+    assert_eq!(test.distributor.claim(&test.alice, &id), alice_share);
+    assert_eq!(test.distributor.claim(&test.bob, &id), bob_share);
+    assert_eq!(test.distributor.get_distribution(&id).claimed, amount);
+}
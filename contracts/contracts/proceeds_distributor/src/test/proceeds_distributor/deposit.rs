@@ -0,0 +1,51 @@
+use crate::test::DistributorTest;
+use crate::DistributorError;
+
+#[test]
+fn deposit_escrows_proceeds() {
+    let test = DistributorTest::setup();
+
+    let snapshot_id = test.lot_token.snapshot();
+    let id = test.distributor.deposit(&snapshot_id, &10_000, &1_000);
+
+    assert_eq!(id, 0);
+    assert_eq!(test.distributor.distribution_count(), 1);
+    assert_eq!(test.payment_token.balance(&test.distributor.address), 10_000);
+    assert_eq!(test.payment_token.balance(&test.admin), 990_000);
+
+    let distribution = test.distributor.get_distribution(&id);
+    assert_eq!(distribution.snapshot_id, snapshot_id);
+    assert_eq!(distribution.total_supply, 1_000);
+    assert_eq!(distribution.claimed, 0);
+}
+
+#[test]
+fn deposit_rejects_invalid_input() {
+    let test = DistributorTest::setup();
+    let snapshot_id = test.lot_token.snapshot();
+
+    assert_eq!(
+        test.distributor.try_deposit(&snapshot_id, &0, &1_000),
+        Err(Ok(DistributorError::InvalidAmount))
+    );
+    assert_eq!(
+        test.distributor.try_deposit(&snapshot_id, &10_000, &0),
+        Err(Ok(DistributorError::InvalidExpiry))
+    );
+    // Unknown snapshots are rejected by the lot token
+    assert!(test.distributor.try_deposit(&(snapshot_id + 1), &10_000, &1_000).is_err());
+}
+
+#[test]
+fn deposit_rejects_empty_snapshot() {
+    let test = DistributorTest::setup();
+
+    test.lot_token.burn(&test.alice, &600);
+    test.lot_token.burn(&test.bob, &400);
+    let snapshot_id = test.lot_token.snapshot();
+
+    assert_eq!(
+        test.distributor.try_deposit(&snapshot_id, &10_000, &1_000),
+        Err(Ok(DistributorError::EmptySnapshot))
+    );
+}
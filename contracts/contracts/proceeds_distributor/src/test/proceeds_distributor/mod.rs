@@ -0,0 +1,3 @@
+mod claim;
+mod deposit;
+mod reclaim;
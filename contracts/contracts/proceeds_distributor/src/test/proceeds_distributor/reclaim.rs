@@ -0,0 +1,50 @@
+use soroban_sdk::testutils::Ledger;
+
+use crate::test::DistributorTest;
+use crate::DistributorError;
+
+#[test]
+fn unclaimed_funds_and_dust_return_after_expiry() {
+    let test = DistributorTest::setup();
+
+    let snapshot_id = test.lot_token.snapshot();
+    let id = test.distributor.deposit(&snapshot_id, &999, &1_000);
+    test.distributor.claim(&test.alice, &id);
+
+    test.env.ledger().with_mut(|li| li.sequence_number = 1_001);
+    // Bob's 399 plus 1 unit of rounding dust
+    assert_eq!(test.distributor.reclaim(&id), 400);
+
+    assert_eq!(test.payment_token.balance(&test.distributor.address), 0);
+    assert_eq!(test.payment_token.balance(&test.admin), 1_000_000 - 599);
+    assert!(test.distributor.get_distribution(&id).reclaimed);
+}
+
+#[test]
+fn reclaim_waits_for_expiry() {
+    let test = DistributorTest::setup();
+
+    let snapshot_id = test.lot_token.snapshot();
+    let id = test.distributor.deposit(&snapshot_id, &10_000, &1_000);
+
+    test.env.ledger().with_mut(|li| li.sequence_number = 1_000);
+    assert_eq!(
+        test.distributor.try_reclaim(&id),
+        Err(Ok(DistributorError::DistributionNotExpired))
+    );
+}
+
+#[test]
+fn reclaim_only_once() {
+    let test = DistributorTest::setup();
+
+    let snapshot_id = test.lot_token.snapshot();
+    let id = test.distributor.deposit(&snapshot_id, &10_000, &1_000);
+
+    test.env.ledger().with_mut(|li| li.sequence_number = 1_001);
+    assert_eq!(test.distributor.reclaim(&id), 10_000);
+    assert_eq!(
+        test.distributor.try_reclaim(&id),
+        Err(Ok(DistributorError::AlreadyReclaimed))
+    );
+}
@@ -0,0 +1,9 @@
+use soroban_sdk::{contractclient, Address, Env};
+
+/// Subset of the `WineToken` interface used by the distributor.
+#[allow(dead_code)]
+#[contractclient(name = "WineTokenClient")]
+pub trait WineTokenInterface {
+    fn balance_of_at(e: Env, holder: Address, snapshot_id: u32) -> i128;
+    fn total_supply_at(e: Env, snapshot_id: u32) -> i128;
+}
@@ -0,0 +1,12 @@
+use soroban_sdk::{self, contracterror};
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum StatusRelayError {
+    Unauthorized = 1,
+    UnknownHandlerKey = 2,
+    InvalidNonce = 3,
+    WrongRelay = 4,
+    InvalidStatusTransition = 5,
+}
@@ -0,0 +1,31 @@
+use soroban_sdk::{contracttype, symbol_short, BytesN, Env};
+
+// HANDLER KEY REGISTERED EVENT
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HandlerKeyRegisteredEvent {
+    pub public_key: BytesN<32>,
+}
+
+/// Publishes a `HandlerKeyRegisteredEvent` to the event stream.
+pub(crate) fn emit_handler_key_registered_event(e: &Env, public_key: BytesN<32>) {
+    let event = HandlerKeyRegisteredEvent { public_key };
+
+    e.events()
+        .publish(("statusRelay", symbol_short!("key_added")), event);
+}
+
+// HANDLER KEY REMOVED EVENT
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HandlerKeyRemovedEvent {
+    pub public_key: BytesN<32>,
+}
+
+/// Publishes a `HandlerKeyRemovedEvent` to the event stream.
+pub(crate) fn emit_handler_key_removed_event(e: &Env, public_key: BytesN<32>) {
+    let event = HandlerKeyRemovedEvent { public_key };
+
+    e.events()
+        .publish(("statusRelay", symbol_short!("key_rmvd")), event);
+}
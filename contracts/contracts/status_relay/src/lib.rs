@@ -0,0 +1,131 @@
+#![no_std]
+
+use common::models::Role;
+use soroban_sdk::xdr::ToXdr;
+use soroban_sdk::{contract, contractimpl, Address, BytesN, Env};
+
+mod error;
+mod events;
+pub mod models;
+mod storage;
+mod test;
+mod wine_token;
+
+use models::SignedStatusPayload;
+use storage::{
+    extend_instance_ttl, is_handler_key, read_handler_nonce, read_lot_token, write_handler_key,
+    write_handler_nonce, write_lot_token,
+};
+use wine_token::WineTokenClient;
+
+pub use error::StatusRelayError;
+
+#[contract]
+pub struct StatusRelay;
+
+#[contractimpl]
+impl StatusRelay {
+    /// Initialize the signed status relay of a wine lot
+    ///
+    /// The token admin must grant the relay the `StatusUpdater` role on the lot
+    /// token before it can post updates.
+    ///
+    /// # Arguments
+    /// * `lot_token` - Address of the `WineToken` whose status is relayed
+    pub fn __constructor(e: Env, lot_token: Address) {
+        write_lot_token(&e, &lot_token);
+        extend_instance_ttl(&e);
+    }
+
+    /// Submit a status update signed off-chain by a registered handler key
+    ///
+    /// Lets handlers without a Stellar account (e.g. warehouse scanners) report
+    /// status through any relayer. The signature covers the XDR encoding of
+    /// `payload`, and each key must use its nonces in order. The lot token
+    /// stores the record with this relay as the handler and `public_key` as the
+    /// handler key.
+    ///
+    /// # Arguments
+    /// * `payload` - Status update, bound to this relay and the key's next nonce
+    /// * `public_key` - Registered ed25519 handler key
+    /// * `signature` - ed25519 signature of `payload`; an invalid signature aborts the call
+    ///
+    /// # Errors
+    /// * `StatusRelayError::UnknownHandlerKey` - If `public_key` is not registered
+    /// * `StatusRelayError::WrongRelay` - If the payload targets another relay
+    /// * `StatusRelayError::InvalidNonce` - If the nonce is not the key's next nonce
+    /// * `StatusRelayError::Unauthorized` - If the relay does not hold `StatusUpdater` on the lot token
+    /// * `StatusRelayError::InvalidStatusTransition` - If the lot cannot move to the new status
+    pub fn submit_signed_status(
+        e: Env,
+        payload: SignedStatusPayload,
+        public_key: BytesN<32>,
+        signature: BytesN<64>,
+    ) -> Result<(), StatusRelayError> {
+        extend_instance_ttl(&e);
+
+        if !is_handler_key(&e, &public_key) {
+            return Err(StatusRelayError::UnknownHandlerKey);
+        }
+        let relay = e.current_contract_address();
+        if payload.relay != relay {
+            return Err(StatusRelayError::WrongRelay);
+        }
+        let nonce = read_handler_nonce(&e, &public_key);
+        if payload.nonce != nonce {
+            return Err(StatusRelayError::InvalidNonce);
+        }
+
+        e.crypto()
+            .ed25519_verify(&public_key, &payload.clone().to_xdr(&e), &signature);
+
+        let lot_token = WineTokenClient::new(&e, &read_lot_token(&e));
+        if !lot_token.has_role(&Role::StatusUpdater, &relay) {
+            return Err(StatusRelayError::Unauthorized);
+        }
+
+        write_handler_nonce(&e, &public_key, nonce.checked_add(1).unwrap());
+        lot_token
+            .try_set_relayed_status(&relay, &payload.status, &payload.location, &public_key)
+            .map_err(|_| StatusRelayError::InvalidStatusTransition)?
+            .map_err(|_| StatusRelayError::InvalidStatusTransition)
+    }
+
+    /// Register an ed25519 key allowed to sign status updates (only the token admin can call)
+    pub fn register_handler_key(e: Env, public_key: BytesN<32>) {
+        let admin = WineTokenClient::new(&e, &read_lot_token(&e)).admin();
+        admin.require_auth();
+        extend_instance_ttl(&e);
+
+        write_handler_key(&e, &public_key, true);
+        events::emit_handler_key_registered_event(&e, public_key);
+    }
+
+    /// Remove a handler key (only the token admin can call)
+    pub fn remove_handler_key(e: Env, public_key: BytesN<32>) {
+        let admin = WineTokenClient::new(&e, &read_lot_token(&e)).admin();
+        admin.require_auth();
+        extend_instance_ttl(&e);
+
+        write_handler_key(&e, &public_key, false);
+        events::emit_handler_key_removed_event(&e, public_key);
+    }
+
+    /// Check whether a handler key is registered
+    pub fn is_handler_key(e: Env, public_key: BytesN<32>) -> bool {
+        extend_instance_ttl(&e);
+        is_handler_key(&e, &public_key)
+    }
+
+    /// Get the nonce the next signed update from `public_key` must carry
+    pub fn handler_nonce(e: Env, public_key: BytesN<32>) -> u64 {
+        extend_instance_ttl(&e);
+        read_handler_nonce(&e, &public_key)
+    }
+
+    /// Get the lot token whose status is relayed
+    pub fn lot_token(e: Env) -> Address {
+        extend_instance_ttl(&e);
+        read_lot_token(&e)
+    }
+}
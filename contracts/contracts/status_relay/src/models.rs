@@ -0,0 +1,12 @@
+use common::models::LotStatus;
+use soroban_sdk::{contracttype, Address, String};
+
+// Off-Chain Status Update Signed by a Handler Key
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SignedStatusPayload {
+    pub relay: Address, // relay contract the update is meant for
+    pub status: LotStatus,
+    pub location: Option<String>,
+    pub nonce: u64, // must equal the key's next nonce
+}
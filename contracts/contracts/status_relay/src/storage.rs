@@ -0,0 +1,69 @@
+use soroban_sdk::{contracttype, Address, BytesN, Env};
+
+pub(crate) const DAY_IN_LEDGERS: u32 = 17280;
+pub(crate) const INSTANCE_BUMP_AMOUNT: u32 = 30 * DAY_IN_LEDGERS;
+pub(crate) const INSTANCE_LIFETIME_THRESHOLD: u32 = INSTANCE_BUMP_AMOUNT - DAY_IN_LEDGERS;
+pub(crate) const PERSISTENT_BUMP_AMOUNT: u32 = 120 * DAY_IN_LEDGERS;
+pub(crate) const PERSISTENT_LIFETIME_THRESHOLD: u32 = PERSISTENT_BUMP_AMOUNT - 20 * DAY_IN_LEDGERS;
+
+#[derive(Clone)]
+#[contracttype]
+pub enum DataKey {
+    LotToken,
+    HandlerKey(BytesN<32>),
+    HandlerNonce(BytesN<32>),
+}
+
+pub fn extend_instance_ttl(e: &Env) {
+    e.storage()
+        .instance()
+        .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+}
+
+fn extend_persistent_ttl(e: &Env, key: &DataKey) {
+    e.storage()
+        .persistent()
+        .extend_ttl(key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+}
+
+// Lot token
+pub fn read_lot_token(e: &Env) -> Address {
+    e.storage().instance().get(&DataKey::LotToken).unwrap()
+}
+
+pub fn write_lot_token(e: &Env, lot_token: &Address) {
+    e.storage().instance().set(&DataKey::LotToken, lot_token);
+}
+
+// Handler keys
+pub fn is_handler_key(e: &Env, public_key: &BytesN<32>) -> bool {
+    let key = DataKey::HandlerKey(public_key.clone());
+    let registered = e.storage().persistent().has(&key);
+    if registered {
+        extend_persistent_ttl(e, &key);
+    }
+    registered
+}
+
+pub fn write_handler_key(e: &Env, public_key: &BytesN<32>, registered: bool) {
+    let key = DataKey::HandlerKey(public_key.clone());
+    if registered {
+        e.storage().persistent().set(&key, &true);
+        extend_persistent_ttl(e, &key);
+    } else {
+        e.storage().persistent().remove(&key);
+    }
+}
+
+/// Next nonce a handler key must sign with. Nonces survive key removal so a
+/// re-registered key cannot replay its old payloads.
+pub fn read_handler_nonce(e: &Env, public_key: &BytesN<32>) -> u64 {
+    let key = DataKey::HandlerNonce(public_key.clone());
+    e.storage().persistent().get(&key).unwrap_or(0)
+}
+
+pub fn write_handler_nonce(e: &Env, public_key: &BytesN<32>, nonce: u64) {
+    let key = DataKey::HandlerNonce(public_key.clone());
+    e.storage().persistent().set(&key, &nonce);
+    extend_persistent_ttl(e, &key);
+}
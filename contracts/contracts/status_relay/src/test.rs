@@ -0,0 +1,71 @@
+#![cfg(test)]
+extern crate std;
+use common::models::WineLotMetadata;
+use soroban_sdk::{testutils::Address as _, Address, Env, String};
+
+// Wine Token Contract, built with `make build` (see the Makefile)
+pub(crate) mod wine_token_contract {
+    soroban_sdk::contractimport!(file = "../../target/wasm32v1-none/release/wine_token.wasm");
+}
+use wine_token_contract::Client as WineTokenClient;
+
+use crate::{StatusRelay, StatusRelayClient};
+
+pub(crate) fn create_wine_lot_metadata(e: &Env) -> WineLotMetadata {
+    WineLotMetadata {
+        lot_id: String::from_str(e, "MAL-2024-001"),
+        winery_name: String::from_str(e, "Bodega Catena"),
+        region: String::from_str(e, "Mendoza"),
+        country: String::from_str(e, "Argentina"),
+        vintage: 2024,
+        varietal: String::from_str(e, "Malbec"),
+        bottle_count: 1000,
+        description: None,
+        token_code: String::from_str(e, "MAL24"),
+    }
+}
+
+pub(crate) fn create_wine_token<'a>(e: &Env, admin: &Address) -> WineTokenClient<'a> {
+    let args = (
+        admin.clone(),
+        0u32,
+        String::from_str(e, "Malbec Reserve 2024"),
+        String::from_str(e, "MAL24"),
+        create_wine_lot_metadata(e),
+        None::<Address>,
+    );
+    WineTokenClient::new(e, &e.register(wine_token_contract::WASM, args))
+}
+
+pub struct RelayTest<'a> {
+    env: Env,
+    admin: Address,
+    lot_token: WineTokenClient<'a>,
+    relay: StatusRelayClient<'a>,
+}
+
+impl<'a> RelayTest<'a> {
+    /// The relay holds the `StatusUpdater` role on the lot token.
+    fn setup() -> Self {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let admin = Address::generate(&env);
+        let lot_token = create_wine_token(&env, &admin);
+        let relay = StatusRelayClient::new(
+            &env,
+            &env.register(StatusRelay, (lot_token.address.clone(),)),
+        );
+
+        lot_token.grant_role(&wine_token_contract::Role::StatusUpdater, &relay.address);
+
+        RelayTest {
+            env,
+            admin,
+            lot_token,
+            relay,
+        }
+    }
+}
+
+mod status_relay;
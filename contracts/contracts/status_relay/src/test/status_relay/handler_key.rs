@@ -0,0 +1,42 @@
+extern crate std;
+
+use soroban_sdk::testutils::{AuthorizedFunction, AuthorizedInvocation};
+use soroban_sdk::{BytesN, IntoVal, Symbol};
+
+use crate::test::RelayTest;
+
+#[test]
+fn register_and_remove_handler_key() {
+    let test = RelayTest::setup();
+    let key = BytesN::from_array(&test.env, &[1u8; 32]);
+
+    assert!(!test.relay.is_handler_key(&key));
+    test.relay.register_handler_key(&key);
+    assert!(test.relay.is_handler_key(&key));
+    assert_eq!(test.relay.handler_nonce(&key), 0);
+
+    test.relay.remove_handler_key(&key);
+    assert!(!test.relay.is_handler_key(&key));
+}
+
+#[test]
+fn register_handler_key_requires_token_admin() {
+    let test = RelayTest::setup();
+    let key = BytesN::from_array(&test.env, &[1u8; 32]);
+    test.relay.register_handler_key(&key);
+
+    assert_eq!(
+        test.env.auths(),
+        std::vec![(
+            test.admin.clone(),
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    test.relay.address.clone(),
+                    Symbol::new(&test.env, "register_handler_key"),
+                    (key,).into_val(&test.env),
+                )),
+                sub_invocations: std::vec![],
+            }
+        )]
+    );
+}
@@ -0,0 +1,2 @@
+mod handler_key;
+mod submit;
@@ -0,0 +1,156 @@
+extern crate std;
+
+use common::models::LotStatus;
+use ed25519_dalek::{Signer, SigningKey};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::xdr::ToXdr;
+use soroban_sdk::{Address, BytesN, Env, String};
+
+use crate::models::SignedStatusPayload;
+use crate::test::{wine_token_contract, RelayTest};
+use crate::StatusRelayError;
+
+pub(crate) fn public_key(e: &Env, signer: &SigningKey) -> BytesN<32> {
+    BytesN::from_array(e, &signer.verifying_key().to_bytes())
+}
+
+fn sign(e: &Env, signer: &SigningKey, payload: &SignedStatusPayload) -> BytesN<64> {
+    let message: std::vec::Vec<u8> = payload.clone().to_xdr(e).iter().collect();
+    BytesN::from_array(e, &signer.sign(&message).to_bytes())
+}
+
+fn payload(test: &RelayTest, status: LotStatus, nonce: u64) -> SignedStatusPayload {
+    SignedStatusPayload {
+        relay: test.relay.address.clone(),
+        status,
+        location: Some(String::from_str(&test.env, "Scanner 4")),
+        nonce,
+    }
+}
+
+#[test]
+fn signed_status_is_recorded() {
+    let test = RelayTest::setup();
+    let signer = SigningKey::from_bytes(&[1u8; 32]);
+    let key = public_key(&test.env, &signer);
+    test.relay.register_handler_key(&key);
+
+    let update = payload(&test, LotStatus::Harvested, 0);
+    test.relay
+        .submit_signed_status(&update, &key, &sign(&test.env, &signer, &update));
+
+    assert_eq!(
+        test.lot_token.get_status(),
+        Some(wine_token_contract::LotStatus::Harvested)
+    );
+    assert_eq!(test.relay.handler_nonce(&key), 1);
+    let record = test.lot_token.get_status_history(&0, &1).get(0).unwrap();
+    assert_eq!(record.handler, test.relay.address);
+    assert_eq!(record.handler_key, Some(key));
+    assert_eq!(record.location, update.location);
+}
+
+#[test]
+fn replayed_payload_is_rejected() {
+    let test = RelayTest::setup();
+    let signer = SigningKey::from_bytes(&[1u8; 32]);
+    let key = public_key(&test.env, &signer);
+    test.relay.register_handler_key(&key);
+
+    let update = payload(&test, LotStatus::Harvested, 0);
+    let signature = sign(&test.env, &signer, &update);
+    test.relay.submit_signed_status(&update, &key, &signature);
+
+    let result = test
+        .relay
+        .try_submit_signed_status(&update, &key, &signature);
+    assert_eq!(result, Err(Ok(StatusRelayError::InvalidNonce)));
+}
+
+#[test]
+fn nonce_survives_key_removal() {
+    let test = RelayTest::setup();
+    let signer = SigningKey::from_bytes(&[1u8; 32]);
+    let key = public_key(&test.env, &signer);
+    test.relay.register_handler_key(&key);
+
+    let update = payload(&test, LotStatus::Harvested, 0);
+    let signature = sign(&test.env, &signer, &update);
+    test.relay.submit_signed_status(&update, &key, &signature);
+
+    test.relay.remove_handler_key(&key);
+    assert!(!test.relay.is_handler_key(&key));
+    let result = test
+        .relay
+        .try_submit_signed_status(&update, &key, &signature);
+    assert_eq!(result, Err(Ok(StatusRelayError::UnknownHandlerKey)));
+
+    test.relay.register_handler_key(&key);
+    let result = test
+        .relay
+        .try_submit_signed_status(&update, &key, &signature);
+    assert_eq!(result, Err(Ok(StatusRelayError::InvalidNonce)));
+}
+
+#[test]
+fn payload_for_another_relay_is_rejected() {
+    let test = RelayTest::setup();
+    let signer = SigningKey::from_bytes(&[1u8; 32]);
+    let key = public_key(&test.env, &signer);
+    test.relay.register_handler_key(&key);
+
+    let mut update = payload(&test, LotStatus::Harvested, 0);
+    update.relay = Address::generate(&test.env);
+    let result =
+        test.relay
+            .try_submit_signed_status(&update, &key, &sign(&test.env, &signer, &update));
+    assert_eq!(result, Err(Ok(StatusRelayError::WrongRelay)));
+}
+
+#[test]
+#[should_panic]
+fn signature_from_other_key_is_rejected() {
+    let test = RelayTest::setup();
+    let signer = SigningKey::from_bytes(&[1u8; 32]);
+    let forger = SigningKey::from_bytes(&[2u8; 32]);
+    let key = public_key(&test.env, &signer);
+    test.relay.register_handler_key(&key);
+
+    let update = payload(&test, LotStatus::Harvested, 0);
+    test.relay
+        .submit_signed_status(&update, &key, &sign(&test.env, &forger, &update));
+}
+
+#[test]
+fn signed_status_follows_lifecycle() {
+    let test = RelayTest::setup();
+    let signer = SigningKey::from_bytes(&[1u8; 32]);
+    let key = public_key(&test.env, &signer);
+    test.relay.register_handler_key(&key);
+
+    let update = payload(&test, LotStatus::Bottled, 0);
+    let result =
+        test.relay
+            .try_submit_signed_status(&update, &key, &sign(&test.env, &signer, &update));
+    assert_eq!(result, Err(Ok(StatusRelayError::InvalidStatusTransition)));
+    assert_eq!(test.relay.handler_nonce(&key), 0);
+}
+
+#[test]
+fn relay_without_status_updater_role_is_rejected() {
+    let test = RelayTest::setup();
+    let signer = SigningKey::from_bytes(&[1u8; 32]);
+    let key = public_key(&test.env, &signer);
+    test.relay.register_handler_key(&key);
+    test.lot_token.revoke_role(
+        &wine_token_contract::Role::StatusUpdater,
+        &test.relay.address,
+    );
+
+    let update = payload(&test, LotStatus::Harvested, 0);
+    let result =
+        test.relay
+            .try_submit_signed_status(&update, &key, &sign(&test.env, &signer, &update));
+    assert_eq!(result, Err(Ok(StatusRelayError::Unauthorized)));
+    assert_eq!(test.relay.handler_nonce(&key), 0);
+}
@@ -0,0 +1,17 @@
+use common::models::{LotStatus, Role};
+use soroban_sdk::{contractclient, Address, BytesN, Env, String};
+
+/// Subset of the `WineToken` interface used by the status relay.
+#[allow(dead_code)]
+#[contractclient(name = "WineTokenClient")]
+pub trait WineTokenInterface {
+    fn admin(e: Env) -> Address;
+    fn has_role(e: Env, role: Role, account: Address) -> bool;
+    fn set_relayed_status(
+        e: Env,
+        relay: Address,
+        status: LotStatus,
+        location: Option<String>,
+        handler_key: BytesN<32>,
+    );
+}
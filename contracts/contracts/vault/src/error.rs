@@ -63,6 +63,8 @@ pub enum ContractError {
     InsufficientOutputAmount = 160,
     ExcessiveInputAmount = 161,
     InvalidFeeBps = 162,
+    NotWineLotVault = 163,
+    LotTokenAlreadySet = 164,
 
     // Add mappings for SoroswapLibraryError
     LibrarySortIdenticalTokens = 190,
@@ -165,6 +165,38 @@ pub(crate) fn emit_manager_changed_event(e: &Env, new_manager: Address) {
         .publish(("vinificaVault", symbol_short!("nmanager")), event);
 }
 
+// WINE LOT DETAILS UPDATED EVENT
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct WineLotDetailsUpdatedEvent {
+    pub caller: Address,
+    pub version: u32,
+}
+
+/// Publishes a `WineLotDetailsUpdatedEvent` to the event stream.
+pub(crate) fn emit_wine_lot_details_updated_event(e: &Env, caller: Address, version: u32) {
+    let event = WineLotDetailsUpdatedEvent { caller, version };
+
+    e.events()
+        .publish(("vinificaVault", symbol_short!("wdetails")), event);
+}
+
+// LOT TOKEN LINKED EVENT
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LotTokenLinkedEvent {
+    pub caller: Address,
+    pub lot_token: Address,
+}
+
+/// Publishes a `LotTokenLinkedEvent` to the event stream.
+pub(crate) fn emit_lot_token_linked_event(e: &Env, caller: Address, lot_token: Address) {
+    let event = LotTokenLinkedEvent { caller, lot_token };
+
+    e.events()
+        .publish(("vinificaVault", symbol_short!("lottoken")), event);
+}
+
 // EMERGENCY MANAGER CHANGED EVENT
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
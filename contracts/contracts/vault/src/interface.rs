@@ -1,7 +1,7 @@
 use soroban_sdk::{Address, BytesN, Env, Map, String, Vec};
 
 use crate::{
-    models::{AssetInvestmentAllocation, CurrentAssetInvestmentAllocation, Instruction, WineLotMetadata}, report::Report, ContractError
+    models::{AssetInvestmentAllocation, CurrentAssetInvestmentAllocation, Instruction, WineLotDetails, WineLotMetadata}, report::Report, ContractError
 };
 use common::models::AssetStrategySet;
 
@@ -308,6 +308,64 @@ pub trait VaultTrait {
     /// # Returns
     /// * `Option<WineLotMetadata>` - The wine lot metadata if this is a wine lot vault, None otherwise.
     fn get_wine_lot_metadata(e: Env) -> Option<WineLotMetadata>;
+
+    /// Sets the extended wine lot details (appellation, ABV, blend, barrel regime and document hashes).
+    ///
+    /// Only the manager can call this, and only on a wine lot vault.
+    ///
+    /// # Arguments
+    /// * `e` - The environment.
+    /// * `caller` - The address initiating the change (must be the manager).
+    /// * `details` - The new wine lot details.
+    ///
+    /// # Returns
+    /// * `Result<(), ContractError>` - Ok if the details were valid and stored.
+    ///
+    /// # Errors
+    /// * `ContractError::NotWineLotVault` - If the vault has no wine lot metadata
+    ///
+    /// # Panics
+    /// Invalid details abort with the shared `WineLotDetailsError` code:
+    /// * `WineLotDetailsError::UnsupportedVersion` (#153) - If `details.version` is not the current layout
+    /// * `WineLotDetailsError::InvalidAbv` (#154) - If the ABV is zero or above 25%
+    /// * `WineLotDetailsError::InvalidBlend` (#155) - If the blend shares do not add up to 10000 bps
+    fn set_wine_lot_details(e: Env, caller: Address, details: WineLotDetails) -> Result<(), ContractError>;
+
+    /// Retrieves the extended wine lot details for this vault, if they have been set.
+    ///
+    /// # Arguments
+    /// * `e` - The environment.
+    ///
+    /// # Returns
+    /// * `Option<WineLotDetails>` - The wine lot details, or None if not set.
+    fn get_wine_lot_details(e: Env) -> Option<WineLotDetails>;
+
+    /// Links the wine lot token this vault was configured around.
+    ///
+    /// Only the manager can call this, and only on a wine lot vault. The link is
+    /// set once and can't be changed afterwards.
+    ///
+    /// # Arguments
+    /// * `e` - The environment.
+    /// * `caller` - The address initiating the change (must be the manager).
+    /// * `lot_token` - The address of the wine lot token.
+    ///
+    /// # Returns
+    /// * `Result<(), ContractError>` - Ok if the token was linked.
+    ///
+    /// # Errors
+    /// * `ContractError::NotWineLotVault` - If the vault has no wine lot metadata
+    /// * `ContractError::LotTokenAlreadySet` - If a lot token is already linked
+    fn set_lot_token(e: Env, caller: Address, lot_token: Address) -> Result<(), ContractError>;
+
+    /// Retrieves the wine lot token linked to this vault, if any.
+    ///
+    /// # Arguments
+    /// * `e` - The environment.
+    ///
+    /// # Returns
+    /// * `Option<Address>` - The wine lot token, or None if not linked.
+    fn get_lot_token(e: Env) -> Option<Address>;
 }
 
 pub trait AdminInterfaceTrait {
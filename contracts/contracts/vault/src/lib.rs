@@ -33,7 +33,8 @@ use storage::{
     get_report, get_vault_fee, set_asset,
     set_vinifica_protocol_fee_rate, set_vinifica_protocol_fee_receiver, set_report,
     set_soroswap_router, set_total_assets, set_vault_fee, set_is_upgradable, update_report_prev_balance,
-    set_wine_lot_metadata, get_wine_lot_metadata
+    set_wine_lot_metadata, get_wine_lot_metadata, set_wine_lot_details, get_wine_lot_details,
+    set_lot_token, get_lot_token
 };
 use strategies::{
     get_strategy_asset, get_strategy_client, get_strategy_struct, invest_in_strategy,
@@ -48,7 +49,7 @@ use common::{models::AssetStrategySet, utils::StringExtensions};
 use vinifica_strategy_core::vinificaStrategyClient;
 
 use crate::token::VaultToken;
-use crate::models::WineLotMetadata;
+use crate::models::{WineLotDetails, WineLotMetadata};
 
 static MINIMUM_LIQUIDITY: i128 = 1000;
 
@@ -244,6 +245,50 @@ impl VaultTrait for vinificaVault {
         get_wine_lot_metadata(&e)
     }
 
+    fn set_wine_lot_details(e: Env, caller: Address, details: WineLotDetails) -> Result<(), ContractError> {
+        extend_instance_ttl(&e);
+        let access_control = AccessControl::new(&e);
+        access_control.require_any_role(&[RolesDataKey::Manager], &caller);
+
+        if get_wine_lot_metadata(&e).is_none() {
+            return Err(ContractError::NotWineLotVault);
+        }
+        details
+            .validate()
+            .unwrap_or_else(|err| panic_with_error!(&e, err));
+
+        set_wine_lot_details(&e, &details);
+        events::emit_wine_lot_details_updated_event(&e, caller, details.version);
+        Ok(())
+    }
+
+    fn get_wine_lot_details(e: Env) -> Option<WineLotDetails> {
+        extend_instance_ttl(&e);
+        get_wine_lot_details(&e)
+    }
+
+    fn set_lot_token(e: Env, caller: Address, lot_token: Address) -> Result<(), ContractError> {
+        extend_instance_ttl(&e);
+        let access_control = AccessControl::new(&e);
+        access_control.require_any_role(&[RolesDataKey::Manager], &caller);
+
+        if get_wine_lot_metadata(&e).is_none() {
+            return Err(ContractError::NotWineLotVault);
+        }
+        if get_lot_token(&e).is_some() {
+            return Err(ContractError::LotTokenAlreadySet);
+        }
+
+        set_lot_token(&e, &lot_token);
+        events::emit_lot_token_linked_event(&e, caller, lot_token);
+        Ok(())
+    }
+
+    fn get_lot_token(e: Env) -> Option<Address> {
+        extend_instance_ttl(&e);
+        get_lot_token(&e)
+    }
+
     /// Handles user deposits into the vinifica Vault and optionally allocates investments automatically.
     ///
     /// This function processes a deposit by transferring each specified asset amount from the user's address to
@@ -1,7 +1,7 @@
 use soroban_sdk::{contracttype, Address, String, Vec};
 
-// Re-export WineLotMetadata from common
-pub use common::models::WineLotMetadata;
+// Re-export WineLotMetadata and WineLotDetails from common
+pub use common::models::{WineLotDetails, WineLotMetadata};
 
 // Investment Allocation in Strategies
 #[contracttype]
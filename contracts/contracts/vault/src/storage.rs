@@ -17,7 +17,7 @@ pub fn extend_instance_ttl(e: &Env) {
         .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
 }
 
-use crate::models::WineLotMetadata;
+use crate::models::{WineLotDetails, WineLotMetadata};
 
 #[derive(Clone)]
 #[contracttype]
@@ -32,6 +32,8 @@ enum DataKey {
     Factory,
     Report(Address),
     WineLotMetadata,       // Wine lot metadata for this vault
+    WineLotDetails,        // Extended wine lot details for this vault
+    LotToken,              // Wine lot token this vault invests around
 }
 
 // AssetStrategySet(index)
@@ -187,4 +189,22 @@ pub fn set_wine_lot_metadata(e: &Env, metadata: &WineLotMetadata) {
 
 pub fn get_wine_lot_metadata(e: &Env) -> Option<WineLotMetadata> {
     e.storage().instance().get(&DataKey::WineLotMetadata)
+}
+
+// Wine Lot Details
+pub fn set_wine_lot_details(e: &Env, details: &WineLotDetails) {
+    e.storage().instance().set(&DataKey::WineLotDetails, details);
+}
+
+pub fn get_wine_lot_details(e: &Env) -> Option<WineLotDetails> {
+    e.storage().instance().get(&DataKey::WineLotDetails)
+}
+
+// Wine Lot Token
+pub fn set_lot_token(e: &Env, lot_token: &Address) {
+    e.storage().instance().set(&DataKey::LotToken, lot_token);
+}
+
+pub fn get_lot_token(e: &Env) -> Option<Address> {
+    e.storage().instance().get(&DataKey::LotToken)
 }
\ No newline at end of file
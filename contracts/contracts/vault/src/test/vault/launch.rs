@@ -0,0 +1,160 @@
+use soroban_sdk::{
+    contract, contractimpl, symbol_short, testutils::Address as _, vec as sorobanvec, Address,
+    BytesN, Env, Map, String, Vec,
+};
+
+use crate::test::{
+    vinifica_vault::{self, AssetStrategySet, ContractError, RolesDataKey, WineLotMetadata, vinificaVaultClient},
+    vinificaVaultTest,
+};
+
+// Wine Factory and Wine Token Contracts, built with `make build` in the wine contracts workspace
+mod wine_factory_contract {
+    soroban_sdk::contractimport!(file = "../../target/wasm32v1-none/release/wine_factory.wasm");
+}
+mod wine_token_contract {
+    soroban_sdk::contractimport!(file = "../../target/wasm32v1-none/release/wine_token.wasm");
+}
+
+// Stands in for the vinifica factory: deploys the real vault WASM with the
+// arguments the wine factory passes through
+#[contract]
+pub struct VaultFactoryStub;
+
+#[contractimpl]
+impl VaultFactoryStub {
+    pub fn __constructor(e: Env, vault_wasm_hash: BytesN<32>, vinifica_receiver: Address) {
+        e.storage().instance().set(&symbol_short!("wasm"), &vault_wasm_hash);
+        e.storage().instance().set(&symbol_short!("receiver"), &vinifica_receiver);
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_vinifica_vault(
+        e: Env,
+        roles: Map<u32, Address>,
+        vault_fee: u32,
+        assets: Vec<AssetStrategySet>,
+        soroswap_router: Address,
+        name_symbol: Map<String, String>,
+        upgradable: bool,
+        wine_lot_metadata: Option<WineLotMetadata>,
+    ) -> Address {
+        let vault_wasm_hash: BytesN<32> = e.storage().instance().get(&symbol_short!("wasm")).unwrap();
+        let vinifica_receiver: Address = e.storage().instance().get(&symbol_short!("receiver")).unwrap();
+        let args = (
+            assets,
+            roles,
+            vault_fee,
+            vinifica_receiver,
+            2500u32,
+            soroswap_router,
+            name_symbol,
+            upgradable,
+            wine_lot_metadata,
+        );
+        e.deployer()
+            .with_current_contract(BytesN::from_array(&e, &[0u8; 32]))
+            .deploy_v2(vault_wasm_hash, args)
+    }
+}
+
+fn create_wine_factory<'a>(test: &vinificaVaultTest) -> wine_factory_contract::Client<'a> {
+    let admin = Address::generate(&test.env);
+    let token_wasm_hash = test.env.deployer().upload_contract_wasm(wine_token_contract::WASM);
+    let factory = wine_factory_contract::Client::new(
+        &test.env,
+        &test.env.register(wine_factory_contract::WASM, (admin, token_wasm_hash)),
+    );
+
+    let vault_wasm_hash = test.env.deployer().upload_contract_wasm(vinifica_vault::WASM);
+    let vault_factory = test.env.register(
+        VaultFactoryStub,
+        (vault_wasm_hash, test.vinifica_protocol_receiver.clone()),
+    );
+    factory.set_vault_factory(&vault_factory);
+    factory
+}
+
+fn create_vault_config(test: &vinificaVaultTest) -> wine_factory_contract::VaultConfig {
+    let mut roles: Map<u32, Address> = Map::new(&test.env);
+    roles.set(RolesDataKey::Manager as u32, test.manager.clone());
+    roles.set(RolesDataKey::EmergencyManager as u32, test.emergency_manager.clone());
+    roles.set(RolesDataKey::VaultFeeReceiver as u32, test.vault_fee_receiver.clone());
+    roles.set(RolesDataKey::RebalanceManager as u32, test.rebalance_manager.clone());
+
+    let mut name_symbol: Map<String, String> = Map::new(&test.env);
+    name_symbol.set(String::from_str(&test.env, "name"), String::from_str(&test.env, "dfToken"));
+    name_symbol.set(String::from_str(&test.env, "symbol"), String::from_str(&test.env, "DFT"));
+
+    wine_factory_contract::VaultConfig {
+        roles,
+        vault_fee: 2000,
+        assets: sorobanvec![
+            &test.env,
+            wine_factory_contract::AssetStrategySet {
+                address: test.token_0.address.clone(),
+                strategies: sorobanvec![
+                    &test.env,
+                    wine_factory_contract::Strategy {
+                        name: String::from_str(&test.env, "Strategy 1"),
+                        address: test.strategy_client_token_0.address.clone(),
+                        paused: false,
+                    }
+                ],
+            }
+        ],
+        soroswap_router: test.soroswap_router.address.clone(),
+        name_symbol,
+        upgradable: true,
+    }
+}
+
+#[test]
+fn launch_lot_links_token_and_vault() {
+    let test = vinificaVaultTest::setup();
+    test.env.mock_all_auths();
+    test.env.cost_estimate().budget().reset_unlimited();
+    let factory = create_wine_factory(&test);
+
+    let winery = Address::generate(&test.env);
+    factory.register_winery(
+        &winery,
+        &String::from_str(&test.env, "Bodega Test"),
+        &String::from_str(&test.env, "Argentina"),
+    );
+    factory.verify_winery(&winery);
+
+    let metadata = wine_factory_contract::WineLotMetadata {
+        lot_id: String::from_str(&test.env, "LOT-2021-001"),
+        winery_name: String::from_str(&test.env, "Bodega Test"),
+        region: String::from_str(&test.env, "Mendoza"),
+        country: String::from_str(&test.env, "Argentina"),
+        vintage: 2021,
+        varietal: String::from_str(&test.env, "Malbec"),
+        bottle_count: 1000,
+        description: None,
+        token_code: String::from_str(&test.env, "MLB21"),
+    };
+    let launch = factory.launch_lot(
+        &winery,
+        &winery,
+        &7,
+        &String::from_str(&test.env, "Malbec 2021"),
+        &String::from_str(&test.env, "MLB21"),
+        &metadata,
+        &None,
+        &create_vault_config(&test),
+    );
+
+    let vault = vinificaVaultClient::new(&test.env, &launch.vault);
+    let token = wine_token_contract::Client::new(&test.env, &launch.token);
+    assert_eq!(vault.get_wine_lot_metadata().unwrap().lot_id, metadata.lot_id);
+    assert_eq!(vault.get_lot_token(), Some(launch.token.clone()));
+    assert_eq!(token.vault(), Some(launch.vault.clone()));
+
+    // The link is final: the manager can't point the vault at another token
+    let other_token = Address::generate(&test.env);
+    let result = vault.try_set_lot_token(&test.manager, &other_token);
+    assert_eq!(result, Err(Ok(ContractError::LotTokenAlreadySet)));
+    assert_eq!(vault.get_lot_token(), Some(launch.token));
+}
@@ -7,8 +7,10 @@ mod fees;
 mod funds;
 mod get_asset_amounts_per_shares;
 mod initialize;
+mod launch;
 mod rebalance;
 mod rescue;
 mod upgrade;
 mod withdraw;
+mod wine_lot;
 mod router;
\ No newline at end of file
@@ -0,0 +1,231 @@
+use soroban_sdk::{
+    testutils::{Address as _, MockAuth, MockAuthInvoke},
+    vec as sorobanvec, Address, BytesN, IntoVal, Map, String, Vec,
+};
+
+use crate::test::{
+    create_strategy_params_token_0,
+    vinifica_vault::{self, AssetStrategySet, BlendComponent, ContractError, RolesDataKey, WineLotDetails, WineLotMetadata, vinificaVaultClient},
+    vinificaVaultTest,
+};
+
+fn create_wine_lot_metadata(test: &vinificaVaultTest) -> WineLotMetadata {
+    WineLotMetadata {
+        lot_id: String::from_str(&test.env, "LOT-2021-001"),
+        winery_name: String::from_str(&test.env, "Bodega Test"),
+        region: String::from_str(&test.env, "Mendoza"),
+        country: String::from_str(&test.env, "Argentina"),
+        vintage: 2021,
+        varietal: String::from_str(&test.env, "Malbec"),
+        bottle_count: 1000,
+        description: None,
+        token_code: String::from_str(&test.env, "MLB21"),
+    }
+}
+
+fn create_wine_lot_details(test: &vinificaVaultTest) -> WineLotDetails {
+    WineLotDetails {
+        version: 1,
+        appellation: Some(String::from_str(&test.env, "Lujan de Cuyo")),
+        abv_bps: 1_400,
+        blend: sorobanvec![
+            &test.env,
+            BlendComponent { varietal: String::from_str(&test.env, "Malbec"), bps: 8_500 },
+            BlendComponent { varietal: String::from_str(&test.env, "Cabernet Franc"), bps: 1_500 }
+        ],
+        barrel_regime: Some(String::from_str(&test.env, "12 months French oak")),
+        lab_analysis_hash: Some(BytesN::from_array(&test.env, &[1u8; 32])),
+        certificate_hashes: sorobanvec![&test.env, BytesN::from_array(&test.env, &[2u8; 32])],
+    }
+}
+
+fn create_wine_lot_vault<'a>(
+    test: &vinificaVaultTest,
+    wine_lot_metadata: Option<WineLotMetadata>,
+) -> vinificaVaultClient<'a> {
+    let assets: Vec<AssetStrategySet> = sorobanvec![
+        &test.env,
+        AssetStrategySet {
+            address: test.token_0.address.clone(),
+            strategies: create_strategy_params_token_0(test)
+        }
+    ];
+
+    let mut roles: Map<u32, Address> = Map::new(&test.env);
+    roles.set(RolesDataKey::Manager as u32, test.manager.clone());
+    roles.set(RolesDataKey::EmergencyManager as u32, test.emergency_manager.clone());
+    roles.set(RolesDataKey::VaultFeeReceiver as u32, test.vault_fee_receiver.clone());
+    roles.set(RolesDataKey::RebalanceManager as u32, test.rebalance_manager.clone());
+
+    let mut name_symbol: Map<String, String> = Map::new(&test.env);
+    name_symbol.set(String::from_str(&test.env, "name"), String::from_str(&test.env, "dfToken"));
+    name_symbol.set(String::from_str(&test.env, "symbol"), String::from_str(&test.env, "DFT"));
+
+    let args = (
+        assets,
+        roles,
+        2000u32,
+        test.vinifica_protocol_receiver.clone(),
+        2500u32,
+        test.soroswap_router.address.clone(),
+        name_symbol,
+        true,
+        wine_lot_metadata,
+    );
+    let address = test.env.register(vinifica_vault::WASM, args);
+    vinificaVaultClient::new(&test.env, &address)
+}
+
+#[test]
+fn set_and_get_wine_lot_details() {
+    let test = vinificaVaultTest::setup();
+    let metadata = create_wine_lot_metadata(&test);
+    let vinifica_contract = create_wine_lot_vault(&test, Some(metadata));
+    assert_eq!(vinifica_contract.get_wine_lot_details(), None);
+
+    let details = create_wine_lot_details(&test);
+    vinifica_contract
+        .mock_auths(&[MockAuth {
+            address: &test.manager,
+            invoke: &MockAuthInvoke {
+                contract: &vinifica_contract.address.clone(),
+                fn_name: "set_wine_lot_details",
+                args: (test.manager.clone(), details.clone()).into_val(&test.env),
+                sub_invokes: &[],
+            },
+        }])
+        .set_wine_lot_details(&test.manager, &details);
+
+    assert_eq!(vinifica_contract.get_wine_lot_details(), Some(details));
+}
+
+#[test]
+fn set_wine_lot_details_not_manager() {
+    let test = vinificaVaultTest::setup();
+    test.env.mock_all_auths();
+    let metadata = create_wine_lot_metadata(&test);
+    let vinifica_contract = create_wine_lot_vault(&test, Some(metadata));
+
+    let details = create_wine_lot_details(&test);
+    let not_manager = Address::generate(&test.env);
+    let result = vinifica_contract.try_set_wine_lot_details(&not_manager, &details);
+    assert_eq!(result, Err(Ok(ContractError::Unauthorized)));
+    assert_eq!(vinifica_contract.get_wine_lot_details(), None);
+}
+
+#[test]
+fn set_wine_lot_details_not_wine_lot_vault() {
+    let test = vinificaVaultTest::setup();
+    test.env.mock_all_auths();
+    let vinifica_contract = create_wine_lot_vault(&test, None);
+
+    let details = create_wine_lot_details(&test);
+    let result = vinifica_contract.try_set_wine_lot_details(&test.manager, &details);
+    assert_eq!(result, Err(Ok(ContractError::NotWineLotVault)));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #153)")]
+fn set_wine_lot_details_unsupported_version() {
+    let test = vinificaVaultTest::setup();
+    test.env.mock_all_auths();
+    let metadata = create_wine_lot_metadata(&test);
+    let vinifica_contract = create_wine_lot_vault(&test, Some(metadata));
+
+    let mut details = create_wine_lot_details(&test);
+    details.version = 2;
+    vinifica_contract.set_wine_lot_details(&test.manager, &details);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #154)")]
+fn set_wine_lot_details_invalid_abv() {
+    let test = vinificaVaultTest::setup();
+    test.env.mock_all_auths();
+    let metadata = create_wine_lot_metadata(&test);
+    let vinifica_contract = create_wine_lot_vault(&test, Some(metadata));
+
+    let mut details = create_wine_lot_details(&test);
+    details.abv_bps = 2_600;
+    vinifica_contract.set_wine_lot_details(&test.manager, &details);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #155)")]
+fn set_wine_lot_details_invalid_blend() {
+    let test = vinificaVaultTest::setup();
+    test.env.mock_all_auths();
+    let metadata = create_wine_lot_metadata(&test);
+    let vinifica_contract = create_wine_lot_vault(&test, Some(metadata));
+
+    let mut details = create_wine_lot_details(&test);
+    details.blend = sorobanvec![
+        &test.env,
+        BlendComponent { varietal: String::from_str(&test.env, "Malbec"), bps: 9_000 }
+    ];
+    vinifica_contract.set_wine_lot_details(&test.manager, &details);
+}
+
+#[test]
+fn set_and_get_lot_token() {
+    let test = vinificaVaultTest::setup();
+    let metadata = create_wine_lot_metadata(&test);
+    let vinifica_contract = create_wine_lot_vault(&test, Some(metadata));
+    assert_eq!(vinifica_contract.get_lot_token(), None);
+
+    let lot_token = Address::generate(&test.env);
+    vinifica_contract
+        .mock_auths(&[MockAuth {
+            address: &test.manager,
+            invoke: &MockAuthInvoke {
+                contract: &vinifica_contract.address.clone(),
+                fn_name: "set_lot_token",
+                args: (test.manager.clone(), lot_token.clone()).into_val(&test.env),
+                sub_invokes: &[],
+            },
+        }])
+        .set_lot_token(&test.manager, &lot_token);
+
+    assert_eq!(vinifica_contract.get_lot_token(), Some(lot_token));
+}
+
+#[test]
+fn set_lot_token_not_manager() {
+    let test = vinificaVaultTest::setup();
+    test.env.mock_all_auths();
+    let metadata = create_wine_lot_metadata(&test);
+    let vinifica_contract = create_wine_lot_vault(&test, Some(metadata));
+
+    let not_manager = Address::generate(&test.env);
+    let lot_token = Address::generate(&test.env);
+    let result = vinifica_contract.try_set_lot_token(&not_manager, &lot_token);
+    assert_eq!(result, Err(Ok(ContractError::Unauthorized)));
+    assert_eq!(vinifica_contract.get_lot_token(), None);
+}
+
+#[test]
+fn set_lot_token_not_wine_lot_vault() {
+    let test = vinificaVaultTest::setup();
+    test.env.mock_all_auths();
+    let vinifica_contract = create_wine_lot_vault(&test, None);
+
+    let lot_token = Address::generate(&test.env);
+    let result = vinifica_contract.try_set_lot_token(&test.manager, &lot_token);
+    assert_eq!(result, Err(Ok(ContractError::NotWineLotVault)));
+}
+
+#[test]
+fn set_lot_token_only_once() {
+    let test = vinificaVaultTest::setup();
+    test.env.mock_all_auths();
+    let metadata = create_wine_lot_metadata(&test);
+    let vinifica_contract = create_wine_lot_vault(&test, Some(metadata));
+
+    let lot_token = Address::generate(&test.env);
+    vinifica_contract.set_lot_token(&test.manager, &lot_token);
+
+    let other_token = Address::generate(&test.env);
+    let result = vinifica_contract.try_set_lot_token(&test.manager, &other_token);
+    assert_eq!(result, Err(Ok(ContractError::LotTokenAlreadySet)));
+    assert_eq!(vinifica_contract.get_lot_token(), Some(lot_token));
+}
@@ -1,15 +1,29 @@
 #![no_std]
+// `create_wine_token` and `launch_lot` take the creator, the winery and the full token setup
+#![allow(clippy::too_many_arguments)]
 
-use common::models::WineLotMetadata;
+use common::models::{PendingAdmin, Role, WineLotMetadata};
 use soroban_sdk::{
-    contract, contractimpl, vec, Address, BytesN, Env, IntoVal, String, Val, Vec,
+    contract, contractimpl, vec, xdr::ToXdr, Address, Bytes, BytesN, Env, IntoVal, String, Val,
+    Vec,
 };
 
+pub mod models;
 mod storage;
+mod test;
+mod vault;
+mod wine_token;
+use models::{LotLaunch, VaultConfig, Winery, WineryStatus};
 use storage::{
-    add_new_token, extend_instance_ttl, get_admin, get_total_tokens, get_token_by_index,
-    get_token_wasm_hash, put_admin, put_token_wasm_hash,
+    add_new_token, add_new_winery, extend_instance_ttl, get_admin, get_admin_token_count,
+    get_pending_admin, get_total_tokens, get_token_by_index, get_token_by_lot_id,
+    get_token_wasm_hash, get_tokens_by_admin, get_tokens_by_vintage, get_vault_factory,
+    get_vintage_token_count, get_wineries, get_winery, get_winery_count, has_lot_id, has_winery,
+    index_token, put_admin, put_pending_admin, put_token_wasm_hash, put_vault_factory, put_winery,
+    remove_pending_admin,
 };
+use vault::{VaultClient, VaultFactoryClient, VAULT_MANAGER_ROLE};
+use wine_token::WineTokenClient;
 
 pub trait WineFactoryTrait {
     /// Initialize the factory contract
@@ -25,37 +39,144 @@ pub trait WineFactoryTrait {
 
     /// Create a new wine lot token
     ///
+    /// `admin` must be a verified winery, and `creator` must be the winery itself
+    /// or one of its registered creators.
+    ///
     /// # Arguments
-    /// * `admin` - Token admin (typically the winery)
+    /// * `creator` - Address authorizing the creation
+    /// * `admin` - Winery that becomes the token admin
     /// * `decimal` - Number of decimals
     /// * `name` - Token name
     /// * `symbol` - Token symbol
     /// * `wine_lot_metadata` - Wine-specific metadata
+    /// * `compliance` - Compliance registry checking the token's holders, if any
     ///
     /// # Returns
     /// * Address of the newly created token
+    ///
+    /// # Errors
+    /// * `WineFactoryError::WineryNotFound` - If `admin` is not a registered winery
+    /// * `WineFactoryError::WineryNotVerified` - If the winery is pending or suspended
+    /// * `WineFactoryError::NotWineryCreator` - If `creator` may not create lots for the winery
+    /// * `WineFactoryError::LotIdAlreadyExists` - If a token was already created for the lot id
     fn create_wine_token(
         e: Env,
+        creator: Address,
         admin: Address,
         decimal: u32,
         name: String,
         symbol: String,
         wine_lot_metadata: WineLotMetadata,
+        compliance: Option<Address>,
     ) -> Result<Address, WineFactoryError>;
 
+    /// Create a wine lot token and an investment vault around it in one go
+    ///
+    /// The vault is created through the configured vault factory with the lot's
+    /// metadata, then the token and the vault are linked to each other. Besides
+    /// `creator`, the winery (to link the vault on its token) and the vault
+    /// manager (to link the token on its vault) must authorize this call.
+    ///
+    /// # Arguments
+    /// * `creator` - Address authorizing the creation
+    /// * `admin` - Winery that becomes the token admin
+    /// * `decimal` - Number of decimals
+    /// * `name` - Token name
+    /// * `symbol` - Token symbol
+    /// * `wine_lot_metadata` - Wine-specific metadata, shared by the token and the vault
+    /// * `compliance` - Compliance registry checking the token's holders, if any
+    /// * `vault_config` - Vault roles, fee, assets and share metadata
+    ///
+    /// # Returns
+    /// * Addresses of the new token and vault
+    ///
+    /// # Errors
+    /// * `WineFactoryError::VaultFactoryNotSet` - If no vault factory has been configured
+    /// * `WineFactoryError::VaultManagerNotSet` - If `vault_config` has no manager role
+    /// * Any error of `create_wine_token`
+    fn launch_lot(
+        e: Env,
+        creator: Address,
+        admin: Address,
+        decimal: u32,
+        name: String,
+        symbol: String,
+        wine_lot_metadata: WineLotMetadata,
+        compliance: Option<Address>,
+        vault_config: VaultConfig,
+    ) -> Result<LotLaunch, WineFactoryError>;
+
     // --- Admin Functions ---
     
-    /// Set a new admin address
-    fn set_admin(e: Env, new_admin: Address) -> Result<(), WineFactoryError>;
+    /// Propose a new admin address
+    ///
+    /// # Arguments
+    /// * `new_admin` - The address that must accept the transfer
+    /// * `delay_ledgers` - Number of ledgers before the transfer can be accepted
+    fn propose_admin(e: Env, new_admin: Address, delay_ledgers: u32) -> Result<(), WineFactoryError>;
+
+    /// Accept a pending admin transfer (called by the proposed admin)
+    fn accept_admin(e: Env) -> Result<(), WineFactoryError>;
+
+    /// Cancel a pending admin transfer
+    fn cancel_admin_transfer(e: Env) -> Result<(), WineFactoryError>;
 
     /// Update the token WASM hash
     fn set_token_wasm_hash(e: Env, new_token_wasm_hash: BytesN<32>) -> Result<(), WineFactoryError>;
 
+    /// Upgrade deployed tokens to the current token WASM hash and run their migrations
+    ///
+    /// Only tokens whose winery opted in, by granting the factory the `Upgrader`
+    /// role on the token, are upgraded; the rest are skipped.
+    ///
+    /// # Arguments
+    /// * `indexes` - Creation indexes of the tokens to upgrade
+    ///
+    /// # Returns
+    /// * Addresses of the upgraded tokens
+    fn upgrade_tokens(e: Env, indexes: Vec<u32>) -> Result<Vec<Address>, WineFactoryError>;
+
+    /// Set the vault factory `launch_lot` creates vaults with
+    fn set_vault_factory(e: Env, vault_factory: Address) -> Result<(), WineFactoryError>;
+
+    // --- Winery Registry (Admin) ---
+
+    /// Register a winery, pending verification
+    ///
+    /// # Arguments
+    /// * `winery` - Address of the winery, used as the token admin of its lots
+    /// * `name` - Winery name
+    /// * `country` - Country the winery operates in
+    fn register_winery(
+        e: Env,
+        winery: Address,
+        name: String,
+        country: String,
+    ) -> Result<(), WineFactoryError>;
+
+    /// Mark a pending winery as verified so it can create lots
+    fn verify_winery(e: Env, winery: Address) -> Result<(), WineFactoryError>;
+
+    /// Suspend a verified winery; it can no longer create lots
+    fn suspend_winery(e: Env, winery: Address) -> Result<(), WineFactoryError>;
+
+    /// Reinstate a suspended winery
+    fn reinstate_winery(e: Env, winery: Address) -> Result<(), WineFactoryError>;
+
+    /// Allow an address to create lots for a winery
+    fn add_winery_creator(e: Env, winery: Address, creator: Address) -> Result<(), WineFactoryError>;
+
+    /// Revoke an address's right to create lots for a winery
+    fn remove_winery_creator(e: Env, winery: Address, creator: Address) -> Result<(), WineFactoryError>;
+
     // --- Read Methods ---
     
     /// Get the current admin address
     fn admin(e: Env) -> Result<Address, WineFactoryError>;
 
+    /// Get the pending admin transfer, if any
+    fn pending_admin(e: Env) -> Option<PendingAdmin>;
+
     /// Get the total number of deployed tokens
     fn total_tokens(e: Env) -> Result<u32, WineFactoryError>;
 
@@ -64,11 +185,85 @@ pub trait WineFactoryTrait {
 
     /// Get the current token WASM hash
     fn token_wasm_hash(e: Env) -> Result<BytesN<32>, WineFactoryError>;
+
+    /// Get the vault factory used by `launch_lot`
+    fn vault_factory(e: Env) -> Result<Address, WineFactoryError>;
+
+    /// Get the address `create_wine_token` will deploy a lot's token to
+    ///
+    /// The address only depends on the factory, the token admin and the lot id,
+    /// so it can be printed on labels before the token exists.
+    fn predict_token_address(e: Env, admin: Address, lot_id: String) -> Address;
+
+    /// Get a registered winery
+    fn get_winery(e: Env, winery: Address) -> Result<Winery, WineFactoryError>;
+
+    /// Get the number of registered wineries
+    fn total_wineries(e: Env) -> u32;
+
+    /// Get registered wineries in registration order
+    ///
+    /// # Arguments
+    /// * `start` - Position of the first winery to return
+    /// * `limit` - Maximum number of wineries to return
+    fn get_wineries(e: Env, start: u32, limit: u32) -> Vec<Winery>;
+
+    /// Get the token created for a lot id
+    fn get_token_by_lot_id(e: Env, lot_id: String) -> Result<Address, WineFactoryError>;
+
+    /// Get the number of tokens created with a winery as admin
+    fn total_tokens_by_admin(e: Env, winery: Address) -> u32;
+
+    /// Get tokens created with a winery as admin, in creation order
+    ///
+    /// # Arguments
+    /// * `winery` - Admin the tokens were created with
+    /// * `start` - Position of the first token to return
+    /// * `limit` - Maximum number of tokens to return
+    fn get_tokens_by_admin(e: Env, winery: Address, start: u32, limit: u32) -> Vec<Address>;
+
+    /// Get the number of tokens created for a vintage
+    fn total_tokens_by_vintage(e: Env, vintage: u32) -> u32;
+
+    /// Get tokens created for a vintage, in creation order
+    ///
+    /// # Arguments
+    /// * `vintage` - Vintage year
+    /// * `start` - Position of the first token to return
+    /// * `limit` - Maximum number of tokens to return
+    fn get_tokens_by_vintage(e: Env, vintage: u32, start: u32, limit: u32) -> Vec<Address>;
 }
 
 #[contract]
 struct WineFactory;
 
+// Salt for a lot's token: sha256(admin || lot_id), both XDR encoded
+fn token_salt(e: &Env, admin: &Address, lot_id: &String) -> BytesN<32> {
+    let mut preimage = Bytes::new(e);
+    preimage.append(&admin.clone().to_xdr(e));
+    preimage.append(&lot_id.clone().to_xdr(e));
+    e.crypto().sha256(&preimage).into()
+}
+
+// Change a winery's status, which must currently be `from`
+fn transition_winery(
+    e: &Env,
+    winery: &Address,
+    from: WineryStatus,
+    to: WineryStatus,
+) -> Result<(), WineFactoryError> {
+    let admin = get_admin(e)?;
+    admin.require_auth();
+
+    let mut entry = get_winery(e, winery)?;
+    if entry.status != from {
+        return Err(WineFactoryError::InvalidWineryStatus);
+    }
+    entry.status = to;
+    put_winery(e, &entry);
+    Ok(())
+}
+
 // Deploy a new token contract
 fn create_wine_token_contract(
     e: &Env,
@@ -78,17 +273,9 @@ fn create_wine_token_contract(
     name: String,
     symbol: String,
     wine_lot_metadata: WineLotMetadata,
+    compliance: Option<Address>,
 ) -> Address {
-    let total_tokens = get_total_tokens(e);
-
-    // Generate salt based on total tokens
-    let salt = {
-        let mut salt_bytes = [0u8; 32];
-        let total_tokens_bytes = total_tokens.to_be_bytes();
-        let len = total_tokens_bytes.len();
-        salt_bytes[..len].copy_from_slice(&total_tokens_bytes);
-        BytesN::from_array(e, &salt_bytes)
-    };
+    let salt = token_salt(e, &admin, &wine_lot_metadata.lot_id);
 
     // Prepare constructor arguments
     let mut init_args: Vec<Val> = vec![e];
@@ -97,6 +284,7 @@ fn create_wine_token_contract(
     init_args.push_back(name.to_val());
     init_args.push_back(symbol.to_val());
     init_args.push_back(wine_lot_metadata.into_val(e));
+    init_args.push_back(compliance.into_val(e));
 
     // Deploy the contract
     e.deployer()
@@ -104,6 +292,50 @@ fn create_wine_token_contract(
         .deploy_v2(token_wasm_hash, init_args)
 }
 
+// Check the creator may create lots for the winery, then deploy and index the token
+fn create_lot_token(
+    e: &Env,
+    creator: &Address,
+    admin: &Address,
+    decimal: u32,
+    name: String,
+    symbol: String,
+    wine_lot_metadata: WineLotMetadata,
+    compliance: Option<Address>,
+) -> Result<Address, WineFactoryError> {
+    creator.require_auth();
+
+    let winery = get_winery(e, admin)?;
+    if winery.status != WineryStatus::Verified {
+        return Err(WineFactoryError::WineryNotVerified);
+    }
+    if creator != admin && !winery.creators.contains(creator) {
+        return Err(WineFactoryError::NotWineryCreator);
+    }
+
+    let token_wasm_hash = get_token_wasm_hash(e)?;
+    if has_lot_id(e, &wine_lot_metadata.lot_id) {
+        return Err(WineFactoryError::LotIdAlreadyExists);
+    }
+    let lot_id = wine_lot_metadata.lot_id.clone();
+    let vintage = wine_lot_metadata.vintage;
+
+    let token_address = create_wine_token_contract(
+        e,
+        token_wasm_hash,
+        admin.clone(),
+        decimal,
+        name,
+        symbol,
+        wine_lot_metadata,
+        compliance,
+    );
+
+    add_new_token(e, token_address.clone());
+    index_token(e, &token_address, admin, &lot_id, vintage);
+    Ok(token_address)
+}
+
 #[contractimpl]
 impl WineFactoryTrait for WineFactory {
     fn __constructor(
@@ -119,27 +351,25 @@ impl WineFactoryTrait for WineFactory {
 
     fn create_wine_token(
         e: Env,
+        creator: Address,
         admin: Address,
         decimal: u32,
         name: String,
         symbol: String,
         wine_lot_metadata: WineLotMetadata,
+        compliance: Option<Address>,
     ) -> Result<Address, WineFactoryError> {
         extend_instance_ttl(&e);
-        
-        let token_wasm_hash = get_token_wasm_hash(&e)?;
-
-        let token_address = create_wine_token_contract(
+        let token_address = create_lot_token(
             &e,
-            token_wasm_hash,
-            admin,
+            &creator,
+            &admin,
             decimal,
             name,
             symbol,
             wine_lot_metadata,
-        );
-
-        add_new_token(&e, token_address.clone());
+            compliance,
+        )?;
 
         // Emit event
         e.events().publish(
@@ -150,13 +380,97 @@ impl WineFactoryTrait for WineFactory {
         Ok(token_address)
     }
 
-    fn set_admin(e: Env, new_admin: Address) -> Result<(), WineFactoryError> {
+    fn launch_lot(
+        e: Env,
+        creator: Address,
+        admin: Address,
+        decimal: u32,
+        name: String,
+        symbol: String,
+        wine_lot_metadata: WineLotMetadata,
+        compliance: Option<Address>,
+        vault_config: VaultConfig,
+    ) -> Result<LotLaunch, WineFactoryError> {
+        extend_instance_ttl(&e);
+        let vault_factory = get_vault_factory(&e)?;
+        let manager = vault_config
+            .roles
+            .get(VAULT_MANAGER_ROLE)
+            .ok_or(WineFactoryError::VaultManagerNotSet)?;
+        let lot_id = wine_lot_metadata.lot_id.clone();
+
+        // The token and the vault check these addresses when they are linked;
+        // authorizing this call up front covers those nested checks
+        if admin != creator {
+            admin.require_auth();
+        }
+        if manager != creator && manager != admin {
+            manager.require_auth();
+        }
+
+        let token = create_lot_token(
+            &e,
+            &creator,
+            &admin,
+            decimal,
+            name,
+            symbol,
+            wine_lot_metadata.clone(),
+            compliance,
+        )?;
+        let vault = VaultFactoryClient::new(&e, &vault_factory).create_vinifica_vault(
+            &vault_config.roles,
+            &vault_config.vault_fee,
+            &vault_config.assets,
+            &vault_config.soroswap_router,
+            &vault_config.name_symbol,
+            &vault_config.upgradable,
+            &Some(wine_lot_metadata),
+        );
+
+        WineTokenClient::new(&e, &token).set_vault(&vault);
+        VaultClient::new(&e, &vault).set_lot_token(&manager, &token);
+
+        e.events()
+            .publish(("lot_launched", admin), (lot_id, token.clone(), vault.clone()));
+        Ok(LotLaunch { token, vault })
+    }
+
+    fn propose_admin(e: Env, new_admin: Address, delay_ledgers: u32) -> Result<(), WineFactoryError> {
+        extend_instance_ttl(&e);
+        let admin = get_admin(&e)?;
+        admin.require_auth();
+
+        let unlock_ledger = e.ledger().sequence().saturating_add(delay_ledgers);
+        put_pending_admin(&e, &PendingAdmin { new_admin: new_admin.clone(), unlock_ledger });
+        e.events().publish(("admin_proposed", admin), (new_admin, unlock_ledger));
+        Ok(())
+    }
+
+    fn accept_admin(e: Env) -> Result<(), WineFactoryError> {
+        extend_instance_ttl(&e);
+        let pending = get_pending_admin(&e).ok_or(WineFactoryError::NoPendingAdmin)?;
+        pending.new_admin.require_auth();
+
+        if e.ledger().sequence() < pending.unlock_ledger {
+            return Err(WineFactoryError::AdminTransferLocked);
+        }
+
+        let previous_admin = get_admin(&e)?;
+        put_admin(&e, &pending.new_admin);
+        remove_pending_admin(&e);
+        e.events().publish(("admin_accepted", previous_admin), pending.new_admin);
+        Ok(())
+    }
+
+    fn cancel_admin_transfer(e: Env) -> Result<(), WineFactoryError> {
         extend_instance_ttl(&e);
         let admin = get_admin(&e)?;
         admin.require_auth();
 
-        put_admin(&e, &new_admin);
-        e.events().publish(("set_admin", "new_admin"), new_admin);
+        let pending = get_pending_admin(&e).ok_or(WineFactoryError::NoPendingAdmin)?;
+        remove_pending_admin(&e);
+        e.events().publish(("admin_cancelled", admin), pending.new_admin);
         Ok(())
     }
 
@@ -170,11 +484,130 @@ impl WineFactoryTrait for WineFactory {
         Ok(())
     }
 
+    fn set_vault_factory(e: Env, vault_factory: Address) -> Result<(), WineFactoryError> {
+        extend_instance_ttl(&e);
+        let admin = get_admin(&e)?;
+        admin.require_auth();
+
+        put_vault_factory(&e, &vault_factory);
+        e.events().publish(("set_vault_factory", "address"), vault_factory);
+        Ok(())
+    }
+
+    fn upgrade_tokens(e: Env, indexes: Vec<u32>) -> Result<Vec<Address>, WineFactoryError> {
+        extend_instance_ttl(&e);
+        let admin = get_admin(&e)?;
+        admin.require_auth();
+
+        let token_wasm_hash = get_token_wasm_hash(&e)?;
+        let factory = e.current_contract_address();
+        let mut upgraded = vec![&e];
+        for index in indexes.iter() {
+            let token_address = get_token_by_index(&e, index)?;
+            let token = WineTokenClient::new(&e, &token_address);
+            if !token.has_role(&Role::Upgrader, &factory) {
+                continue;
+            }
+
+            token.upgrade(&factory, &token_wasm_hash);
+            let code_version = token.migrate(&factory);
+            e.events().publish(
+                ("token_upgraded", token_address.clone()),
+                (token_wasm_hash.clone(), code_version),
+            );
+            upgraded.push_back(token_address);
+        }
+        Ok(upgraded)
+    }
+
+    fn register_winery(
+        e: Env,
+        winery: Address,
+        name: String,
+        country: String,
+    ) -> Result<(), WineFactoryError> {
+        extend_instance_ttl(&e);
+        let admin = get_admin(&e)?;
+        admin.require_auth();
+
+        if has_winery(&e, &winery) {
+            return Err(WineFactoryError::WineryAlreadyRegistered);
+        }
+        add_new_winery(
+            &e,
+            &Winery {
+                address: winery.clone(),
+                name: name.clone(),
+                country: country.clone(),
+                status: WineryStatus::Pending,
+                creators: vec![&e],
+            },
+        );
+        e.events().publish(("winery_registered", winery), (name, country));
+        Ok(())
+    }
+
+    fn verify_winery(e: Env, winery: Address) -> Result<(), WineFactoryError> {
+        extend_instance_ttl(&e);
+        transition_winery(&e, &winery, WineryStatus::Pending, WineryStatus::Verified)?;
+        e.events().publish(("winery_verified", winery), ());
+        Ok(())
+    }
+
+    fn suspend_winery(e: Env, winery: Address) -> Result<(), WineFactoryError> {
+        extend_instance_ttl(&e);
+        transition_winery(&e, &winery, WineryStatus::Verified, WineryStatus::Suspended)?;
+        e.events().publish(("winery_suspended", winery), ());
+        Ok(())
+    }
+
+    fn reinstate_winery(e: Env, winery: Address) -> Result<(), WineFactoryError> {
+        extend_instance_ttl(&e);
+        transition_winery(&e, &winery, WineryStatus::Suspended, WineryStatus::Verified)?;
+        e.events().publish(("winery_reinstated", winery), ());
+        Ok(())
+    }
+
+    fn add_winery_creator(e: Env, winery: Address, creator: Address) -> Result<(), WineFactoryError> {
+        extend_instance_ttl(&e);
+        let admin = get_admin(&e)?;
+        admin.require_auth();
+
+        let mut entry = get_winery(&e, &winery)?;
+        if !entry.creators.contains(&creator) {
+            entry.creators.push_back(creator.clone());
+            put_winery(&e, &entry);
+        }
+        e.events().publish(("winery_creator_added", winery), creator);
+        Ok(())
+    }
+
+    fn remove_winery_creator(e: Env, winery: Address, creator: Address) -> Result<(), WineFactoryError> {
+        extend_instance_ttl(&e);
+        let admin = get_admin(&e)?;
+        admin.require_auth();
+
+        let mut entry = get_winery(&e, &winery)?;
+        let index = entry
+            .creators
+            .first_index_of(&creator)
+            .ok_or(WineFactoryError::NotWineryCreator)?;
+        entry.creators.remove(index);
+        put_winery(&e, &entry);
+        e.events().publish(("winery_creator_removed", winery), creator);
+        Ok(())
+    }
+
     fn admin(e: Env) -> Result<Address, WineFactoryError> {
         extend_instance_ttl(&e);
         get_admin(&e)
     }
 
+    fn pending_admin(e: Env) -> Option<PendingAdmin> {
+        extend_instance_ttl(&e);
+        get_pending_admin(&e)
+    }
+
     fn total_tokens(e: Env) -> Result<u32, WineFactoryError> {
         extend_instance_ttl(&e);
         Ok(get_total_tokens(&e))
@@ -189,6 +622,58 @@ impl WineFactoryTrait for WineFactory {
         extend_instance_ttl(&e);
         get_token_wasm_hash(&e)
     }
+
+    fn vault_factory(e: Env) -> Result<Address, WineFactoryError> {
+        extend_instance_ttl(&e);
+        get_vault_factory(&e)
+    }
+
+    fn get_winery(e: Env, winery: Address) -> Result<Winery, WineFactoryError> {
+        extend_instance_ttl(&e);
+        get_winery(&e, &winery)
+    }
+
+    fn total_wineries(e: Env) -> u32 {
+        extend_instance_ttl(&e);
+        get_winery_count(&e)
+    }
+
+    fn get_wineries(e: Env, start: u32, limit: u32) -> Vec<Winery> {
+        extend_instance_ttl(&e);
+        get_wineries(&e, start, limit)
+    }
+
+    fn predict_token_address(e: Env, admin: Address, lot_id: String) -> Address {
+        extend_instance_ttl(&e);
+        e.deployer()
+            .with_current_contract(token_salt(&e, &admin, &lot_id))
+            .deployed_address()
+    }
+
+    fn get_token_by_lot_id(e: Env, lot_id: String) -> Result<Address, WineFactoryError> {
+        extend_instance_ttl(&e);
+        get_token_by_lot_id(&e, &lot_id)
+    }
+
+    fn total_tokens_by_admin(e: Env, winery: Address) -> u32 {
+        extend_instance_ttl(&e);
+        get_admin_token_count(&e, &winery)
+    }
+
+    fn get_tokens_by_admin(e: Env, winery: Address, start: u32, limit: u32) -> Vec<Address> {
+        extend_instance_ttl(&e);
+        get_tokens_by_admin(&e, &winery, start, limit)
+    }
+
+    fn total_tokens_by_vintage(e: Env, vintage: u32) -> u32 {
+        extend_instance_ttl(&e);
+        get_vintage_token_count(&e, vintage)
+    }
+
+    fn get_tokens_by_vintage(e: Env, vintage: u32, start: u32, limit: u32) -> Vec<Address> {
+        extend_instance_ttl(&e);
+        get_tokens_by_vintage(&e, vintage, start, limit)
+    }
 }
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
@@ -198,5 +683,15 @@ pub enum WineFactoryError {
     NotInitialized = 1,
     AdminNotFound = 2,
     TokenNotFound = 3,
+    NoPendingAdmin = 4,
+    AdminTransferLocked = 5,
+    LotIdAlreadyExists = 6,
+    WineryNotFound = 7,
+    WineryAlreadyRegistered = 8,
+    WineryNotVerified = 9,
+    NotWineryCreator = 10,
+    InvalidWineryStatus = 11,
+    VaultFactoryNotSet = 12,
+    VaultManagerNotSet = 13,
 }
 
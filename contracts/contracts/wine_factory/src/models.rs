@@ -0,0 +1,42 @@
+use common::models::AssetStrategySet;
+use soroban_sdk::{contracttype, Address, Map, String, Vec};
+
+// Verification state of a registered winery
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum WineryStatus {
+    Pending,   // Registered, awaiting verification
+    Verified,  // Allowed to create lot tokens
+    Suspended, // Temporarily barred from creating lot tokens
+}
+
+// Winery registry entry, keyed by the winery's address
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Winery {
+    pub address: Address,
+    pub name: String,
+    pub country: String,
+    pub status: WineryStatus,
+    pub creators: Vec<Address>, // Addresses besides the winery allowed to create lots
+}
+
+// Vault settings for `launch_lot`, passed through to the vault factory
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VaultConfig {
+    pub roles: Map<u32, Address>, // Vault role id -> address; must include the manager (2)
+    pub vault_fee: u32,           // Vault fee in basis points
+    pub assets: Vec<AssetStrategySet>,
+    pub soroswap_router: Address,
+    pub name_symbol: Map<String, String>, // "name" and "symbol" of the vault shares
+    pub upgradable: bool,
+}
+
+// Addresses created by `launch_lot`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LotLaunch {
+    pub token: Address,
+    pub vault: Address,
+}
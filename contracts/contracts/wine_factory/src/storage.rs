@@ -1,17 +1,31 @@
-use soroban_sdk::{Address, BytesN, Env};
+use common::models::PendingAdmin;
+use soroban_sdk::{Address, BytesN, Env, IntoVal, String, Val, Vec};
+use crate::models::Winery;
 use crate::WineFactoryError;
 
 const DAY_IN_LEDGERS: u32 = 17280;
 const INSTANCE_BUMP_AMOUNT: u32 = 30 * DAY_IN_LEDGERS;
 const INSTANCE_LIFETIME_THRESHOLD: u32 = INSTANCE_BUMP_AMOUNT - DAY_IN_LEDGERS;
+const PERSISTENT_BUMP_AMOUNT: u32 = 120 * DAY_IN_LEDGERS;
+const PERSISTENT_LIFETIME_THRESHOLD: u32 = PERSISTENT_BUMP_AMOUNT - 20 * DAY_IN_LEDGERS;
 
 #[derive(Clone)]
 #[soroban_sdk::contracttype]
 pub enum DataKey {
     Admin,
+    PendingAdmin,
     TokenWasmHash,
     TotalTokens,
     Token(u32),
+    TokenByLotId(String),
+    AdminTokenCount(Address),
+    AdminToken(Address, u32),
+    VintageTokenCount(u32),
+    VintageToken(u32, u32),
+    WineryCount,
+    WineryByIndex(u32),
+    Winery(Address),
+    VaultFactory,
 }
 
 pub fn extend_instance_ttl(e: &Env) {
@@ -32,6 +46,19 @@ pub fn put_admin(e: &Env, admin: &Address) {
     e.storage().instance().set(&DataKey::Admin, admin);
 }
 
+// Pending Admin
+pub fn get_pending_admin(e: &Env) -> Option<PendingAdmin> {
+    e.storage().instance().get(&DataKey::PendingAdmin)
+}
+
+pub fn put_pending_admin(e: &Env, pending: &PendingAdmin) {
+    e.storage().instance().set(&DataKey::PendingAdmin, pending);
+}
+
+pub fn remove_pending_admin(e: &Env) {
+    e.storage().instance().remove(&DataKey::PendingAdmin);
+}
+
 // Token WASM Hash
 pub fn get_token_wasm_hash(e: &Env) -> Result<BytesN<32>, WineFactoryError> {
     e.storage()
@@ -44,6 +71,18 @@ pub fn put_token_wasm_hash(e: &Env, hash: BytesN<32>) {
     e.storage().instance().set(&DataKey::TokenWasmHash, &hash);
 }
 
+// Vault Factory
+pub fn get_vault_factory(e: &Env) -> Result<Address, WineFactoryError> {
+    e.storage()
+        .instance()
+        .get(&DataKey::VaultFactory)
+        .ok_or(WineFactoryError::VaultFactoryNotSet)
+}
+
+pub fn put_vault_factory(e: &Env, vault_factory: &Address) {
+    e.storage().instance().set(&DataKey::VaultFactory, vault_factory);
+}
+
 // Total Tokens
 pub fn get_total_tokens(e: &Env) -> u32 {
     e.storage()
@@ -70,6 +109,140 @@ pub fn get_token_by_index(e: &Env, index: u32) -> Result<Address, WineFactoryErr
         .ok_or(WineFactoryError::TokenNotFound)
 }
 
+// Reverse indexes, kept in persistent storage as they grow with every token
+fn extend_persistent_ttl(e: &Env, key: &DataKey) {
+    e.storage()
+        .persistent()
+        .extend_ttl(key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+}
+
+fn put_persistent<V: IntoVal<Env, Val>>(e: &Env, key: DataKey, value: &V) {
+    e.storage().persistent().set(&key, value);
+    extend_persistent_ttl(e, &key);
+}
+
+fn get_count(e: &Env, key: &DataKey) -> u32 {
+    match e.storage().persistent().get::<DataKey, u32>(key) {
+        Some(count) => {
+            extend_persistent_ttl(e, key);
+            count
+        }
+        None => 0,
+    }
+}
+
+fn get_page(
+    e: &Env,
+    count: u32,
+    start: u32,
+    limit: u32,
+    key: impl Fn(u32) -> DataKey,
+) -> Vec<Address> {
+    let mut tokens = Vec::new(e);
+    let end = start.saturating_add(limit).min(count);
+    for index in start..end {
+        let key = key(index);
+        if let Some(token) = e.storage().persistent().get::<DataKey, Address>(&key) {
+            extend_persistent_ttl(e, &key);
+            tokens.push_back(token);
+        }
+    }
+    tokens
+}
+
+pub fn has_lot_id(e: &Env, lot_id: &String) -> bool {
+    e.storage()
+        .persistent()
+        .has(&DataKey::TokenByLotId(lot_id.clone()))
+}
+
+pub fn get_token_by_lot_id(e: &Env, lot_id: &String) -> Result<Address, WineFactoryError> {
+    let key = DataKey::TokenByLotId(lot_id.clone());
+    let token = e
+        .storage()
+        .persistent()
+        .get::<DataKey, Address>(&key)
+        .ok_or(WineFactoryError::TokenNotFound)?;
+    extend_persistent_ttl(e, &key);
+    Ok(token)
+}
+
+pub fn get_admin_token_count(e: &Env, admin: &Address) -> u32 {
+    get_count(e, &DataKey::AdminTokenCount(admin.clone()))
+}
+
+pub fn get_tokens_by_admin(e: &Env, admin: &Address, start: u32, limit: u32) -> Vec<Address> {
+    let count = get_admin_token_count(e, admin);
+    get_page(e, count, start, limit, |index| {
+        DataKey::AdminToken(admin.clone(), index)
+    })
+}
 
+pub fn get_vintage_token_count(e: &Env, vintage: u32) -> u32 {
+    get_count(e, &DataKey::VintageTokenCount(vintage))
+}
+
+pub fn get_tokens_by_vintage(e: &Env, vintage: u32, start: u32, limit: u32) -> Vec<Address> {
+    let count = get_vintage_token_count(e, vintage);
+    get_page(e, count, start, limit, |index| DataKey::VintageToken(vintage, index))
+}
 
+/// Adds a new token to the lot id, admin and vintage indexes.
+pub fn index_token(e: &Env, token_address: &Address, admin: &Address, lot_id: &String, vintage: u32) {
+    put_persistent(e, DataKey::TokenByLotId(lot_id.clone()), token_address);
 
+    let admin_count = get_admin_token_count(e, admin);
+    put_persistent(e, DataKey::AdminToken(admin.clone(), admin_count), token_address);
+    put_persistent(e, DataKey::AdminTokenCount(admin.clone()), &(admin_count + 1));
+
+    let vintage_count = get_vintage_token_count(e, vintage);
+    put_persistent(e, DataKey::VintageToken(vintage, vintage_count), token_address);
+    put_persistent(e, DataKey::VintageTokenCount(vintage), &(vintage_count + 1));
+}
+
+// Winery registry
+pub fn get_winery_count(e: &Env) -> u32 {
+    e.storage()
+        .instance()
+        .get(&DataKey::WineryCount)
+        .unwrap_or(0)
+}
+
+pub fn get_winery(e: &Env, winery: &Address) -> Result<Winery, WineFactoryError> {
+    let key = DataKey::Winery(winery.clone());
+    let entry = e
+        .storage()
+        .persistent()
+        .get::<DataKey, Winery>(&key)
+        .ok_or(WineFactoryError::WineryNotFound)?;
+    extend_persistent_ttl(e, &key);
+    Ok(entry)
+}
+
+pub fn has_winery(e: &Env, winery: &Address) -> bool {
+    e.storage()
+        .persistent()
+        .has(&DataKey::Winery(winery.clone()))
+}
+
+pub fn put_winery(e: &Env, winery: &Winery) {
+    put_persistent(e, DataKey::Winery(winery.address.clone()), winery);
+}
+
+/// Stores a new winery and appends it to the registry order.
+pub fn add_new_winery(e: &Env, winery: &Winery) {
+    let count = get_winery_count(e);
+    put_winery(e, winery);
+    put_persistent(e, DataKey::WineryByIndex(count), &winery.address);
+    e.storage().instance().set(&DataKey::WineryCount, &(count + 1));
+}
+
+pub fn get_wineries(e: &Env, start: u32, limit: u32) -> Vec<Winery> {
+    let mut wineries = Vec::new(e);
+    for address in get_page(e, get_winery_count(e), start, limit, DataKey::WineryByIndex).iter() {
+        if let Ok(winery) = get_winery(e, &address) {
+            wineries.push_back(winery);
+        }
+    }
+    wineries
+}
@@ -0,0 +1,80 @@
+#![cfg(test)]
+extern crate std;
+use common::models::WineLotMetadata;
+use soroban_sdk::{testutils::Address as _, Address, Env, String};
+
+use crate::storage::{add_new_token, index_token};
+use crate::{WineFactory, WineFactoryClient};
+
+// Wine Token Contract, built with `make build` (see the Makefile)
+pub(crate) mod wine_token_contract {
+    soroban_sdk::contractimport!(file = "../../target/wasm32v1-none/release/wine_token.wasm");
+}
+
+pub(crate) fn create_wine_lot_metadata(e: &Env, lot_id: &str, vintage: u32) -> WineLotMetadata {
+    WineLotMetadata {
+        lot_id: String::from_str(e, lot_id),
+        winery_name: String::from_str(e, "Bodega Catena"),
+        region: String::from_str(e, "Mendoza"),
+        country: String::from_str(e, "Argentina"),
+        vintage,
+        varietal: String::from_str(e, "Malbec"),
+        bottle_count: 1000,
+        description: None,
+        token_code: String::from_str(e, "MAL24"),
+    }
+}
+
+pub struct WineFactoryTest<'a> {
+    env: Env,
+    admin: Address,
+    factory: WineFactoryClient<'a>,
+}
+
+impl<'a> WineFactoryTest<'a> {
+    fn setup() -> Self {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let admin = Address::generate(&env);
+        let token_wasm_hash = env.deployer().upload_contract_wasm(wine_token_contract::WASM);
+        let factory = WineFactoryClient::new(
+            &env,
+            &env.register(WineFactory, (admin.clone(), token_wasm_hash)),
+        );
+
+        WineFactoryTest {
+            env,
+            admin,
+            factory,
+        }
+    }
+
+    fn register_verified_winery(&self, winery: &Address) {
+        self.factory.register_winery(
+            winery,
+            &String::from_str(&self.env, "Bodega Catena"),
+            &String::from_str(&self.env, "Argentina"),
+        );
+        self.factory.verify_winery(winery);
+    }
+
+    /// Records a token as if `create_wine_token` had deployed it, for tests
+    /// that only exercise the factory's indexes.
+    fn index_token(&self, winery: &Address, lot_id: &str, vintage: u32) -> Address {
+        let token = Address::generate(&self.env);
+        self.env.as_contract(&self.factory.address, || {
+            add_new_token(&self.env, token.clone());
+            index_token(
+                &self.env,
+                &token,
+                winery,
+                &String::from_str(&self.env, lot_id),
+                vintage,
+            );
+        });
+        token
+    }
+}
+
+mod wine_factory;
@@ -0,0 +1,88 @@
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::xdr::ScAddress;
+use soroban_sdk::{Address, String};
+
+use crate::test::{create_wine_lot_metadata, WineFactoryTest};
+
+fn create_token(test: &WineFactoryTest, winery: &Address, lot_id: &str, vintage: u32) -> Address {
+    test.factory.create_wine_token(
+        winery,
+        winery,
+        &7,
+        &String::from_str(&test.env, "Malbec Reserve"),
+        &String::from_str(&test.env, "MAL"),
+        &create_wine_lot_metadata(&test.env, lot_id, vintage),
+        &None,
+    )
+}
+
+#[test]
+fn predicted_address_is_deterministic() {
+    let test = WineFactoryTest::setup();
+    let winery = Address::generate(&test.env);
+    let lot_id = String::from_str(&test.env, "MAL-2024-001");
+
+    let predicted = test.factory.predict_token_address(&winery, &lot_id);
+    assert_eq!(test.factory.predict_token_address(&winery, &lot_id), predicted);
+
+    // Creating other tokens first does not affect the address
+    test.register_verified_winery(&winery);
+    create_token(&test, &winery, "MAL-2023-001", 2023);
+    assert_eq!(test.factory.predict_token_address(&winery, &lot_id), predicted);
+}
+
+#[test]
+fn predicted_address_matches_created_token() {
+    let test = WineFactoryTest::setup();
+    let winery = Address::generate(&test.env);
+    test.register_verified_winery(&winery);
+    let lot_id = String::from_str(&test.env, "MAL-2024-001");
+
+    let predicted = test.factory.predict_token_address(&winery, &lot_id);
+    let token = create_token(&test, &winery, "MAL-2024-001", 2024);
+    assert_eq!(token, predicted);
+    assert_eq!(test.factory.predict_token_address(&winery, &lot_id), token);
+}
+
+#[test]
+fn created_address_does_not_depend_on_creation_order() {
+    let first = WineFactoryTest::setup();
+    let winery = Address::generate(&first.env);
+    first.register_verified_winery(&winery);
+    let old_then_new = (
+        create_token(&first, &winery, "MAL-2023-001", 2023),
+        create_token(&first, &winery, "MAL-2024-001", 2024),
+    );
+
+    let second = WineFactoryTest::setup();
+    let winery = Address::generate(&second.env);
+    second.register_verified_winery(&winery);
+    let new = create_token(&second, &winery, "MAL-2024-001", 2024);
+    let old = create_token(&second, &winery, "MAL-2023-001", 2023);
+
+    // Both factories and wineries are the same, only the order differs
+    assert_eq!(
+        ScAddress::from(&second.factory.address),
+        ScAddress::from(&first.factory.address)
+    );
+    assert_eq!(ScAddress::from(&old), ScAddress::from(&old_then_new.0));
+    assert_eq!(ScAddress::from(&new), ScAddress::from(&old_then_new.1));
+    assert_ne!(old, new);
+}
+
+#[test]
+fn predicted_address_depends_on_admin_and_lot_id() {
+    let test = WineFactoryTest::setup();
+    let winery = Address::generate(&test.env);
+    let other = Address::generate(&test.env);
+    let lot_id = String::from_str(&test.env, "MAL-2024-001");
+
+    let predicted = test.factory.predict_token_address(&winery, &lot_id);
+    assert_ne!(test.factory.predict_token_address(&other, &lot_id), predicted);
+    assert_ne!(
+        test.factory
+            .predict_token_address(&winery, &String::from_str(&test.env, "MAL-2024-002")),
+        predicted
+    );
+    assert_ne!(predicted, test.factory.address);
+}
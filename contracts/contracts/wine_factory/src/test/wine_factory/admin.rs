@@ -0,0 +1,56 @@
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::Address;
+
+use crate::test::WineFactoryTest;
+use crate::WineFactoryError;
+
+#[test]
+fn propose_and_accept_admin() {
+    let test = WineFactoryTest::setup();
+    let new_admin = Address::generate(&test.env);
+
+    test.factory.propose_admin(&new_admin, &0);
+    assert_eq!(test.factory.pending_admin().unwrap().new_admin, new_admin);
+    assert_eq!(test.factory.admin(), test.admin);
+
+    test.factory.accept_admin();
+    assert_eq!(test.factory.admin(), new_admin);
+    assert!(test.factory.pending_admin().is_none());
+}
+
+#[test]
+fn accept_admin_respects_timelock() {
+    let test = WineFactoryTest::setup();
+    let new_admin = Address::generate(&test.env);
+
+    let start = test.env.ledger().sequence();
+    test.factory.propose_admin(&new_admin, &50);
+
+    let result = test.factory.try_accept_admin();
+    assert_eq!(result, Err(Ok(WineFactoryError::AdminTransferLocked)));
+
+    test.env.ledger().set_sequence_number(start + 50);
+    test.factory.accept_admin();
+    assert_eq!(test.factory.admin(), new_admin);
+}
+
+#[test]
+fn cancel_admin_transfer() {
+    let test = WineFactoryTest::setup();
+    let new_admin = Address::generate(&test.env);
+
+    test.factory.propose_admin(&new_admin, &0);
+    test.factory.cancel_admin_transfer();
+
+    let result = test.factory.try_accept_admin();
+    assert_eq!(result, Err(Ok(WineFactoryError::NoPendingAdmin)));
+    assert_eq!(test.factory.admin(), test.admin);
+}
+
+#[test]
+fn cancel_without_proposal_fails() {
+    let test = WineFactoryTest::setup();
+
+    let result = test.factory.try_cancel_admin_transfer();
+    assert_eq!(result, Err(Ok(WineFactoryError::NoPendingAdmin)));
+}
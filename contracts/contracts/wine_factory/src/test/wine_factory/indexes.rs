@@ -0,0 +1,90 @@
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{vec, Address, String};
+
+use crate::test::{create_wine_lot_metadata, WineFactoryTest};
+use crate::WineFactoryError;
+
+#[test]
+fn token_by_lot_id() {
+    let test = WineFactoryTest::setup();
+    let winery = Address::generate(&test.env);
+
+    let token = test.index_token(&winery, "MAL-2024-001", 2024);
+
+    assert_eq!(
+        test.factory
+            .get_token_by_lot_id(&String::from_str(&test.env, "MAL-2024-001")),
+        token
+    );
+    assert_eq!(
+        test.factory
+            .try_get_token_by_lot_id(&String::from_str(&test.env, "MAL-2024-002")),
+        Err(Ok(WineFactoryError::TokenNotFound))
+    );
+}
+
+#[test]
+fn tokens_by_admin_are_paginated() {
+    let test = WineFactoryTest::setup();
+    let winery = Address::generate(&test.env);
+    let other = Address::generate(&test.env);
+
+    let first = test.index_token(&winery, "MAL-2023-001", 2023);
+    test.index_token(&other, "CAB-2024-001", 2024);
+    let second = test.index_token(&winery, "MAL-2024-001", 2024);
+    let third = test.index_token(&winery, "MAL-2024-002", 2024);
+
+    assert_eq!(test.factory.total_tokens(), 4);
+    assert_eq!(test.factory.total_tokens_by_admin(&winery), 3);
+    assert_eq!(
+        test.factory.get_tokens_by_admin(&winery, &0, &2),
+        vec![&test.env, first, second.clone()]
+    );
+    assert_eq!(
+        test.factory.get_tokens_by_admin(&winery, &1, &10),
+        vec![&test.env, second, third]
+    );
+    assert_eq!(test.factory.get_tokens_by_admin(&winery, &3, &10).len(), 0);
+}
+
+#[test]
+fn tokens_by_vintage_are_paginated() {
+    let test = WineFactoryTest::setup();
+    let winery = Address::generate(&test.env);
+    let other = Address::generate(&test.env);
+
+    test.index_token(&winery, "MAL-2023-001", 2023);
+    let first = test.index_token(&other, "CAB-2024-001", 2024);
+    let second = test.index_token(&winery, "MAL-2024-001", 2024);
+
+    assert_eq!(test.factory.total_tokens_by_vintage(&2024), 2);
+    assert_eq!(
+        test.factory.get_tokens_by_vintage(&2024, &0, &10),
+        vec![&test.env, first, second.clone()]
+    );
+    assert_eq!(
+        test.factory.get_tokens_by_vintage(&2024, &1, &1),
+        vec![&test.env, second]
+    );
+    assert_eq!(test.factory.total_tokens_by_vintage(&2022), 0);
+}
+
+#[test]
+fn duplicate_lot_id_is_rejected() {
+    let test = WineFactoryTest::setup();
+    let winery = Address::generate(&test.env);
+
+    test.register_verified_winery(&winery);
+    test.index_token(&winery, "MAL-2024-001", 2024);
+
+    let result = test.factory.try_create_wine_token(
+        &winery,
+        &winery,
+        &7,
+        &String::from_str(&test.env, "Malbec Reserve 2024"),
+        &String::from_str(&test.env, "MAL24"),
+        &create_wine_lot_metadata(&test.env, "MAL-2024-001", 2024),
+        &None,
+    );
+    assert_eq!(result, Err(Ok(WineFactoryError::LotIdAlreadyExists)));
+}
@@ -0,0 +1,224 @@
+use common::models::{AssetStrategySet, WineLotMetadata};
+use soroban_sdk::testutils::{Address as _, AuthorizedFunction, Events};
+use soroban_sdk::{
+    contract, contractimpl, map, symbol_short, vec, Address, Env, Map, String, Symbol, TryFromVal,
+    Val, Vec,
+};
+
+use crate::models::{LotLaunch, VaultConfig};
+use crate::test::{create_wine_lot_metadata, wine_token_contract, WineFactoryTest};
+use crate::vault::VAULT_MANAGER_ROLE;
+use crate::WineFactoryError;
+
+// Stands in for a vinifica vault: only the wine lot parts `launch_lot` touches
+#[contract]
+pub struct VaultStub;
+
+#[contractimpl]
+impl VaultStub {
+    pub fn initialize(e: Env, manager: Address, wine_lot_metadata: Option<WineLotMetadata>) {
+        e.storage().instance().set(&symbol_short!("manager"), &manager);
+        e.storage().instance().set(&symbol_short!("metadata"), &wine_lot_metadata);
+    }
+
+    pub fn set_lot_token(e: Env, caller: Address, lot_token: Address) {
+        caller.require_auth();
+        let manager: Address = e.storage().instance().get(&symbol_short!("manager")).unwrap();
+        assert_eq!(caller, manager);
+        assert!(Self::get_wine_lot_metadata(e.clone()).is_some());
+        e.storage().instance().set(&symbol_short!("lot_token"), &lot_token);
+    }
+
+    pub fn get_lot_token(e: Env) -> Option<Address> {
+        e.storage().instance().get(&symbol_short!("lot_token"))
+    }
+
+    pub fn get_wine_lot_metadata(e: Env) -> Option<WineLotMetadata> {
+        e.storage().instance().get(&symbol_short!("metadata")).unwrap()
+    }
+}
+
+// Stands in for the vinifica vault factory. Native contracts can't deploy
+// other native contracts, so it hands out a vault registered by the test.
+#[contract]
+pub struct VaultFactoryStub;
+
+#[contractimpl]
+impl VaultFactoryStub {
+    pub fn __constructor(e: Env, vault: Address) {
+        e.storage().instance().set(&symbol_short!("vault"), &vault);
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_vinifica_vault(
+        e: Env,
+        roles: Map<u32, Address>,
+        _vault_fee: u32,
+        _assets: Vec<AssetStrategySet>,
+        _soroswap_router: Address,
+        _name_symbol: Map<String, String>,
+        _upgradable: bool,
+        wine_lot_metadata: Option<WineLotMetadata>,
+    ) -> Address {
+        let vault: Address = e.storage().instance().get(&symbol_short!("vault")).unwrap();
+        VaultStubClient::new(&e, &vault)
+            .initialize(&roles.get(VAULT_MANAGER_ROLE).unwrap(), &wine_lot_metadata);
+        vault
+    }
+}
+
+fn vault_config(test: &WineFactoryTest, roles: Map<u32, Address>) -> VaultConfig {
+    VaultConfig {
+        roles,
+        vault_fee: 100,
+        assets: vec![&test.env],
+        soroswap_router: Address::generate(&test.env),
+        name_symbol: map![
+            &test.env,
+            (String::from_str(&test.env, "name"), String::from_str(&test.env, "Malbec Vault")),
+            (String::from_str(&test.env, "symbol"), String::from_str(&test.env, "MALV")),
+        ],
+        upgradable: true,
+    }
+}
+
+fn manager_roles(test: &WineFactoryTest) -> Map<u32, Address> {
+    map![&test.env, (VAULT_MANAGER_ROLE, Address::generate(&test.env))]
+}
+
+fn try_launch(
+    test: &WineFactoryTest,
+    winery: &Address,
+    config: &VaultConfig,
+) -> Result<LotLaunch, WineFactoryError> {
+    match test.factory.try_launch_lot(
+        winery,
+        winery,
+        &7,
+        &String::from_str(&test.env, "Malbec Reserve 2024"),
+        &String::from_str(&test.env, "MAL24"),
+        &create_wine_lot_metadata(&test.env, "MAL-2024-001", 2024),
+        &None,
+        config,
+    ) {
+        Ok(Ok(launch)) => Ok(launch),
+        Err(Ok(err)) => Err(err),
+        _ => panic!("unexpected launch result"),
+    }
+}
+
+#[test]
+fn admin_sets_vault_factory() {
+    let test = WineFactoryTest::setup();
+    let vault_factory = Address::generate(&test.env);
+
+    assert_eq!(
+        test.factory.try_vault_factory(),
+        Err(Ok(WineFactoryError::VaultFactoryNotSet))
+    );
+
+    test.factory.set_vault_factory(&vault_factory);
+    assert_eq!(test.factory.vault_factory(), vault_factory);
+}
+
+#[test]
+fn launch_requires_vault_factory() {
+    let test = WineFactoryTest::setup();
+    let winery = Address::generate(&test.env);
+    test.register_verified_winery(&winery);
+
+    let config = vault_config(&test, manager_roles(&test));
+    assert_eq!(
+        try_launch(&test, &winery, &config),
+        Err(WineFactoryError::VaultFactoryNotSet)
+    );
+}
+
+#[test]
+fn launch_requires_vault_manager() {
+    let test = WineFactoryTest::setup();
+    let winery = Address::generate(&test.env);
+    test.register_verified_winery(&winery);
+    test.factory.set_vault_factory(&Address::generate(&test.env));
+
+    let config = vault_config(&test, map![&test.env, (0u32, Address::generate(&test.env))]);
+    assert_eq!(
+        try_launch(&test, &winery, &config),
+        Err(WineFactoryError::VaultManagerNotSet)
+    );
+}
+
+#[test]
+fn launch_requires_verified_winery() {
+    let test = WineFactoryTest::setup();
+    let winery = Address::generate(&test.env);
+    test.factory.register_winery(
+        &winery,
+        &String::from_str(&test.env, "Bodega Catena"),
+        &String::from_str(&test.env, "Argentina"),
+    );
+    test.factory.set_vault_factory(&Address::generate(&test.env));
+
+    let config = vault_config(&test, manager_roles(&test));
+    assert_eq!(
+        try_launch(&test, &winery, &config),
+        Err(WineFactoryError::WineryNotVerified)
+    );
+    assert_eq!(test.factory.total_tokens(), 0);
+}
+
+#[test]
+fn launch_deploys_and_links_token_and_vault() {
+    let test = WineFactoryTest::setup();
+    let winery = Address::generate(&test.env);
+    test.register_verified_winery(&winery);
+
+    let vault = VaultStubClient::new(&test.env, &test.env.register(VaultStub, ()));
+    let vault_factory = test.env.register(VaultFactoryStub, (vault.address.clone(),));
+    test.factory.set_vault_factory(&vault_factory);
+
+    let manager = Address::generate(&test.env);
+    let config = vault_config(&test, map![&test.env, (VAULT_MANAGER_ROLE, manager.clone())]);
+    let launch = try_launch(&test, &winery, &config).unwrap();
+
+    // The winery and the vault manager each authorize the launch itself
+    let auths = test.env.auths();
+    assert_eq!(auths.len(), 2);
+    for signer in [&winery, &manager] {
+        let (_, invocation) = auths.iter().find(|(address, _)| address == signer).unwrap();
+        let AuthorizedFunction::Contract((contract, function, _)) = &invocation.function else {
+            panic!("expected a contract call");
+        };
+        assert_eq!(contract, &test.factory.address);
+        assert_eq!(function, &Symbol::new(&test.env, "launch_lot"));
+    }
+
+    let lot_launched = String::from_str(&test.env, "lot_launched");
+    let mut launched = Vec::<Val>::new(&test.env);
+    for (contract, topics, data) in test.env.events().all().iter() {
+        if contract == test.factory.address
+            && String::try_from_val(&test.env, &topics.get_unchecked(0)) == Ok(lot_launched.clone())
+        {
+            launched.push_back(data);
+        }
+    }
+    assert_eq!(launched.len(), 1);
+    let (lot_id, token_address, vault_address) =
+        <(String, Address, Address)>::try_from_val(&test.env, &launched.get_unchecked(0)).unwrap();
+    assert_eq!(lot_id, String::from_str(&test.env, "MAL-2024-001"));
+    assert_eq!(token_address, launch.token);
+    assert_eq!(vault_address, launch.vault);
+
+    // The token is deployed from the factory's WASM and indexed
+    let token = wine_token_contract::Client::new(&test.env, &launch.token);
+    assert_eq!(token.admin(), winery);
+    assert_eq!(token.get_wine_lot_metadata().lot_id, lot_id);
+    assert_eq!(test.factory.get_token_by_lot_id(&lot_id), launch.token);
+    assert_eq!(test.factory.total_tokens(), 1);
+
+    // The vault was created for the lot, and each side points at the other
+    assert_eq!(launch.vault, vault.address);
+    assert_eq!(vault.get_wine_lot_metadata().unwrap().lot_id, lot_id);
+    assert_eq!(token.vault(), Some(launch.vault.clone()));
+    assert_eq!(vault.get_lot_token(), Some(launch.token.clone()));
+}
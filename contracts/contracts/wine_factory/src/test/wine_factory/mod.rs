@@ -0,0 +1,6 @@
+mod address;
+mod admin;
+mod indexes;
+mod launch;
+mod upgrade;
+mod winery;
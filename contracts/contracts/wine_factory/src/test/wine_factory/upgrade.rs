@@ -0,0 +1,74 @@
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{vec, Address, Env, String, Symbol, Vec};
+
+use crate::test::wine_token_contract::{Client as WineTokenClient, LotStatus, Role};
+use crate::test::{create_wine_lot_metadata, WineFactoryTest};
+use crate::WineFactoryError;
+
+// Storage key of a wine token `DataKey` unit variant
+fn token_key(e: &Env, variant: &str) -> Vec<Symbol> {
+    vec![e, Symbol::new(e, variant)]
+}
+
+// Creates a recalled token through the factory, then rewinds its storage to
+// the version 2 layout, which had no pause flag
+fn add_legacy_token<'a>(test: &WineFactoryTest, winery: &Address) -> WineTokenClient<'a> {
+    test.register_verified_winery(winery);
+    let token = test.factory.create_wine_token(
+        winery,
+        winery,
+        &0,
+        &String::from_str(&test.env, "Malbec Reserve 2024"),
+        &String::from_str(&test.env, "MAL24"),
+        &create_wine_lot_metadata(&test.env, "MAL-2024-001", 2024),
+        &None,
+    );
+    let client = WineTokenClient::new(&test.env, &token);
+    client.set_status(winery, &LotStatus::Recalled, &None);
+    test.env.as_contract(&token, || {
+        let storage = test.env.storage().instance();
+        storage.set(&token_key(&test.env, "CodeVersion"), &2u32);
+        storage.remove(&token_key(&test.env, "Paused"));
+    });
+    client
+}
+
+#[test]
+fn tokens_without_opt_in_are_skipped() {
+    let test = WineFactoryTest::setup();
+    let winery = Address::generate(&test.env);
+    let token = add_legacy_token(&test, &winery);
+
+    assert_eq!(test.factory.upgrade_tokens(&vec![&test.env, 0]).len(), 0);
+    assert_eq!(token.code_version(), 2);
+}
+
+#[test]
+fn opted_in_tokens_are_upgraded_and_migrated() {
+    let test = WineFactoryTest::setup();
+    let winery = Address::generate(&test.env);
+    let token = add_legacy_token(&test, &winery);
+    assert_eq!(token.code_version(), 2);
+
+    token.grant_role(&Role::Upgrader, &test.factory.address);
+    assert_eq!(
+        test.factory.upgrade_tokens(&vec![&test.env, 0]),
+        vec![&test.env, token.address.clone()]
+    );
+    assert_eq!(token.code_version(), 3);
+    assert!(token.is_paused());
+
+    // Running it again finds nothing left to migrate
+    test.factory.upgrade_tokens(&vec![&test.env, 0]);
+    assert_eq!(token.code_version(), 3);
+}
+
+#[test]
+fn unknown_index_fails() {
+    let test = WineFactoryTest::setup();
+
+    assert_eq!(
+        test.factory.try_upgrade_tokens(&vec![&test.env, 3]),
+        Err(Ok(WineFactoryError::TokenNotFound))
+    );
+}
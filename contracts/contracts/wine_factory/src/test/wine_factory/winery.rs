@@ -0,0 +1,235 @@
+use soroban_sdk::testutils::{Address as _, Events, MockAuth, MockAuthInvoke};
+use soroban_sdk::{vec, Address, IntoVal, String};
+
+use crate::models::WineryStatus;
+use crate::test::{create_wine_lot_metadata, wine_token_contract, WineFactoryTest};
+use crate::WineFactoryError;
+
+fn try_create(
+    test: &WineFactoryTest,
+    creator: &Address,
+    winery: &Address,
+) -> Result<Address, WineFactoryError> {
+    match test.factory.try_create_wine_token(
+        creator,
+        winery,
+        &7,
+        &String::from_str(&test.env, "Malbec Reserve 2024"),
+        &String::from_str(&test.env, "MAL24"),
+        &create_wine_lot_metadata(&test.env, "MAL-2024-001", 2024),
+        &None,
+    ) {
+        Ok(Ok(token)) => Ok(token),
+        Err(Ok(err)) => Err(err),
+        _ => panic!("unexpected create result"),
+    }
+}
+
+#[test]
+fn register_and_verify_winery() {
+    let test = WineFactoryTest::setup();
+    let winery = Address::generate(&test.env);
+
+    test.factory.register_winery(
+        &winery,
+        &String::from_str(&test.env, "Bodega Catena"),
+        &String::from_str(&test.env, "Argentina"),
+    );
+    let entry = test.factory.get_winery(&winery);
+    assert_eq!(entry.status, WineryStatus::Pending);
+    assert_eq!(entry.name, String::from_str(&test.env, "Bodega Catena"));
+    assert_eq!(entry.creators.len(), 0);
+
+    test.factory.verify_winery(&winery);
+    assert_eq!(test.factory.get_winery(&winery).status, WineryStatus::Verified);
+
+    assert_eq!(
+        test.factory.try_register_winery(
+            &winery,
+            &String::from_str(&test.env, "Bodega Catena"),
+            &String::from_str(&test.env, "Argentina"),
+        ),
+        Err(Ok(WineFactoryError::WineryAlreadyRegistered))
+    );
+}
+
+#[test]
+fn wineries_are_paginated() {
+    let test = WineFactoryTest::setup();
+    let first = Address::generate(&test.env);
+    let second = Address::generate(&test.env);
+    let third = Address::generate(&test.env);
+
+    test.register_verified_winery(&first);
+    test.register_verified_winery(&second);
+    test.register_verified_winery(&third);
+
+    assert_eq!(test.factory.total_wineries(), 3);
+    let page = test.factory.get_wineries(&1, &5);
+    assert_eq!(page.len(), 2);
+    assert_eq!(page.get_unchecked(0).address, second);
+    assert_eq!(page.get_unchecked(1).address, third);
+}
+
+#[test]
+fn suspend_and_reinstate_emit_events() {
+    let test = WineFactoryTest::setup();
+    let winery = Address::generate(&test.env);
+    test.register_verified_winery(&winery);
+
+    test.factory.suspend_winery(&winery);
+    assert_eq!(
+        test.env.events().all(),
+        vec![
+            &test.env,
+            (
+                test.factory.address.clone(),
+                ("winery_suspended", winery.clone()).into_val(&test.env),
+                ().into_val(&test.env),
+            ),
+        ]
+    );
+    assert_eq!(test.factory.get_winery(&winery).status, WineryStatus::Suspended);
+
+    test.factory.reinstate_winery(&winery);
+    assert_eq!(
+        test.env.events().all(),
+        vec![
+            &test.env,
+            (
+                test.factory.address.clone(),
+                ("winery_reinstated", winery.clone()).into_val(&test.env),
+                ().into_val(&test.env),
+            ),
+        ]
+    );
+    assert_eq!(test.factory.get_winery(&winery).status, WineryStatus::Verified);
+}
+
+#[test]
+fn invalid_status_transitions_fail() {
+    let test = WineFactoryTest::setup();
+    let winery = Address::generate(&test.env);
+
+    assert_eq!(
+        test.factory.try_verify_winery(&winery),
+        Err(Ok(WineFactoryError::WineryNotFound))
+    );
+
+    test.factory.register_winery(
+        &winery,
+        &String::from_str(&test.env, "Bodega Catena"),
+        &String::from_str(&test.env, "Argentina"),
+    );
+    assert_eq!(
+        test.factory.try_suspend_winery(&winery),
+        Err(Ok(WineFactoryError::InvalidWineryStatus))
+    );
+    assert_eq!(
+        test.factory.try_reinstate_winery(&winery),
+        Err(Ok(WineFactoryError::InvalidWineryStatus))
+    );
+}
+
+#[test]
+fn only_verified_wineries_create_tokens() {
+    let test = WineFactoryTest::setup();
+    let winery = Address::generate(&test.env);
+
+    assert_eq!(
+        try_create(&test, &winery, &winery),
+        Err(WineFactoryError::WineryNotFound)
+    );
+
+    test.factory.register_winery(
+        &winery,
+        &String::from_str(&test.env, "Bodega Catena"),
+        &String::from_str(&test.env, "Argentina"),
+    );
+    assert_eq!(
+        try_create(&test, &winery, &winery),
+        Err(WineFactoryError::WineryNotVerified)
+    );
+
+    test.factory.verify_winery(&winery);
+    let token = try_create(&test, &winery, &winery).unwrap();
+    let client = wine_token_contract::Client::new(&test.env, &token);
+    assert_eq!(client.admin(), winery);
+    assert_eq!(client.decimals(), 7);
+    assert_eq!(client.get_wine_lot_metadata().lot_id, String::from_str(&test.env, "MAL-2024-001"));
+    assert_eq!(test.factory.total_tokens(), 1);
+    assert_eq!(
+        test.factory.get_token_by_lot_id(&String::from_str(&test.env, "MAL-2024-001")),
+        token
+    );
+
+    test.factory.suspend_winery(&winery);
+    assert_eq!(
+        try_create(&test, &winery, &winery),
+        Err(WineFactoryError::WineryNotVerified)
+    );
+}
+
+#[test]
+fn registered_creators_create_for_their_winery() {
+    let test = WineFactoryTest::setup();
+    let winery = Address::generate(&test.env);
+    let creator = Address::generate(&test.env);
+    test.register_verified_winery(&winery);
+
+    assert_eq!(
+        try_create(&test, &creator, &winery),
+        Err(WineFactoryError::NotWineryCreator)
+    );
+
+    test.factory.add_winery_creator(&winery, &creator);
+    assert_eq!(test.factory.get_winery(&winery).creators, vec![&test.env, creator.clone()]);
+    let token = try_create(&test, &creator, &winery).unwrap();
+    // The token belongs to the winery, not the creator
+    assert_eq!(wine_token_contract::Client::new(&test.env, &token).admin(), winery);
+
+    test.factory.remove_winery_creator(&winery, &creator);
+    assert_eq!(
+        try_create(&test, &creator, &winery),
+        Err(WineFactoryError::NotWineryCreator)
+    );
+    assert_eq!(
+        test.factory.try_remove_winery_creator(&winery, &creator),
+        Err(Ok(WineFactoryError::NotWineryCreator))
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Auth, InvalidAction)")]
+fn create_requires_creator_auth() {
+    let test = WineFactoryTest::setup();
+    let winery = Address::generate(&test.env);
+    let creator = Address::generate(&test.env);
+    test.register_verified_winery(&winery);
+    test.factory.add_winery_creator(&winery, &creator);
+
+    let name = String::from_str(&test.env, "Malbec Reserve 2024");
+    let symbol = String::from_str(&test.env, "MAL24");
+    let metadata = create_wine_lot_metadata(&test.env, "MAL-2024-001", 2024);
+    // The winery signs, but the creator named in the call does not
+    test.env.mock_auths(&[MockAuth {
+        address: &winery,
+        invoke: &MockAuthInvoke {
+            contract: &test.factory.address,
+            fn_name: "create_wine_token",
+            args: (
+                creator.clone(),
+                winery.clone(),
+                7u32,
+                name.clone(),
+                symbol.clone(),
+                metadata.clone(),
+                None::<Address>,
+            )
+                .into_val(&test.env),
+            sub_invokes: &[],
+        },
+    }]);
+    test.factory
+        .create_wine_token(&creator, &winery, &7, &name, &symbol, &metadata, &None);
+}
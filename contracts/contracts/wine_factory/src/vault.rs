@@ -0,0 +1,28 @@
+use common::models::{AssetStrategySet, WineLotMetadata};
+use soroban_sdk::{contractclient, Address, Env, Map, String, Vec};
+
+// Role id of the vault manager in the vault's roles map
+pub const VAULT_MANAGER_ROLE: u32 = 2;
+
+/// Subset of the vault factory interface used by the factory.
+#[allow(dead_code)]
+#[contractclient(name = "VaultFactoryClient")]
+pub trait VaultFactoryInterface {
+    fn create_vinifica_vault(
+        e: Env,
+        roles: Map<u32, Address>,
+        vault_fee: u32,
+        assets: Vec<AssetStrategySet>,
+        soroswap_router: Address,
+        name_symbol: Map<String, String>,
+        upgradable: bool,
+        wine_lot_metadata: Option<WineLotMetadata>,
+    ) -> Address;
+}
+
+/// Subset of the vault interface used by the factory.
+#[allow(dead_code)]
+#[contractclient(name = "VaultClient")]
+pub trait VaultInterface {
+    fn set_lot_token(e: Env, caller: Address, lot_token: Address);
+}
@@ -0,0 +1,12 @@
+use common::models::Role;
+use soroban_sdk::{contractclient, Address, BytesN, Env};
+
+/// Subset of the `WineToken` interface used by the factory.
+#[allow(dead_code)]
+#[contractclient(name = "WineTokenClient")]
+pub trait WineTokenInterface {
+    fn has_role(e: Env, role: Role, account: Address) -> bool;
+    fn upgrade(e: Env, upgrader: Address, new_wasm_hash: BytesN<32>);
+    fn migrate(e: Env, upgrader: Address) -> u32;
+    fn set_vault(e: Env, vault: Address);
+}
@@ -0,0 +1,86 @@
+use soroban_sdk::{contracttype, Address, Env};
+
+pub use common::models::Role;
+
+use crate::error::WineTokenError;
+use crate::storage::{read_administrator, PERSISTENT_BUMP_AMOUNT, PERSISTENT_LIFETIME_THRESHOLD};
+
+#[contracttype]
+#[derive(Clone)]
+pub enum RolesDataKey {
+    Member(Role, Address),
+}
+
+#[derive(Clone)]
+pub struct AccessControl(Env);
+
+impl AccessControl {
+    pub fn new(env: &Env) -> AccessControl {
+        AccessControl(env.clone())
+    }
+}
+
+pub trait AccessControlTrait {
+    fn has_role(&self, role: Role, account: &Address) -> bool;
+    fn grant_role(&self, role: Role, account: &Address) -> Result<(), WineTokenError>;
+    fn revoke_role(&self, role: Role, account: &Address) -> Result<(), WineTokenError>;
+    fn require_role(&self, role: Role, caller: &Address) -> Result<(), WineTokenError>;
+}
+
+impl AccessControlTrait for AccessControl {
+    fn has_role(&self, role: Role, account: &Address) -> bool {
+        if *account == read_administrator(&self.0) {
+            return true;
+        }
+        if role == Role::Admin {
+            return false;
+        }
+
+        let key = RolesDataKey::Member(role, account.clone());
+        let is_member = self.0.storage().persistent().has(&key);
+        if is_member {
+            self.0
+                .storage()
+                .persistent()
+                .extend_ttl(&key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+        }
+        is_member
+    }
+
+    fn grant_role(&self, role: Role, account: &Address) -> Result<(), WineTokenError> {
+        // The admin role moves through `propose_admin`, never through grants
+        if role == Role::Admin {
+            return Err(WineTokenError::RoleNotGrantable);
+        }
+
+        let key = RolesDataKey::Member(role, account.clone());
+        self.0.storage().persistent().set(&key, &true);
+        self.0
+            .storage()
+            .persistent()
+            .extend_ttl(&key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+        Ok(())
+    }
+
+    fn revoke_role(&self, role: Role, account: &Address) -> Result<(), WineTokenError> {
+        if role == Role::Admin {
+            return Err(WineTokenError::RoleNotGrantable);
+        }
+
+        let key = RolesDataKey::Member(role, account.clone());
+        if !self.0.storage().persistent().has(&key) {
+            return Err(WineTokenError::RoleNotFound);
+        }
+        self.0.storage().persistent().remove(&key);
+        Ok(())
+    }
+
+    fn require_role(&self, role: Role, caller: &Address) -> Result<(), WineTokenError> {
+        caller.require_auth();
+
+        if !self.has_role(role, caller) {
+            return Err(WineTokenError::Unauthorized);
+        }
+        Ok(())
+    }
+}
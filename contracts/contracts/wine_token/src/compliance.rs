@@ -0,0 +1,34 @@
+use soroban_sdk::{contractclient, vec, Address, Env, String, Vec};
+
+use crate::storage::{read_wine_lot_metadata, DataKey};
+
+/// Subset of the `ComplianceRegistry` interface used by the token.
+#[allow(dead_code)]
+#[contractclient(name = "ComplianceClient")]
+pub trait ComplianceInterface {
+    fn check(e: Env, origin_country: String, accounts: Vec<Address>);
+}
+
+/// Compliance registry is fixed at construction and never changes afterwards.
+pub fn read_compliance(e: &Env) -> Option<Address> {
+    e.storage().instance().get(&DataKey::Compliance)
+}
+
+pub fn write_compliance(e: &Env, registry: &Address) {
+    e.storage().instance().set(&DataKey::Compliance, registry);
+}
+
+/// Panics with the registry's `NotAllowlisted` or `CountryBlocked` error (#142,
+/// #143) if any of `accounts` may not hold a lot of this origin country. Does
+/// nothing for tokens created without a compliance registry.
+pub fn check_compliance(e: &Env, accounts: Vec<Address>) {
+    if let Some(registry) = read_compliance(e) {
+        let origin_country = read_wine_lot_metadata(e).country;
+        ComplianceClient::new(e, &registry).check(&origin_country, &accounts);
+    }
+}
+
+/// Checks a sender and a recipient with a single registry call.
+pub fn check_transfer_compliance(e: &Env, from: &Address, to: &Address) {
+    check_compliance(e, vec![e, from.clone(), to.clone()]);
+}
@@ -0,0 +1,56 @@
+use common::models::WineLotDetailsError;
+use soroban_sdk::{self, contracterror};
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum WineTokenError {
+    // Status Errors (10x)
+    InvalidStatusTransition = 100,
+
+    // Supply Errors (11x)
+    SupplyCapExceeded = 110,
+    InsufficientBalance = 111,
+
+    // Authorization/Role-based Errors (13x)
+    Unauthorized = 130,
+    RoleNotFound = 131,
+    RoleNotGrantable = 132,
+    NoPendingAdmin = 133,
+    AdminTransferLocked = 134,
+
+    // Compliance Errors (14x), NotAllowlisted (142) and CountryBlocked (143)
+    // are raised by the compliance registry
+    AccountFrozen = 140,
+    TransfersPaused = 141,
+
+    // Metadata Errors (15x)
+    ImmutableMetadataField = 150,
+    MetadataUnchanged = 151,
+    BottleCountBelowMinted = 152,
+    UnsupportedDetailsVersion = 153,
+    InvalidAbv = 154,
+    InvalidBlend = 155,
+
+    // Batch Errors (19x)
+    EmptyBatch = 190,
+    BatchTooLarge = 191,
+    NegativeAmount = 192,
+    BatchTotalOverflow = 193,
+
+    // Snapshot Errors (20x)
+    SnapshotNotFound = 200,
+
+    // Upgrade Errors (21x)
+    UnsupportedMigration = 210,
+}
+
+impl From<WineLotDetailsError> for WineTokenError {
+    fn from(err: WineLotDetailsError) -> Self {
+        match err {
+            WineLotDetailsError::UnsupportedVersion => WineTokenError::UnsupportedDetailsVersion,
+            WineLotDetailsError::InvalidAbv => WineTokenError::InvalidAbv,
+            WineLotDetailsError::InvalidBlend => WineTokenError::InvalidBlend,
+        }
+    }
+}
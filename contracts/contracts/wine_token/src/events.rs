@@ -0,0 +1,304 @@
+//! Definition of the Events used in the WineToken contract
+//!
+//! Balance, allowance and admin changes publish the exact SEP-41 events
+//! (`mint`, `transfer`, `burn`, `clawback`, `approve` and `set_admin` as a
+//! symbol, followed by the addresses involved, with the amount or the new admin
+//! as data), so wallets and indexers read them like any other Stellar token.
+//! Every other action publishes a single typed event as its data.
+use common::models::{LotStatus, Role};
+use soroban_sdk::{contracttype, symbol_short, Address, BytesN, Env, String, Vec};
+use soroban_token_sdk::TokenUtils;
+
+use crate::models::MetadataChange;
+
+/// Publishes the SEP-41 `mint` event: topics `("mint", minter, to)`, data `amount`.
+pub(crate) fn emit_mint_event(e: &Env, minter: Address, to: Address, amount: i128) {
+    TokenUtils::new(e).events().mint(minter, to, amount);
+}
+
+/// Publishes the SEP-41 `transfer` event: topics `("transfer", from, to)`, data `amount`.
+pub(crate) fn emit_transfer_event(e: &Env, from: Address, to: Address, amount: i128) {
+    TokenUtils::new(e).events().transfer(from, to, amount);
+}
+
+/// Publishes the SEP-41 `burn` event: topics `("burn", from)`, data `amount`.
+pub(crate) fn emit_burn_event(e: &Env, from: Address, amount: i128) {
+    TokenUtils::new(e).events().burn(from, amount);
+}
+
+/// Publishes the SEP-41 `clawback` event: topics `("clawback", admin, from)`, data `amount`.
+pub(crate) fn emit_clawback_event(e: &Env, admin: Address, from: Address, amount: i128) {
+    TokenUtils::new(e).events().clawback(admin, from, amount);
+}
+
+/// Publishes the SEP-41 `approve` event: topics `("approve", from, spender)`,
+/// data `(amount, expiration_ledger)`.
+pub(crate) fn emit_approve_event(
+    e: &Env,
+    from: Address,
+    spender: Address,
+    amount: i128,
+    expiration_ledger: u32,
+) {
+    TokenUtils::new(e)
+        .events()
+        .approve(from, spender, amount, expiration_ledger);
+}
+
+/// Publishes the SEP-41 `set_admin` event: topics `("set_admin", previous_admin)`, data `new_admin`.
+pub(crate) fn emit_admin_changed_event(e: &Env, previous_admin: Address, new_admin: Address) {
+    TokenUtils::new(e).events().set_admin(previous_admin, new_admin);
+}
+
+// STATUS UPDATE EVENT
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StatusUpdateEvent {
+    pub index: u32, // position in the status history
+    pub status: LotStatus,
+    pub location: Option<String>,
+    pub handler: Address,
+    pub handler_key: Option<BytesN<32>>,
+    pub timestamp: u64,
+}
+
+/// Publishes a `StatusUpdateEvent` to the event stream.
+pub(crate) fn emit_status_update_event(e: &Env, event: StatusUpdateEvent) {
+    e.events()
+        .publish((symbol_short!("status"), event.handler.clone()), event);
+}
+
+// METADATA UPDATED EVENT
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MetadataUpdatedEvent {
+    pub editor: Address,
+    pub version: u32,
+    pub changes: Vec<MetadataChange>,
+}
+
+/// Publishes a `MetadataUpdatedEvent` to the event stream.
+pub(crate) fn emit_metadata_updated_event(
+    e: &Env,
+    editor: Address,
+    version: u32,
+    changes: Vec<MetadataChange>,
+) {
+    let event = MetadataUpdatedEvent {
+        editor: editor.clone(),
+        version,
+        changes,
+    };
+
+    e.events()
+        .publish((symbol_short!("metadata"), editor), event);
+}
+
+// DETAILS UPDATED EVENT
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DetailsUpdatedEvent {
+    pub editor: Address,
+    pub version: u32, // version of the `WineLotDetails` layout
+}
+
+/// Publishes a `DetailsUpdatedEvent` to the event stream.
+pub(crate) fn emit_details_updated_event(e: &Env, editor: Address, version: u32) {
+    let event = DetailsUpdatedEvent {
+        editor: editor.clone(),
+        version,
+    };
+
+    e.events()
+        .publish((symbol_short!("details"), editor), event);
+}
+
+// SNAPSHOT EVENT
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SnapshotEvent {
+    pub id: u32,
+    pub ledger: u32,
+}
+
+/// Publishes a `SnapshotEvent` to the event stream.
+pub(crate) fn emit_snapshot_event(e: &Env, id: u32) {
+    let event = SnapshotEvent {
+        id,
+        ledger: e.ledger().sequence(),
+    };
+
+    e.events().publish((symbol_short!("snapshot"),), event);
+}
+
+// ADMIN PROPOSED EVENT
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AdminProposedEvent {
+    pub admin: Address,
+    pub new_admin: Address,
+    pub unlock_ledger: u32,
+}
+
+/// Publishes an `AdminProposedEvent` to the event stream.
+pub(crate) fn emit_admin_proposed_event(
+    e: &Env,
+    admin: Address,
+    new_admin: Address,
+    unlock_ledger: u32,
+) {
+    let event = AdminProposedEvent {
+        admin: admin.clone(),
+        new_admin,
+        unlock_ledger,
+    };
+
+    e.events()
+        .publish((symbol_short!("proposed"), admin), event);
+}
+
+// ADMIN TRANSFER CANCELLED EVENT
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AdminCancelledEvent {
+    pub admin: Address,
+    pub new_admin: Address, // admin the cancelled transfer was proposed to
+}
+
+/// Publishes an `AdminCancelledEvent` to the event stream.
+pub(crate) fn emit_admin_cancelled_event(e: &Env, admin: Address, new_admin: Address) {
+    let event = AdminCancelledEvent {
+        admin: admin.clone(),
+        new_admin,
+    };
+
+    e.events()
+        .publish((symbol_short!("cancelled"), admin), event);
+}
+
+// VAULT LINKED EVENT
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VaultLinkedEvent {
+    pub vault: Address,
+}
+
+/// Publishes a `VaultLinkedEvent` to the event stream.
+pub(crate) fn emit_vault_linked_event(e: &Env, vault: Address) {
+    let event = VaultLinkedEvent { vault };
+
+    e.events().publish((symbol_short!("vault"),), event);
+}
+
+// PAUSE EVENT
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PauseEvent {
+    pub paused: bool,
+}
+
+/// Publishes a `PauseEvent` under `pause` or `unpause` to the event stream.
+pub(crate) fn emit_pause_event(e: &Env, paused: bool) {
+    let topic = if paused {
+        symbol_short!("pause")
+    } else {
+        symbol_short!("unpause")
+    };
+
+    e.events().publish((topic,), PauseEvent { paused });
+}
+
+// FREEZE EVENT
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FreezeEvent {
+    pub account: Address,
+    pub frozen: bool,
+}
+
+/// Publishes a `FreezeEvent` under `freeze` or `unfreeze` to the event stream.
+pub(crate) fn emit_freeze_event(e: &Env, account: Address, frozen: bool) {
+    let topic = if frozen {
+        symbol_short!("freeze")
+    } else {
+        symbol_short!("unfreeze")
+    };
+    let event = FreezeEvent {
+        account: account.clone(),
+        frozen,
+    };
+
+    e.events().publish((topic, account), event);
+}
+
+// ROLE CHANGED EVENT
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RoleChangedEvent {
+    pub role: Role,
+    pub account: Address,
+    pub granted: bool,
+}
+
+/// Publishes a `RoleChangedEvent` under `grant` or `revoke` to the event stream.
+pub(crate) fn emit_role_changed_event(e: &Env, role: Role, account: Address, granted: bool) {
+    let topic = if granted {
+        symbol_short!("grant")
+    } else {
+        symbol_short!("revoke")
+    };
+    let event = RoleChangedEvent {
+        role,
+        account: account.clone(),
+        granted,
+    };
+
+    e.events().publish((topic, account), event);
+}
+
+// UPGRADE EVENT
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UpgradeEvent {
+    pub upgrader: Address,
+    pub code_version: u32, // version recorded before the upgrade
+    pub new_wasm_hash: BytesN<32>,
+}
+
+/// Publishes an `UpgradeEvent` to the event stream.
+pub(crate) fn emit_upgrade_event(
+    e: &Env,
+    upgrader: Address,
+    code_version: u32,
+    new_wasm_hash: BytesN<32>,
+) {
+    let event = UpgradeEvent {
+        upgrader: upgrader.clone(),
+        code_version,
+        new_wasm_hash,
+    };
+
+    e.events()
+        .publish((symbol_short!("upgrade"), upgrader), event);
+}
+
+// MIGRATE EVENT
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MigrateEvent {
+    pub upgrader: Address,
+    pub from_version: u32,
+    pub to_version: u32,
+}
+
+/// Publishes a `MigrateEvent` to the event stream.
+pub(crate) fn emit_migrate_event(e: &Env, upgrader: Address, from_version: u32, to_version: u32) {
+    let event = MigrateEvent {
+        upgrader: upgrader.clone(),
+        from_version,
+        to_version,
+    };
+
+    e.events()
+        .publish((symbol_short!("migrate"), upgrader), event);
+}
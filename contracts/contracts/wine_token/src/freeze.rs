@@ -0,0 +1,81 @@
+use soroban_sdk::{panic_with_error, Address, Env};
+
+use crate::error::WineTokenError;
+use crate::storage::{DataKey, PERSISTENT_BUMP_AMOUNT, PERSISTENT_LIFETIME_THRESHOLD};
+
+pub fn is_frozen(e: &Env, account: &Address) -> bool {
+    let key = DataKey::Frozen(account.clone());
+    let frozen = e.storage().persistent().has(&key);
+    if frozen {
+        e.storage()
+            .persistent()
+            .extend_ttl(&key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+    }
+    frozen
+}
+
+/// Number of frozen accounts, or `None` on tokens deployed before it was counted.
+fn read_frozen_count(e: &Env) -> Option<u32> {
+    e.storage().instance().get(&DataKey::FrozenCount)
+}
+
+pub fn write_frozen_count(e: &Env, count: u32) {
+    e.storage().instance().set(&DataKey::FrozenCount, &count);
+}
+
+/// Whether any account may be frozen. While none is, transfers skip the
+/// per-account frozen reads.
+pub fn any_frozen(e: &Env) -> bool {
+    read_frozen_count(e) != Some(0)
+}
+
+pub fn write_frozen(e: &Env, account: &Address, frozen: bool) {
+    let key = DataKey::Frozen(account.clone());
+    if let Some(count) = read_frozen_count(e) {
+        match (e.storage().persistent().has(&key), frozen) {
+            (false, true) => write_frozen_count(e, count + 1),
+            (true, false) => write_frozen_count(e, count - 1),
+            _ => {}
+        }
+    }
+    if frozen {
+        e.storage().persistent().set(&key, &true);
+        e.storage()
+            .persistent()
+            .extend_ttl(&key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+    } else {
+        e.storage().persistent().remove(&key);
+    }
+}
+
+/// Transfers are paused for the whole lot while the flag is set. A recall
+/// sets it, and only the admin clears it again.
+pub fn is_paused(e: &Env) -> bool {
+    e.storage()
+        .instance()
+        .get(&DataKey::Paused)
+        .unwrap_or(false)
+}
+
+pub fn write_paused(e: &Env, paused: bool) {
+    if paused {
+        e.storage().instance().set(&DataKey::Paused, &true);
+    } else {
+        e.storage().instance().remove(&DataKey::Paused);
+    }
+}
+
+/// Panics if the lot is paused or any of `accounts` is frozen.
+pub fn check_not_restricted(e: &Env, accounts: &[&Address]) {
+    if is_paused(e) {
+        panic_with_error!(e, WineTokenError::TransfersPaused);
+    }
+    if !any_frozen(e) {
+        return;
+    }
+    for account in accounts {
+        if is_frozen(e, account) {
+            panic_with_error!(e, WineTokenError::AccountFrozen);
+        }
+    }
+}
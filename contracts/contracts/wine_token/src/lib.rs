@@ -1,20 +1,60 @@
 #![no_std]
 
-use soroban_sdk::{contract, contractimpl, token, Address, Env, String};
+use soroban_sdk::{contract, contractimpl, token, vec, Address, BytesN, Env, String, Vec};
 use soroban_sdk::token::TokenInterface;
 use soroban_token_sdk::metadata::TokenMetadata;
-use common::models::WineLotMetadata;
-
+use common::models::{LotStatus, PendingAdmin, StatusRecord, WineLotDetails, WineLotMetadata};
+
+mod access;
+mod allowance;
+mod compliance;
+mod error;
+pub mod events;
+mod freeze;
+mod metadata;
+pub mod models;
+mod snapshot;
+mod status;
 mod storage;
+mod test;
+mod total_supply;
+mod upgrade;
+use access::{AccessControl, AccessControlTrait};
+use allowance::{read_allowance, spend_allowance, write_allowance};
+use compliance::{check_compliance, check_transfer_compliance, read_compliance, write_compliance};
+use freeze::{
+    any_frozen, check_not_restricted, is_frozen, is_paused, write_frozen, write_frozen_count,
+    write_paused,
+};
+use metadata::{
+    archive_wine_lot_metadata, diff_wine_lot_metadata, read_archived_wine_lot_metadata,
+    read_metadata_version, write_metadata_version,
+};
+use snapshot::{
+    check_snapshot_id, next_snapshot_id, read_balance_at, read_snapshot_id, read_total_supply_at,
+};
+use status::apply_status;
+use total_supply::{
+    decrease_total_supply, increase_total_supply, max_supply_for, read_max_supply,
+    read_total_minted, read_total_supply,
+};
+use upgrade::{read_code_version, run_migrations, write_code_version, CODE_VERSION};
 use storage::{
     read_administrator, write_administrator,
+    read_pending_admin, write_pending_admin, remove_pending_admin,
+    read_vault, write_vault,
     read_wine_lot_metadata, write_wine_lot_metadata,
+    read_wine_lot_details, write_wine_lot_details,
     read_metadata, write_metadata,
     read_balance, spend_balance, receive_balance,
-    read_status, write_status,
-    INSTANCE_BUMP_AMOUNT, INSTANCE_LIFETIME_THRESHOLD,
+    read_status,
+    read_status_count, read_status_records,
+    INSTANCE_BUMP_AMOUNT, INSTANCE_LIFETIME_THRESHOLD, TX_MAX_FOOTPRINT_ENTRIES, TX_MAX_WRITE_ENTRIES,
 };
 
+pub use access::Role;
+pub use error::WineTokenError;
+
 #[contract]
 pub struct WineToken;
 
@@ -28,6 +68,7 @@ impl WineToken {
     /// * `name` - Token name (e.g., "Malbec Reserve 2024")
     /// * `symbol` - Token symbol (e.g., "MAL24")
     /// * `wine_lot_metadata` - Wine-specific metadata
+    /// * `compliance` - `ComplianceRegistry` restricting mints and transfers to allowlisted holders, if any
     pub fn __constructor(
         e: Env,
         admin: Address,
@@ -35,6 +76,7 @@ impl WineToken {
         name: String,
         symbol: String,
         wine_lot_metadata: WineLotMetadata,
+        compliance: Option<Address>,
     ) {
         if decimal > 18 {
             panic!("Decimal must not be greater than 18");
@@ -42,6 +84,11 @@ impl WineToken {
 
         write_administrator(&e, &admin);
         write_wine_lot_metadata(&e, &wine_lot_metadata);
+        if let Some(registry) = compliance {
+            write_compliance(&e, &registry);
+        }
+        write_frozen_count(&e, 0);
+        write_code_version(&e, CODE_VERSION);
         write_metadata(
             &e,
             TokenMetadata {
@@ -60,10 +107,125 @@ impl WineToken {
         read_wine_lot_metadata(&e)
     }
 
-    /// Mint new tokens (only admin/winery can call)
-    pub fn mint(e: Env, to: Address, amount: i128) {
-        let admin = read_administrator(&e);
-        admin.require_auth();
+    /// Update the wine lot metadata (only admin or a `MetadataEditor` can call)
+    ///
+    /// The replaced metadata is archived under its version number and the
+    /// version counter is bumped. Emits the list of changed fields.
+    ///
+    /// # Arguments
+    /// * `editor` - Address authorizing the update
+    /// * `wine_lot_metadata` - The full new metadata
+    ///
+    /// # Returns
+    /// * The new metadata version
+    ///
+    /// # Errors
+    /// * `WineTokenError::Unauthorized` - If `editor` is neither the admin nor a `MetadataEditor`
+    /// * `WineTokenError::ImmutableMetadataField` - If `lot_id` or `vintage` would change
+    /// * `WineTokenError::MetadataUnchanged` - If no field changes
+    /// * `WineTokenError::BottleCountBelowMinted` - If the new bottle count no longer covers the minted supply
+    pub fn update_wine_lot_metadata(
+        e: Env,
+        editor: Address,
+        wine_lot_metadata: WineLotMetadata,
+    ) -> Result<u32, WineTokenError> {
+        AccessControl::new(&e).require_role(Role::MetadataEditor, &editor)?;
+
+        e.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+
+        let current = read_wine_lot_metadata(&e);
+        let changes = diff_wine_lot_metadata(&e, &current, &wine_lot_metadata)?;
+
+        if max_supply_for(&e, wine_lot_metadata.bottle_count) < read_total_minted(&e) {
+            return Err(WineTokenError::BottleCountBelowMinted);
+        }
+
+        let version = read_metadata_version(&e);
+        archive_wine_lot_metadata(&e, version, &current);
+        let new_version = version.checked_add(1).unwrap();
+        write_metadata_version(&e, new_version);
+        write_wine_lot_metadata(&e, &wine_lot_metadata);
+
+        events::emit_metadata_updated_event(&e, editor, new_version, changes);
+        Ok(new_version)
+    }
+
+    /// Get the current wine lot metadata version
+    pub fn metadata_version(e: Env) -> u32 {
+        e.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        read_metadata_version(&e)
+    }
+
+    /// Get the wine lot metadata as it was at `version`
+    pub fn get_wine_lot_metadata_version(e: Env, version: u32) -> Option<WineLotMetadata> {
+        e.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        if version == read_metadata_version(&e) {
+            return Some(read_wine_lot_metadata(&e));
+        }
+        read_archived_wine_lot_metadata(&e, version)
+    }
+
+    /// Set the extended wine lot details (only admin or a `MetadataEditor` can call)
+    ///
+    /// # Arguments
+    /// * `editor` - Address authorizing the update
+    /// * `details` - Appellation, ABV, blend, barrel regime and document hashes
+    ///
+    /// # Errors
+    /// * `WineTokenError::Unauthorized` - If `editor` is neither the admin nor a `MetadataEditor`
+    /// * `WineTokenError::UnsupportedDetailsVersion` - If `details.version` is not the current layout
+    /// * `WineTokenError::InvalidAbv` - If the ABV is zero or above 25%
+    /// * `WineTokenError::InvalidBlend` - If the blend shares do not add up to 10000 bps
+    pub fn set_wine_lot_details(
+        e: Env,
+        editor: Address,
+        details: WineLotDetails,
+    ) -> Result<(), WineTokenError> {
+        AccessControl::new(&e).require_role(Role::MetadataEditor, &editor)?;
+
+        e.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+
+        details.validate()?;
+        write_wine_lot_details(&e, &details);
+        events::emit_details_updated_event(&e, editor, details.version);
+        Ok(())
+    }
+
+    /// Get the extended wine lot details, if they have been set
+    pub fn get_wine_lot_details(e: Env) -> Option<WineLotDetails> {
+        e.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        read_wine_lot_details(&e)
+    }
+
+    /// Mint new tokens (only admin or a `Minter` can call)
+    ///
+    /// The lot can never mint more than `bottle_count * 10^decimal` tokens in total.
+    /// Burned tokens do not free up room under the cap, since they stand for bottles
+    /// that have left the lot.
+    ///
+    /// # Arguments
+    /// * `minter` - Address authorizing the mint
+    /// * `to` - Recipient of the new tokens
+    /// * `amount` - Amount to mint
+    ///
+    /// # Errors
+    /// * `WineTokenError::Unauthorized` - If `minter` is neither the admin nor a `Minter`
+    /// * `WineTokenError::SupplyCapExceeded` - If the mint would exceed the lot's bottle count
+    /// * `WineTokenError::TransfersPaused` - If the lot is paused, e.g. after a recall
+    /// * `WineTokenError::AccountFrozen` - If `to` is frozen
+    /// * `ComplianceError::NotAllowlisted` / `ComplianceError::CountryBlocked` - If the compliance registry rejects `to`
+    pub fn mint(e: Env, minter: Address, to: Address, amount: i128) -> Result<(), WineTokenError> {
+        AccessControl::new(&e).require_role(Role::Minter, &minter)?;
 
         if amount < 0 {
             panic!("Amount must be non-negative");
@@ -73,14 +235,208 @@ impl WineToken {
             .instance()
             .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
 
+        let total_minted = read_total_minted(&e)
+            .checked_add(amount)
+            .ok_or(WineTokenError::SupplyCapExceeded)?;
+        if total_minted > read_max_supply(&e) {
+            return Err(WineTokenError::SupplyCapExceeded);
+        }
+
+        check_not_restricted(&e, &[&to]);
+        check_compliance(&e, vec![&e, to.clone()]);
         receive_balance(&e, to.clone(), amount);
-        
-        e.events().publish(("mint", "to"), to.clone());
-        e.events().publish(("mint", "amount"), amount);
+        increase_total_supply(&e, amount);
+
+        events::emit_mint_event(&e, minter, to, amount);
+        Ok(())
+    }
+
+    /// Mint to several recipients at once (only admin or a `Minter` can call)
+    ///
+    /// The batch is all-or-nothing: the supply cap is checked against the batch
+    /// total before any balance changes, and one set of mint events is emitted
+    /// per recipient.
+    ///
+    /// # Arguments
+    /// * `minter` - Address authorizing the mint
+    /// * `recipients` - Recipient and amount pairs, at most `max_batch_size`
+    ///
+    /// # Errors
+    /// * `WineTokenError::Unauthorized` - If `minter` is neither the admin nor a `Minter`
+    /// * `WineTokenError::EmptyBatch` / `WineTokenError::BatchTooLarge` - If the batch size is out of bounds
+    /// * `WineTokenError::NegativeAmount` - If any amount is negative
+    /// * `WineTokenError::BatchTotalOverflow` - If the amounts overflow when added up
+    /// * `WineTokenError::SupplyCapExceeded` - If the batch would exceed the lot's bottle count
+    /// * `WineTokenError::TransfersPaused` - If the lot is paused, e.g. after a recall
+    /// * `WineTokenError::AccountFrozen` - If a recipient is frozen
+    /// * `ComplianceError::NotAllowlisted` / `ComplianceError::CountryBlocked` - If the compliance registry rejects a recipient
+    pub fn mint_batch(
+        e: Env,
+        minter: Address,
+        recipients: Vec<(Address, i128)>,
+    ) -> Result<(), WineTokenError> {
+        AccessControl::new(&e).require_role(Role::Minter, &minter)?;
+        let batch_total = check_batch(&e, &recipients)?;
+
+        e.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+
+        let total_minted = read_total_minted(&e)
+            .checked_add(batch_total)
+            .ok_or(WineTokenError::SupplyCapExceeded)?;
+        if total_minted > read_max_supply(&e) {
+            return Err(WineTokenError::SupplyCapExceeded);
+        }
+
+        let mut accounts = Vec::new(&e);
+        for (to, _) in recipients.iter() {
+            check_not_restricted(&e, &[&to]);
+            accounts.push_back(to);
+        }
+        check_compliance(&e, accounts);
+        for (to, amount) in recipients.iter() {
+            receive_balance(&e, to.clone(), amount);
+            events::emit_mint_event(&e, minter.clone(), to, amount);
+        }
+        increase_total_supply(&e, batch_total);
+        Ok(())
+    }
+
+    /// Transfer from one holder to several recipients at once (holder must sign)
+    ///
+    /// The batch is all-or-nothing: the sender's balance and every recipient's
+    /// freeze and compliance state are checked before any balance changes.
+    ///
+    /// # Arguments
+    /// * `from` - Sender of the tokens
+    /// * `recipients` - Recipient and amount pairs, at most `max_batch_size`
+    ///
+    /// # Errors
+    /// * `WineTokenError::EmptyBatch` / `WineTokenError::BatchTooLarge` - If the batch size is out of bounds
+    /// * `WineTokenError::NegativeAmount` - If any amount is negative
+    /// * `WineTokenError::BatchTotalOverflow` - If the amounts overflow when added up
+    /// * `WineTokenError::TransfersPaused` - If the lot is paused, e.g. after a recall
+    /// * `WineTokenError::AccountFrozen` - If `from` or a recipient is frozen
+    /// * `ComplianceError::NotAllowlisted` / `ComplianceError::CountryBlocked` - If the compliance registry rejects `from` or a recipient
+    /// * `WineTokenError::InsufficientBalance` - If `from` holds less than the batch total
+    pub fn transfer_batch(
+        e: Env,
+        from: Address,
+        recipients: Vec<(Address, i128)>,
+    ) -> Result<(), WineTokenError> {
+        from.require_auth();
+        let batch_total = check_batch(&e, &recipients)?;
+
+        e.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+
+        check_not_restricted(&e, &[&from]);
+        let mut accounts = vec![&e, from.clone()];
+        for (to, _) in recipients.iter() {
+            check_not_restricted(&e, &[&to]);
+            accounts.push_back(to);
+        }
+        check_compliance(&e, accounts);
+        spend_balance(&e, from.clone(), batch_total);
+
+        for (to, amount) in recipients.iter() {
+            receive_balance(&e, to.clone(), amount);
+            events::emit_transfer_event(&e, from.clone(), to, amount);
+        }
+        Ok(())
+    }
+
+    /// Get the largest batch `mint_batch` and `transfer_batch` currently accept
+    ///
+    /// The cap depends on the checks each recipient goes through, so it shrinks
+    /// while accounts are frozen, with a compliance registry and once a snapshot
+    /// has been taken. Clients should split larger mints into batches this size.
+    pub fn max_batch_size(e: Env) -> u32 {
+        e.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        max_batch_size(&e)
+    }
+
+    /// Get the circulating supply (minted minus burned)
+    pub fn total_supply(e: Env) -> i128 {
+        e.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        read_total_supply(&e)
+    }
+
+    /// Take a snapshot of all balances and the total supply (only admin can call)
+    ///
+    /// Nothing is copied when the snapshot is taken; balances are checkpointed
+    /// the first time they change afterwards.
+    ///
+    /// # Returns
+    /// * Id of the new snapshot, starting at 1
+    pub fn snapshot(e: Env) -> u32 {
+        let admin = read_administrator(&e);
+        admin.require_auth();
+
+        e.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+
+        let id = next_snapshot_id(&e);
+        events::emit_snapshot_event(&e, id);
+        id
+    }
+
+    /// Get the id of the latest snapshot, 0 if none was taken
+    pub fn current_snapshot_id(e: Env) -> u32 {
+        e.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        read_snapshot_id(&e)
+    }
+
+    /// Get a holder's balance as of a snapshot
+    ///
+    /// # Errors
+    /// * `WineTokenError::SnapshotNotFound` - If no snapshot with this id was taken
+    pub fn balance_of_at(e: Env, holder: Address, snapshot_id: u32) -> Result<i128, WineTokenError> {
+        e.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        check_snapshot_id(&e, snapshot_id)?;
+
+        Ok(read_balance_at(&e, &holder, snapshot_id)
+            .unwrap_or_else(|| read_balance(&e, holder)))
+    }
+
+    /// Get the circulating supply as of a snapshot
+    ///
+    /// # Errors
+    /// * `WineTokenError::SnapshotNotFound` - If no snapshot with this id was taken
+    pub fn total_supply_at(e: Env, snapshot_id: u32) -> Result<i128, WineTokenError> {
+        e.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        check_snapshot_id(&e, snapshot_id)?;
+
+        Ok(read_total_supply_at(&e, snapshot_id).unwrap_or_else(|| read_total_supply(&e)))
+    }
+
+    /// Get the maximum amount that can ever be minted (`bottle_count * 10^decimal`)
+    pub fn max_supply(e: Env) -> i128 {
+        e.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        read_max_supply(&e)
     }
 
-    /// Update admin (only current admin can call)
-    pub fn set_admin(e: Env, new_admin: Address) {
+    /// Propose a new admin (only current admin can call)
+    ///
+    /// The transfer only completes once `new_admin` calls `accept_admin`, and not
+    /// before `delay_ledgers` ledgers have passed. A new proposal replaces any
+    /// pending one.
+    pub fn propose_admin(e: Env, new_admin: Address, delay_ledgers: u32) {
         let admin = read_administrator(&e);
         admin.require_auth();
 
@@ -88,9 +444,59 @@ impl WineToken {
             .instance()
             .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
 
-        write_administrator(&e, &new_admin);
-        e.events()
-            .publish(("set_admin", "new_admin"), new_admin);
+        let unlock_ledger = e.ledger().sequence().saturating_add(delay_ledgers);
+        write_pending_admin(&e, &PendingAdmin { new_admin: new_admin.clone(), unlock_ledger });
+        events::emit_admin_proposed_event(&e, admin, new_admin, unlock_ledger);
+    }
+
+    /// Accept a pending admin transfer (only the proposed admin can call)
+    ///
+    /// # Errors
+    /// * `WineTokenError::NoPendingAdmin` - If no transfer has been proposed
+    /// * `WineTokenError::AdminTransferLocked` - If the timelock has not expired yet
+    pub fn accept_admin(e: Env) -> Result<(), WineTokenError> {
+        e.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+
+        let pending = read_pending_admin(&e).ok_or(WineTokenError::NoPendingAdmin)?;
+        pending.new_admin.require_auth();
+
+        if e.ledger().sequence() < pending.unlock_ledger {
+            return Err(WineTokenError::AdminTransferLocked);
+        }
+
+        let previous_admin = read_administrator(&e);
+        write_administrator(&e, &pending.new_admin);
+        remove_pending_admin(&e);
+        events::emit_admin_changed_event(&e, previous_admin, pending.new_admin);
+        Ok(())
+    }
+
+    /// Cancel a pending admin transfer (only current admin can call)
+    ///
+    /// # Errors
+    /// * `WineTokenError::NoPendingAdmin` - If no transfer has been proposed
+    pub fn cancel_admin_transfer(e: Env) -> Result<(), WineTokenError> {
+        let admin = read_administrator(&e);
+        admin.require_auth();
+
+        e.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+
+        let pending = read_pending_admin(&e).ok_or(WineTokenError::NoPendingAdmin)?;
+        remove_pending_admin(&e);
+        events::emit_admin_cancelled_event(&e, admin, pending.new_admin);
+        Ok(())
+    }
+
+    /// Get the pending admin transfer, if any
+    pub fn pending_admin(e: Env) -> Option<PendingAdmin> {
+        e.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        read_pending_admin(&e)
     }
 
     /// Get current admin
@@ -101,61 +507,396 @@ impl WineToken {
         read_administrator(&e)
     }
 
-    /// Update wine lot status (only admin/winery can call)
+    /// Link the investment vault built around this lot (only admin can call)
+    ///
+    /// Linking again replaces the previous vault.
+    pub fn set_vault(e: Env, vault: Address) {
+        let admin = read_administrator(&e);
+        admin.require_auth();
+
+        e.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+
+        write_vault(&e, &vault);
+        events::emit_vault_linked_event(&e, vault);
+    }
+
+    /// Get the linked investment vault, if any
+    pub fn vault(e: Env) -> Option<Address> {
+        e.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        read_vault(&e)
+    }
+
+    /// Freeze an account so it can no longer send, receive or burn tokens (only admin can call)
+    pub fn freeze(e: Env, account: Address) {
+        let admin = read_administrator(&e);
+        admin.require_auth();
+
+        e.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+
+        write_frozen(&e, &account, true);
+        events::emit_freeze_event(&e, account, true);
+    }
+
+    /// Unfreeze a previously frozen account (only admin can call)
+    pub fn unfreeze(e: Env, account: Address) {
+        let admin = read_administrator(&e);
+        admin.require_auth();
+
+        e.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+
+        write_frozen(&e, &account, false);
+        events::emit_freeze_event(&e, account, false);
+    }
+
+    /// Check whether an account is frozen
+    pub fn is_frozen(e: Env, account: Address) -> bool {
+        e.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        is_frozen(&e, &account)
+    }
+
+    /// Pause all mints, transfers and burns of the lot (only admin can call)
+    ///
+    /// Recalling the lot pauses it as well. Clawbacks keep working while paused.
+    pub fn pause(e: Env) {
+        let admin = read_administrator(&e);
+        admin.require_auth();
+
+        e.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+
+        write_paused(&e, true);
+        events::emit_pause_event(&e, true);
+    }
+
+    /// Resume mints, transfers and burns after a pause or a recall (only admin can call)
+    ///
+    /// A recalled lot keeps its `Recalled` status; only trading resumes, e.g. to
+    /// move clawed-back tokens once the recall has been handled.
+    pub fn unpause(e: Env) {
+        let admin = read_administrator(&e);
+        admin.require_auth();
+
+        e.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+
+        write_paused(&e, false);
+        events::emit_pause_event(&e, false);
+    }
+
+    /// Check whether mints, transfers and burns are paused
+    pub fn is_paused(e: Env) -> bool {
+        e.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        is_paused(&e)
+    }
+
+    /// Get the compliance registry restricting mints and transfers, if any
+    pub fn compliance(e: Env) -> Option<Address> {
+        e.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        read_compliance(&e)
+    }
+
+    /// Claw back tokens from an account, e.g. during a product recall (only admin can call)
+    ///
+    /// The tokens are destroyed and removed from the circulating supply. Clawback
+    /// works on frozen accounts and while transfers are paused.
+    pub fn clawback(e: Env, from: Address, amount: i128) {
+        let admin = read_administrator(&e);
+        admin.require_auth();
+
+        if amount < 0 {
+            panic!("Amount must be non-negative");
+        }
+
+        e.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+
+        spend_balance(&e, from.clone(), amount);
+        decrease_total_supply(&e, amount);
+
+        events::emit_clawback_event(&e, admin, from, amount);
+    }
+
+    /// Replace the token's code (only admin or an `Upgrader` can call)
+    ///
+    /// Wineries opt into factory-driven upgrades by granting the factory the
+    /// `Upgrader` role. The new code takes effect once this call returns; call
+    /// `migrate` afterwards so it can rewrite stored data.
     ///
     /// # Arguments
-    /// * `status` - New status string (e.g., "harvested", "fermented", "aged", "bottled", "shipped", "available", "sold_out", "recalled")
+    /// * `upgrader` - Address authorizing the upgrade
+    /// * `new_wasm_hash` - Hash of the uploaded WASM to switch to
+    ///
+    /// # Errors
+    /// * `WineTokenError::Unauthorized` - If `upgrader` is neither the admin nor an `Upgrader`
+    pub fn upgrade(e: Env, upgrader: Address, new_wasm_hash: BytesN<32>) -> Result<(), WineTokenError> {
+        AccessControl::new(&e).require_role(Role::Upgrader, &upgrader)?;
+
+        e.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+
+        e.deployer()
+            .update_current_contract_wasm(new_wasm_hash.clone());
+        events::emit_upgrade_event(&e, upgrader, read_code_version(&e), new_wasm_hash);
+        Ok(())
+    }
+
+    /// Run the data migrations of the current code (only admin or an `Upgrader` can call)
+    ///
+    /// Does nothing if the stored data is already at the current code version.
+    ///
+    /// # Returns
+    /// * The code version after migrating
+    ///
+    /// # Errors
+    /// * `WineTokenError::Unauthorized` - If `upgrader` is neither the admin nor an `Upgrader`
+    /// * `WineTokenError::UnsupportedMigration` - If no migration path exists from the stored version
+    pub fn migrate(e: Env, upgrader: Address) -> Result<u32, WineTokenError> {
+        AccessControl::new(&e).require_role(Role::Upgrader, &upgrader)?;
+
+        e.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+
+        let from = run_migrations(&e);
+        if from != CODE_VERSION {
+            events::emit_migrate_event(&e, upgrader, from, CODE_VERSION);
+        }
+        Ok(CODE_VERSION)
+    }
+
+    /// Get the code version of the token's stored data
+    pub fn code_version(e: Env) -> u32 {
+        e.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        read_code_version(&e)
+    }
+
+    /// Grant a role to an account (only admin can call)
+    ///
+    /// # Errors
+    /// * `WineTokenError::RoleNotGrantable` - If `role` is `Admin`; use `propose_admin` instead
+    pub fn grant_role(e: Env, role: Role, account: Address) -> Result<(), WineTokenError> {
+        let admin = read_administrator(&e);
+        admin.require_auth();
+
+        e.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+
+        AccessControl::new(&e).grant_role(role, &account)?;
+        events::emit_role_changed_event(&e, role, account, true);
+        Ok(())
+    }
+
+    /// Revoke a role from an account (only admin can call)
+    ///
+    /// # Errors
+    /// * `WineTokenError::RoleNotGrantable` - If `role` is `Admin`
+    /// * `WineTokenError::RoleNotFound` - If `account` does not hold `role`
+    pub fn revoke_role(e: Env, role: Role, account: Address) -> Result<(), WineTokenError> {
+        let admin = read_administrator(&e);
+        admin.require_auth();
+
+        e.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+
+        AccessControl::new(&e).revoke_role(role, &account)?;
+        events::emit_role_changed_event(&e, role, account, false);
+        Ok(())
+    }
+
+    /// Check whether an account holds a role (the admin holds every role)
+    pub fn has_role(e: Env, role: Role, account: Address) -> bool {
+        e.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        AccessControl::new(&e).has_role(role, &account)
+    }
+
+    /// Update wine lot status (only admin or a `StatusUpdater` can call)
+    ///
+    /// The lot follows a fixed lifecycle (harvested → fermented → aged → bottled →
+    /// shipped → available → sold_out) and can be recalled from any state. The
+    /// previous status is always read from storage.
+    ///
+    /// # Arguments
+    /// * `updater` - Address recording the change, stored as the handler
+    /// * `status` - New lifecycle status
     /// * `location` - Optional location string
-    /// * `previous_status` - Optional previous status for audit trail
+    ///
+    /// # Errors
+    /// * `WineTokenError::Unauthorized` - If `updater` is neither the admin nor a `StatusUpdater`
+    /// * `WineTokenError::InvalidStatusTransition` - If the lot cannot move from its current status to `status`
     pub fn set_status(
         e: Env,
-        status: String,
+        updater: Address,
+        status: LotStatus,
         location: Option<String>,
-        previous_status: Option<String>,
-    ) {
-        let admin = read_administrator(&e);
-        admin.require_auth();
+    ) -> Result<(), WineTokenError> {
+        AccessControl::new(&e).require_role(Role::StatusUpdater, &updater)?;
 
         e.storage()
             .instance()
             .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
 
-        let old_status = read_status(&e);
-        write_status(&e, &status);
+        apply_status(&e, status, location, updater, None)
+    }
 
-        // Emit status change event
-        e.events().publish(("status_update", "status"), status.clone());
-        if let Some(loc) = location {
-            e.events().publish(("status_update", "location"), loc);
-        }
-        if let Some(prev) = previous_status {
-            e.events().publish(("status_update", "previous_status"), prev);
-        } else if let Some(prev) = old_status {
-            e.events().publish(("status_update", "previous_status"), prev);
-        }
-        e.events().publish(("status_update", "timestamp"), e.ledger().timestamp());
+    /// Record a status update relayed from an off-chain handler key (only admin or a `StatusUpdater` can call)
+    ///
+    /// Called by a `StatusRelay` contract once it has checked the handler's
+    /// signature and nonce. The record is stored with `relay` as the handler and
+    /// `handler_key` as the key that signed the update.
+    ///
+    /// # Arguments
+    /// * `relay` - Relay contract posting the update
+    /// * `status` - New lifecycle status
+    /// * `location` - Optional location string
+    /// * `handler_key` - ed25519 key that signed the update
+    ///
+    /// # Errors
+    /// * `WineTokenError::Unauthorized` - If `relay` is neither the admin nor a `StatusUpdater`
+    /// * `WineTokenError::InvalidStatusTransition` - If the lot cannot move from its current status to `status`
+    pub fn set_relayed_status(
+        e: Env,
+        relay: Address,
+        status: LotStatus,
+        location: Option<String>,
+        handler_key: BytesN<32>,
+    ) -> Result<(), WineTokenError> {
+        AccessControl::new(&e).require_role(Role::StatusUpdater, &relay)?;
+
+        e.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+
+        apply_status(&e, status, location, relay, Some(handler_key))
     }
 
     /// Get current wine lot status
-    pub fn get_status(e: Env) -> Option<String> {
+    pub fn get_status(e: Env) -> Option<LotStatus> {
         e.storage()
             .instance()
             .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
         read_status(&e)
     }
+
+    /// Get a page of the wine lot status history, oldest first
+    ///
+    /// # Arguments
+    /// * `start` - Index of the first record to return
+    /// * `limit` - Maximum number of records to return
+    pub fn get_status_history(e: Env, start: u32, limit: u32) -> Vec<StatusRecord> {
+        e.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        read_status_records(&e, start, limit)
+    }
+
+    /// Get the number of recorded status changes
+    pub fn status_count(e: Env) -> u32 {
+        e.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+        read_status_count(&e)
+    }
+}
+
+/// Largest batch whose ledger footprint fits a transaction.
+///
+/// Every recipient adds its balance entry, plus its frozen entry while any
+/// account is frozen, its allowlist and country rule entries with a compliance
+/// registry, and its checkpoint count and checkpoint entries once a snapshot
+/// has been taken. Only the balance and, with snapshots, the checkpoint entries
+/// are written, which caps plain batches by the write limit instead. The fixed
+/// part covers the token instance and code, the sender or supply entries, and
+/// the registry's instance and code. The budget tests check that each cap fits
+/// and that one more recipient would not.
+fn max_batch_size(e: &Env) -> u32 {
+    let (mut fixed, mut per_recipient) = (6, 1);
+    let (mut fixed_writes, mut writes_per_recipient) = (2, 1);
+    if any_frozen(e) {
+        fixed += 1;
+        per_recipient += 1;
+    }
+    if read_compliance(e).is_some() {
+        fixed += 4;
+        per_recipient += 2;
+    }
+    if read_snapshot_id(e) > 0 {
+        fixed += 3;
+        per_recipient += 3;
+        fixed_writes += 2;
+        writes_per_recipient += 2;
+    }
+    let by_entries = (TX_MAX_FOOTPRINT_ENTRIES - fixed) / per_recipient;
+    let by_writes = (TX_MAX_WRITE_ENTRIES - fixed_writes) / writes_per_recipient;
+    by_entries.min(by_writes)
+}
+
+/// Checks the batch size and amounts, returning the batch total.
+fn check_batch(e: &Env, recipients: &Vec<(Address, i128)>) -> Result<i128, WineTokenError> {
+    if recipients.is_empty() {
+        return Err(WineTokenError::EmptyBatch);
+    }
+    if recipients.len() > max_batch_size(e) {
+        return Err(WineTokenError::BatchTooLarge);
+    }
+
+    let mut total: i128 = 0;
+    for (_, amount) in recipients.iter() {
+        if amount < 0 {
+            return Err(WineTokenError::NegativeAmount);
+        }
+        total = total
+            .checked_add(amount)
+            .ok_or(WineTokenError::BatchTotalOverflow)?;
+    }
+    Ok(total)
 }
 
 #[contractimpl]
 impl token::TokenInterface for WineToken {
-    fn allowance(e: Env, _from: Address, _spender: Address) -> i128 {
+    fn allowance(e: Env, from: Address, spender: Address) -> i128 {
         e.storage()
             .instance()
             .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
-        0 // Simple implementation: no allowances
+        read_allowance(&e, from, spender).amount
     }
 
-    fn approve(_e: Env, _from: Address, _spender: Address, _amount: i128, _expiration_ledger: u32) {
-        panic!("Approve not supported in simple wine token");
+    fn approve(e: Env, from: Address, spender: Address, amount: i128, expiration_ledger: u32) {
+        from.require_auth();
+
+        if amount < 0 {
+            panic!("Amount must be non-negative");
+        }
+
+        e.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+
+        write_allowance(&e, from.clone(), spender.clone(), amount, expiration_ledger);
+        events::emit_approve_event(&e, from, spender, amount, expiration_ledger);
     }
 
     fn balance(e: Env, id: Address) -> i128 {
@@ -176,19 +917,32 @@ impl token::TokenInterface for WineToken {
             .instance()
             .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
 
+        check_not_restricted(&e, &[&from, &to]);
+        check_transfer_compliance(&e, &from, &to);
         spend_balance(&e, from.clone(), amount);
         receive_balance(&e, to.clone(), amount);
-        
-        e.events().publish(("transfer", "from"), from);
-        e.events().publish(("transfer", "to"), to);
-        e.events().publish(("transfer", "amount"), amount);
+
+        events::emit_transfer_event(&e, from, to, amount);
     }
 
-    fn transfer_from(e: Env, _spender: Address, _from: Address, _to: Address, _amount: i128) {
+    fn transfer_from(e: Env, spender: Address, from: Address, to: Address, amount: i128) {
+        spender.require_auth();
+
+        if amount < 0 {
+            panic!("Amount must be non-negative");
+        }
+
         e.storage()
             .instance()
             .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
-        panic!("Transfer_from not supported in simple wine token");
+
+        check_not_restricted(&e, &[&spender, &from, &to]);
+        check_transfer_compliance(&e, &from, &to);
+        spend_allowance(&e, from.clone(), spender, amount);
+        spend_balance(&e, from.clone(), amount);
+        receive_balance(&e, to.clone(), amount);
+
+        events::emit_transfer_event(&e, from, to, amount);
     }
 
     fn burn(e: Env, from: Address, amount: i128) {
@@ -202,14 +956,30 @@ impl token::TokenInterface for WineToken {
             .instance()
             .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
 
+        check_not_restricted(&e, &[&from]);
         spend_balance(&e, from.clone(), amount);
-        
-        e.events().publish(("burn", "from"), from);
-        e.events().publish(("burn", "amount"), amount);
+        decrease_total_supply(&e, amount);
+
+        events::emit_burn_event(&e, from, amount);
     }
 
-    fn burn_from(_e: Env, _spender: Address, _from: Address, _amount: i128) {
-        panic!("Burn_from not supported in simple wine token");
+    fn burn_from(e: Env, spender: Address, from: Address, amount: i128) {
+        spender.require_auth();
+
+        if amount < 0 {
+            panic!("Amount must be non-negative");
+        }
+
+        e.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+
+        check_not_restricted(&e, &[&spender, &from]);
+        spend_allowance(&e, from.clone(), spender, amount);
+        spend_balance(&e, from.clone(), amount);
+        decrease_total_supply(&e, amount);
+
+        events::emit_burn_event(&e, from, amount);
     }
 
     fn decimals(e: Env) -> u32 {
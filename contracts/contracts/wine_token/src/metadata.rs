@@ -0,0 +1,78 @@
+use common::models::WineLotMetadata;
+use soroban_sdk::{Env, Vec};
+
+use crate::error::WineTokenError;
+use crate::models::MetadataChange;
+use crate::storage::{DataKey, PERSISTENT_BUMP_AMOUNT, PERSISTENT_LIFETIME_THRESHOLD};
+
+/// Version of the current wine lot metadata, starting at 1 on construction.
+pub fn read_metadata_version(e: &Env) -> u32 {
+    let key = DataKey::MetadataVersion;
+    e.storage().instance().get(&key).unwrap_or(1)
+}
+
+pub fn write_metadata_version(e: &Env, version: u32) {
+    let key = DataKey::MetadataVersion;
+    e.storage().instance().set(&key, &version);
+}
+
+/// Stores a superseded metadata version so the full history stays auditable.
+pub fn archive_wine_lot_metadata(e: &Env, version: u32, metadata: &WineLotMetadata) {
+    let key = DataKey::MetadataHistory(version);
+    e.storage().persistent().set(&key, metadata);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+}
+
+pub fn read_archived_wine_lot_metadata(e: &Env, version: u32) -> Option<WineLotMetadata> {
+    let key = DataKey::MetadataHistory(version);
+    let metadata = e.storage().persistent().get::<DataKey, WineLotMetadata>(&key);
+    if metadata.is_some() {
+        e.storage()
+            .persistent()
+            .extend_ttl(&key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+    }
+    metadata
+}
+
+/// Lists the fields that differ between two metadata versions.
+///
+/// `lot_id` and `vintage` identify the lot and can never change.
+pub fn diff_wine_lot_metadata(
+    e: &Env,
+    old: &WineLotMetadata,
+    new: &WineLotMetadata,
+) -> Result<Vec<MetadataChange>, WineTokenError> {
+    if old.lot_id != new.lot_id || old.vintage != new.vintage {
+        return Err(WineTokenError::ImmutableMetadataField);
+    }
+
+    let mut changes = Vec::new(e);
+    if old.winery_name != new.winery_name {
+        changes.push_back(MetadataChange::WineryName(old.winery_name.clone(), new.winery_name.clone()));
+    }
+    if old.region != new.region {
+        changes.push_back(MetadataChange::Region(old.region.clone(), new.region.clone()));
+    }
+    if old.country != new.country {
+        changes.push_back(MetadataChange::Country(old.country.clone(), new.country.clone()));
+    }
+    if old.varietal != new.varietal {
+        changes.push_back(MetadataChange::Varietal(old.varietal.clone(), new.varietal.clone()));
+    }
+    if old.bottle_count != new.bottle_count {
+        changes.push_back(MetadataChange::BottleCount(old.bottle_count, new.bottle_count));
+    }
+    if old.description != new.description {
+        changes.push_back(MetadataChange::Description(old.description.clone(), new.description.clone()));
+    }
+    if old.token_code != new.token_code {
+        changes.push_back(MetadataChange::TokenCode(old.token_code.clone(), new.token_code.clone()));
+    }
+
+    if changes.is_empty() {
+        return Err(WineTokenError::MetadataUnchanged);
+    }
+    Ok(changes)
+}
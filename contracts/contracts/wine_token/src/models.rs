@@ -0,0 +1,14 @@
+use soroban_sdk::{contracttype, String};
+
+// Single field change in a wine lot metadata update, as (previous, new)
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum MetadataChange {
+    WineryName(String, String),
+    Region(String, String),
+    Country(String, String),
+    Varietal(String, String),
+    BottleCount(u32, u32),
+    Description(Option<String>, Option<String>),
+    TokenCode(String, String),
+}
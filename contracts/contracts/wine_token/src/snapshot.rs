@@ -0,0 +1,126 @@
+use soroban_sdk::{Address, Env, IntoVal, TryFromVal, Val};
+
+use crate::error::WineTokenError;
+use crate::storage::{DataKey, PERSISTENT_BUMP_AMOUNT, PERSISTENT_LIFETIME_THRESHOLD};
+
+// Snapshots are stored lazily: taking one only bumps the counter, and a
+// balance (or the supply) is checkpointed the first time it changes after a
+// snapshot. A checkpoint `(id, value)` holds the value as of snapshot `id`.
+// Balances that never change are served from the live value.
+//
+// Each series of checkpoints is a count plus one entry per checkpoint, so a
+// transfer touches the same few entries however many snapshots have been taken.
+
+#[derive(Clone)]
+enum Series {
+    Balance(Address),
+    Supply,
+}
+
+impl Series {
+    fn count_key(&self) -> DataKey {
+        match self {
+            Series::Balance(addr) => DataKey::BalanceCheckpointCount(addr.clone()),
+            Series::Supply => DataKey::SupplyCheckpointCount,
+        }
+    }
+
+    fn checkpoint_key(&self, index: u32) -> DataKey {
+        match self {
+            Series::Balance(addr) => DataKey::BalanceCheckpoint(addr.clone(), index),
+            Series::Supply => DataKey::SupplyCheckpoint(index),
+        }
+    }
+}
+
+pub fn read_snapshot_id(e: &Env) -> u32 {
+    let key = DataKey::SnapshotId;
+    e.storage().instance().get(&key).unwrap_or(0)
+}
+
+/// Starts a new snapshot and returns its id. Ids start at 1.
+pub fn next_snapshot_id(e: &Env) -> u32 {
+    let id = read_snapshot_id(e).checked_add(1).unwrap();
+    e.storage().instance().set(&DataKey::SnapshotId, &id);
+    id
+}
+
+pub fn check_snapshot_id(e: &Env, snapshot_id: u32) -> Result<(), WineTokenError> {
+    if snapshot_id == 0 || snapshot_id > read_snapshot_id(e) {
+        return Err(WineTokenError::SnapshotNotFound);
+    }
+    Ok(())
+}
+
+fn read_persistent<V: TryFromVal<Env, Val>>(e: &Env, key: &DataKey) -> Option<V> {
+    let value = e.storage().persistent().get::<DataKey, V>(key);
+    if value.is_some() {
+        e.storage()
+            .persistent()
+            .extend_ttl(key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+    }
+    value
+}
+
+fn write_persistent<V: IntoVal<Env, Val>>(e: &Env, key: &DataKey, value: &V) {
+    e.storage().persistent().set(key, value);
+    e.storage()
+        .persistent()
+        .extend_ttl(key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+}
+
+fn read_checkpoint_count(e: &Env, series: &Series) -> u32 {
+    read_persistent(e, &series.count_key()).unwrap_or(0)
+}
+
+fn read_checkpoint(e: &Env, series: &Series, index: u32) -> (u32, i128) {
+    read_persistent(e, &series.checkpoint_key(index)).unwrap()
+}
+
+/// Records `value` for the current snapshot unless it was already recorded.
+/// Must be called with the value *before* it changes.
+fn write_checkpoint(e: &Env, series: Series, value: i128) {
+    let current = read_snapshot_id(e);
+    if current == 0 {
+        return;
+    }
+
+    let count = read_checkpoint_count(e, &series);
+    if count > 0 && read_checkpoint(e, &series, count - 1).0 >= current {
+        return;
+    }
+    write_persistent(e, &series.checkpoint_key(count), &(current, value));
+    write_persistent(e, &series.count_key(), &(count + 1));
+}
+
+/// Value as of `snapshot_id`: the first checkpoint taken at or after it, or
+/// `None` if the value has not changed since.
+fn find_checkpoint(e: &Env, series: Series, snapshot_id: u32) -> Option<i128> {
+    let count = read_checkpoint_count(e, &series);
+    let (mut low, mut high) = (0, count);
+    while low < high {
+        let mid = (low + high) / 2;
+        if read_checkpoint(e, &series, mid).0 < snapshot_id {
+            low = mid + 1;
+        } else {
+            high = mid;
+        }
+    }
+    (low < count).then(|| read_checkpoint(e, &series, low).1)
+}
+
+pub fn checkpoint_balance(e: &Env, addr: &Address, balance: i128) {
+    write_checkpoint(e, Series::Balance(addr.clone()), balance);
+}
+
+pub fn checkpoint_total_supply(e: &Env, total_supply: i128) {
+    write_checkpoint(e, Series::Supply, total_supply);
+}
+
+pub fn read_balance_at(e: &Env, addr: &Address, snapshot_id: u32) -> Option<i128> {
+    find_checkpoint(e, Series::Balance(addr.clone()), snapshot_id)
+}
+
+pub fn read_total_supply_at(e: &Env, snapshot_id: u32) -> Option<i128> {
+    find_checkpoint(e, Series::Supply, snapshot_id)
+}
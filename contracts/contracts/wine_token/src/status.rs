@@ -0,0 +1,72 @@
+use common::models::{LotStatus, StatusRecord};
+use soroban_sdk::{Address, BytesN, Env, String};
+
+use crate::error::WineTokenError;
+use crate::events::{emit_pause_event, emit_status_update_event, StatusUpdateEvent};
+use crate::freeze::write_paused;
+use crate::storage::{append_status_record, read_status, read_status_count, write_status};
+
+/// Checks a lot lifecycle transition.
+///
+/// A lot moves harvested → fermented → aged → bottled → shipped → available → sold_out,
+/// one step at a time. It can be recalled from any state, and once recalled it
+/// accepts no further transitions.
+pub fn is_valid_transition(current: Option<LotStatus>, next: LotStatus) -> bool {
+    match (current, next) {
+        (Some(LotStatus::Recalled), _) => false,
+        (_, LotStatus::Recalled) => true,
+        (None, LotStatus::Harvested) => true,
+        (Some(LotStatus::Harvested), LotStatus::Fermented) => true,
+        (Some(LotStatus::Fermented), LotStatus::Aged) => true,
+        (Some(LotStatus::Aged), LotStatus::Bottled) => true,
+        (Some(LotStatus::Bottled), LotStatus::Shipped) => true,
+        (Some(LotStatus::Shipped), LotStatus::Available) => true,
+        (Some(LotStatus::Available), LotStatus::SoldOut) => true,
+        _ => false,
+    }
+}
+
+/// Moves the lot to `status`, appends it to the history and emits the status events.
+/// A recall also pauses the lot's transfers.
+pub fn apply_status(
+    e: &Env,
+    status: LotStatus,
+    location: Option<String>,
+    handler: Address,
+    handler_key: Option<BytesN<32>>,
+) -> Result<(), WineTokenError> {
+    let old_status = read_status(e);
+    if !is_valid_transition(old_status, status) {
+        return Err(WineTokenError::InvalidStatusTransition);
+    }
+    write_status(e, &status);
+    if status == LotStatus::Recalled {
+        write_paused(e, true);
+        emit_pause_event(e, true);
+    }
+    let index = read_status_count(e);
+    append_status_record(
+        e,
+        &StatusRecord {
+            status,
+            location: location.clone(),
+            handler: handler.clone(),
+            handler_key: handler_key.clone(),
+            timestamp: e.ledger().timestamp(),
+            sequence: e.ledger().sequence(),
+        },
+    );
+
+    emit_status_update_event(
+        e,
+        StatusUpdateEvent {
+            index,
+            status,
+            location,
+            handler,
+            handler_key,
+            timestamp: e.ledger().timestamp(),
+        },
+    );
+    Ok(())
+}
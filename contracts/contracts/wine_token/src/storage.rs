@@ -1,21 +1,64 @@
-use soroban_sdk::{Address, Env, String};
+use soroban_sdk::{panic_with_error, Address, Env, Vec};
 use soroban_token_sdk::metadata::TokenMetadata;
-use common::models::WineLotMetadata;
+use common::models::{LotStatus, PendingAdmin, StatusRecord, WineLotDetails, WineLotMetadata};
+
+use crate::error::WineTokenError;
+use crate::snapshot::checkpoint_balance;
 
 pub(crate) const DAY_IN_LEDGERS: u32 = 17280;
 pub(crate) const INSTANCE_BUMP_AMOUNT: u32 = 30 * DAY_IN_LEDGERS;
 pub(crate) const INSTANCE_LIFETIME_THRESHOLD: u32 = INSTANCE_BUMP_AMOUNT - DAY_IN_LEDGERS;
 pub(crate) const BALANCE_BUMP_AMOUNT: u32 = 120 * DAY_IN_LEDGERS;
 pub(crate) const BALANCE_LIFETIME_THRESHOLD: u32 = BALANCE_BUMP_AMOUNT - DAY_IN_LEDGERS;
+pub(crate) const PERSISTENT_BUMP_AMOUNT: u32 = 120 * DAY_IN_LEDGERS;
+pub(crate) const PERSISTENT_LIFETIME_THRESHOLD: u32 = PERSISTENT_BUMP_AMOUNT - 20 * DAY_IN_LEDGERS;
+
+/// Ledger entries a transaction may read or write in total, and write
+/// (protocol 22 network settings).
+pub(crate) const TX_MAX_FOOTPRINT_ENTRIES: u32 = 40;
+pub(crate) const TX_MAX_WRITE_ENTRIES: u32 = 25;
+
+#[derive(Clone)]
+#[soroban_sdk::contracttype]
+pub struct AllowanceDataKey {
+    pub from: Address,
+    pub spender: Address,
+}
+
+#[soroban_sdk::contracttype]
+pub struct AllowanceValue {
+    pub amount: i128,
+    pub expiration_ledger: u32,
+}
 
 #[derive(Clone)]
 #[soroban_sdk::contracttype]
 pub enum DataKey {
     Admin,
+    PendingAdmin,
+    Allowance(AllowanceDataKey),
     WineLotMetadata,
+    MetadataVersion,
+    MetadataHistory(u32),
+    WineLotDetails,
     Balance(Address),
     Metadata,
     Status,
+    StatusCount,
+    StatusRecord(u32),
+    TotalSupply,
+    TotalMinted,
+    Frozen(Address),
+    FrozenCount,
+    Paused,
+    Compliance,
+    SnapshotId,
+    BalanceCheckpointCount(Address),
+    BalanceCheckpoint(Address, u32),
+    SupplyCheckpointCount,
+    SupplyCheckpoint(u32),
+    CodeVersion,
+    Vault,
 }
 
 // Admin functions
@@ -29,6 +72,33 @@ pub fn write_administrator(e: &Env, id: &Address) {
     e.storage().instance().set(&key, id);
 }
 
+// Two-step admin transfer functions
+pub fn read_pending_admin(e: &Env) -> Option<PendingAdmin> {
+    let key = DataKey::PendingAdmin;
+    e.storage().instance().get(&key)
+}
+
+pub fn write_pending_admin(e: &Env, pending: &PendingAdmin) {
+    let key = DataKey::PendingAdmin;
+    e.storage().instance().set(&key, pending);
+}
+
+pub fn remove_pending_admin(e: &Env) {
+    let key = DataKey::PendingAdmin;
+    e.storage().instance().remove(&key);
+}
+
+// Linked investment vault functions
+pub fn read_vault(e: &Env) -> Option<Address> {
+    let key = DataKey::Vault;
+    e.storage().instance().get(&key)
+}
+
+pub fn write_vault(e: &Env, vault: &Address) {
+    let key = DataKey::Vault;
+    e.storage().instance().set(&key, vault);
+}
+
 // Wine Lot Metadata functions
 pub fn read_wine_lot_metadata(e: &Env) -> WineLotMetadata {
     let key = DataKey::WineLotMetadata;
@@ -40,6 +110,17 @@ pub fn write_wine_lot_metadata(e: &Env, metadata: &WineLotMetadata) {
     e.storage().instance().set(&key, metadata);
 }
 
+// Wine Lot Details functions
+pub fn read_wine_lot_details(e: &Env) -> Option<WineLotDetails> {
+    let key = DataKey::WineLotDetails;
+    e.storage().instance().get(&key)
+}
+
+pub fn write_wine_lot_details(e: &Env, details: &WineLotDetails) {
+    let key = DataKey::WineLotDetails;
+    e.storage().instance().set(&key, details);
+}
+
 // Metadata functions
 pub fn read_metadata(e: &Env) -> TokenMetadata {
     let key = DataKey::Metadata;
@@ -74,24 +155,59 @@ fn write_balance(e: &Env, addr: Address, amount: i128) {
 
 pub fn receive_balance(e: &Env, addr: Address, amount: i128) {
     let balance = read_balance(e, addr.clone());
+    checkpoint_balance(e, &addr, balance);
     write_balance(e, addr, balance + amount);
 }
 
 pub fn spend_balance(e: &Env, addr: Address, amount: i128) {
     let balance = read_balance(e, addr.clone());
     if balance < amount {
-        panic!("Insufficient balance");
+        panic_with_error!(e, WineTokenError::InsufficientBalance);
     }
+    checkpoint_balance(e, &addr, balance);
     write_balance(e, addr, balance - amount);
 }
 
 // Status functions
-pub fn read_status(e: &Env) -> Option<String> {
+pub fn read_status(e: &Env) -> Option<LotStatus> {
     let key = DataKey::Status;
     e.storage().instance().get(&key)
 }
 
-pub fn write_status(e: &Env, status: &String) {
+pub fn write_status(e: &Env, status: &LotStatus) {
     let key = DataKey::Status;
     e.storage().instance().set(&key, status);
 }
+
+// Status history functions
+pub fn read_status_count(e: &Env) -> u32 {
+    let key = DataKey::StatusCount;
+    e.storage().instance().get(&key).unwrap_or(0)
+}
+
+pub fn append_status_record(e: &Env, record: &StatusRecord) {
+    let count = read_status_count(e);
+    let key = DataKey::StatusRecord(count);
+    e.storage().persistent().set(&key, record);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+    e.storage()
+        .instance()
+        .set(&DataKey::StatusCount, &count.checked_add(1).unwrap());
+}
+
+pub fn read_status_records(e: &Env, start: u32, limit: u32) -> Vec<StatusRecord> {
+    let end = start.saturating_add(limit).min(read_status_count(e));
+    let mut records = Vec::new(e);
+    for index in start..end {
+        let key = DataKey::StatusRecord(index);
+        if let Some(record) = e.storage().persistent().get::<DataKey, StatusRecord>(&key) {
+            e.storage()
+                .persistent()
+                .extend_ttl(&key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+            records.push_back(record);
+        }
+    }
+    records
+}
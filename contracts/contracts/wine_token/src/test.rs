@@ -0,0 +1,102 @@
+#![cfg(test)]
+extern crate std;
+use common::models::WineLotMetadata;
+use soroban_sdk::{testutils::Address as _, Address, Env, String};
+
+use crate::{WineToken, WineTokenClient};
+
+// Compliance Registry Contract, built with `make build` (see the Makefile)
+pub(crate) mod compliance_registry_contract {
+    soroban_sdk::contractimport!(
+        file = "../../target/wasm32v1-none/release/compliance_registry.wasm"
+    );
+}
+
+use compliance_registry_contract::Client as ComplianceRegistryClient;
+
+pub(crate) fn create_wine_lot_metadata(e: &Env, bottle_count: u32) -> WineLotMetadata {
+    WineLotMetadata {
+        lot_id: String::from_str(e, "MAL-2024-001"),
+        winery_name: String::from_str(e, "Bodega Catena"),
+        region: String::from_str(e, "Mendoza"),
+        country: String::from_str(e, "Argentina"),
+        vintage: 2024,
+        varietal: String::from_str(e, "Malbec"),
+        bottle_count,
+        description: Some(String::from_str(e, "Premium Reserve")),
+        token_code: String::from_str(e, "MAL24"),
+    }
+}
+
+pub(crate) fn create_wine_token<'a>(
+    e: &Env,
+    admin: &Address,
+    decimal: u32,
+    wine_lot_metadata: WineLotMetadata,
+) -> WineTokenClient<'a> {
+    let args = (
+        admin.clone(),
+        decimal,
+        String::from_str(e, "Malbec Reserve 2024"),
+        String::from_str(e, "MAL24"),
+        wine_lot_metadata,
+        None::<Address>,
+    );
+    WineTokenClient::new(e, &e.register(WineToken, args))
+}
+
+/// Creates a lot token checked against a fresh compliance registry run by `admin`.
+pub(crate) fn create_compliant_wine_token<'a>(
+    e: &Env,
+    admin: &Address,
+) -> (WineTokenClient<'a>, ComplianceRegistryClient<'a>) {
+    let registry = ComplianceRegistryClient::new(
+        e,
+        &e.register(compliance_registry_contract::WASM, (admin.clone(),)),
+    );
+    let args = (
+        admin.clone(),
+        0u32,
+        String::from_str(e, "Malbec Reserve 2024"),
+        String::from_str(e, "MAL24"),
+        create_wine_lot_metadata(e, 1000),
+        Some(registry.address.clone()),
+    );
+    let token = WineTokenClient::new(e, &e.register(WineToken, args));
+    (token, registry)
+}
+
+pub struct WineTokenTest<'a> {
+    env: Env,
+    admin: Address,
+    token: WineTokenClient<'a>,
+    user: Address,
+    spender: Address,
+    receiver: Address,
+}
+
+impl<'a> WineTokenTest<'a> {
+    fn setup() -> Self {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let admin = Address::generate(&env);
+        let wine_lot_metadata = create_wine_lot_metadata(&env, 1000);
+        let token = create_wine_token(&env, &admin, 0, wine_lot_metadata);
+
+        let user = Address::generate(&env);
+        let spender = Address::generate(&env);
+        let receiver = Address::generate(&env);
+
+        WineTokenTest {
+            env,
+            admin,
+            token,
+            user,
+            spender,
+            receiver,
+        }
+    }
+}
+
+mod wine_token;
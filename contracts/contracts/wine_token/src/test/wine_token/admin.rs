@@ -0,0 +1,73 @@
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::Address;
+
+use crate::error::WineTokenError;
+use crate::test::WineTokenTest;
+
+#[test]
+fn propose_and_accept_admin() {
+    let test = WineTokenTest::setup();
+    let new_admin = Address::generate(&test.env);
+
+    test.token.propose_admin(&new_admin, &0);
+    let pending = test.token.pending_admin().unwrap();
+    assert_eq!(pending.new_admin, new_admin);
+    assert_eq!(test.token.admin(), test.admin);
+
+    test.token.accept_admin();
+    assert_eq!(test.token.admin(), new_admin);
+    assert!(test.token.pending_admin().is_none());
+}
+
+#[test]
+fn accept_admin_respects_timelock() {
+    let test = WineTokenTest::setup();
+    let new_admin = Address::generate(&test.env);
+
+    let start = test.env.ledger().sequence();
+    test.token.propose_admin(&new_admin, &100);
+    assert_eq!(test.token.pending_admin().unwrap().unlock_ledger, start + 100);
+
+    let result = test.token.try_accept_admin();
+    assert_eq!(result, Err(Ok(WineTokenError::AdminTransferLocked)));
+
+    test.env.ledger().set_sequence_number(start + 100);
+    test.token.accept_admin();
+    assert_eq!(test.token.admin(), new_admin);
+}
+
+#[test]
+fn accept_without_proposal_fails() {
+    let test = WineTokenTest::setup();
+
+    let result = test.token.try_accept_admin();
+    assert_eq!(result, Err(Ok(WineTokenError::NoPendingAdmin)));
+}
+
+#[test]
+fn cancel_admin_transfer() {
+    let test = WineTokenTest::setup();
+    let new_admin = Address::generate(&test.env);
+
+    test.token.propose_admin(&new_admin, &0);
+    test.token.cancel_admin_transfer();
+    assert!(test.token.pending_admin().is_none());
+
+    let result = test.token.try_accept_admin();
+    assert_eq!(result, Err(Ok(WineTokenError::NoPendingAdmin)));
+    assert_eq!(test.token.admin(), test.admin);
+}
+
+#[test]
+fn new_admin_holds_admin_role() {
+    let test = WineTokenTest::setup();
+    let new_admin = Address::generate(&test.env);
+
+    test.token.propose_admin(&new_admin, &0);
+    test.token.accept_admin();
+
+    test.token.mint(&new_admin, &test.user, &10);
+    assert_eq!(test.token.balance(&test.user), 10);
+    let result = test.token.try_mint(&test.admin, &test.user, &10);
+    assert_eq!(result, Err(Ok(WineTokenError::Unauthorized)));
+}
@@ -0,0 +1,102 @@
+use soroban_sdk::testutils::{Ledger, MockAuth, MockAuthInvoke};
+use soroban_sdk::IntoVal;
+
+use crate::test::WineTokenTest;
+
+#[test]
+fn approve_and_read_allowance() {
+    let test = WineTokenTest::setup();
+    let expiration_ledger = test.env.ledger().sequence() + 1000;
+
+    test.token
+        .approve(&test.user, &test.spender, &500, &expiration_ledger);
+
+    assert_eq!(test.token.allowance(&test.user, &test.spender), 500);
+}
+
+#[test]
+fn approve_requires_owner_auth() {
+    let test = WineTokenTest::setup();
+    let expiration_ledger = test.env.ledger().sequence() + 1000;
+
+    let result = test
+        .token
+        .mock_auths(&[MockAuth {
+            address: &test.spender,
+            invoke: &MockAuthInvoke {
+                contract: &test.token.address,
+                fn_name: "approve",
+                args: (&test.user, &test.spender, 500i128, expiration_ledger).into_val(&test.env),
+                sub_invokes: &[],
+            },
+        }])
+        .try_approve(&test.user, &test.spender, &500, &expiration_ledger);
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn allowance_expires() {
+    let test = WineTokenTest::setup();
+    let expiration_ledger = test.env.ledger().sequence() + 100;
+
+    test.token
+        .approve(&test.user, &test.spender, &500, &expiration_ledger);
+    test.env
+        .ledger()
+        .set_sequence_number(expiration_ledger + 1);
+
+    assert_eq!(test.token.allowance(&test.user, &test.spender), 0);
+}
+
+#[test]
+#[should_panic(expected = "expiration_ledger is less than ledger seq when amount > 0")]
+fn approve_with_past_expiration() {
+    let test = WineTokenTest::setup();
+    test.env.ledger().set_sequence_number(100);
+
+    test.token.approve(&test.user, &test.spender, &500, &99);
+}
+
+#[test]
+fn transfer_from_spends_allowance() {
+    let test = WineTokenTest::setup();
+    let expiration_ledger = test.env.ledger().sequence() + 1000;
+
+    test.token.mint(&test.admin, &test.user, &1000);
+    test.token
+        .approve(&test.user, &test.spender, &600, &expiration_ledger);
+    test.token
+        .transfer_from(&test.spender, &test.user, &test.receiver, &400);
+
+    assert_eq!(test.token.balance(&test.user), 600);
+    assert_eq!(test.token.balance(&test.receiver), 400);
+    assert_eq!(test.token.allowance(&test.user, &test.spender), 200);
+}
+
+#[test]
+#[should_panic(expected = "insufficient allowance")]
+fn transfer_from_over_allowance() {
+    let test = WineTokenTest::setup();
+    let expiration_ledger = test.env.ledger().sequence() + 1000;
+
+    test.token.mint(&test.admin, &test.user, &1000);
+    test.token
+        .approve(&test.user, &test.spender, &100, &expiration_ledger);
+    test.token
+        .transfer_from(&test.spender, &test.user, &test.receiver, &101);
+}
+
+#[test]
+fn burn_from_spends_allowance() {
+    let test = WineTokenTest::setup();
+    let expiration_ledger = test.env.ledger().sequence() + 1000;
+
+    test.token.mint(&test.admin, &test.user, &1000);
+    test.token
+        .approve(&test.user, &test.spender, &300, &expiration_ledger);
+    test.token.burn_from(&test.spender, &test.user, &300);
+
+    assert_eq!(test.token.balance(&test.user), 700);
+    assert_eq!(test.token.allowance(&test.user, &test.spender), 0);
+}
@@ -0,0 +1,162 @@
+use soroban_sdk::testutils::{Address as _, Events};
+use soroban_sdk::{vec, Address, Vec};
+
+use crate::test::{create_compliant_wine_token, WineTokenTest};
+use crate::{Role, WineTokenError};
+
+#[test]
+fn mint_batch_credits_every_recipient() {
+    let test = WineTokenTest::setup();
+    let recipients = vec![
+        &test.env,
+        (test.user.clone(), 100i128),
+        (test.receiver.clone(), 250i128),
+        (test.spender.clone(), 50i128),
+    ];
+
+    test.token.mint_batch(&test.admin, &recipients);
+    // One mint event per recipient
+    assert_eq!(test.env.events().all().len(), 3);
+
+    assert_eq!(test.token.balance(&test.user), 100);
+    assert_eq!(test.token.balance(&test.receiver), 250);
+    assert_eq!(test.token.balance(&test.spender), 50);
+    assert_eq!(test.token.total_supply(), 400);
+}
+
+#[test]
+fn mint_batch_is_all_or_nothing() {
+    let test = WineTokenTest::setup();
+    let recipients = vec![
+        &test.env,
+        (test.user.clone(), 600i128),
+        (test.receiver.clone(), 401i128),
+    ];
+
+    let result = test.token.try_mint_batch(&test.admin, &recipients);
+    assert_eq!(result, Err(Ok(WineTokenError::SupplyCapExceeded)));
+    assert_eq!(test.token.balance(&test.user), 0);
+    assert_eq!(test.token.total_supply(), 0);
+}
+
+#[test]
+fn mint_batch_requires_minter() {
+    let test = WineTokenTest::setup();
+    let minter = Address::generate(&test.env);
+    let recipients = vec![&test.env, (test.user.clone(), 10i128)];
+
+    let result = test.token.try_mint_batch(&minter, &recipients);
+    assert_eq!(result, Err(Ok(WineTokenError::Unauthorized)));
+
+    test.token.grant_role(&Role::Minter, &minter);
+    test.token.mint_batch(&minter, &recipients);
+    assert_eq!(test.token.balance(&test.user), 10);
+}
+
+#[test]
+fn batch_size_is_bounded() {
+    let test = WineTokenTest::setup();
+
+    let result = test.token.try_mint_batch(&test.admin, &Vec::new(&test.env));
+    assert_eq!(result, Err(Ok(WineTokenError::EmptyBatch)));
+
+    let mut recipients = Vec::new(&test.env);
+    for _ in 0..=test.token.max_batch_size() {
+        recipients.push_back((Address::generate(&test.env), 1i128));
+    }
+    let result = test.token.try_mint_batch(&test.admin, &recipients);
+    assert_eq!(result, Err(Ok(WineTokenError::BatchTooLarge)));
+    let result = test.token.try_transfer_batch(&test.user, &recipients);
+    assert_eq!(result, Err(Ok(WineTokenError::BatchTooLarge)));
+}
+
+#[test]
+fn batch_size_follows_active_checks() {
+    let test = WineTokenTest::setup();
+    let max = test.token.max_batch_size();
+    assert!(max >= 16);
+
+    test.token.freeze(&test.spender);
+    assert!(test.token.max_batch_size() < max);
+    test.token.unfreeze(&test.spender);
+    assert_eq!(test.token.max_batch_size(), max);
+
+    test.token.snapshot();
+    assert!(test.token.max_batch_size() < max);
+
+    let (token, _) = create_compliant_wine_token(&test.env, &test.admin);
+    assert!(token.max_batch_size() < max);
+}
+
+#[test]
+fn batch_amounts_are_checked() {
+    let test = WineTokenTest::setup();
+
+    let negative = vec![
+        &test.env,
+        (test.user.clone(), 10i128),
+        (test.receiver.clone(), -1i128),
+    ];
+    let result = test.token.try_mint_batch(&test.admin, &negative);
+    assert_eq!(result, Err(Ok(WineTokenError::NegativeAmount)));
+    let result = test.token.try_transfer_batch(&test.user, &negative);
+    assert_eq!(result, Err(Ok(WineTokenError::NegativeAmount)));
+
+    let overflow = vec![
+        &test.env,
+        (test.user.clone(), i128::MAX),
+        (test.receiver.clone(), 1i128),
+    ];
+    let result = test.token.try_mint_batch(&test.admin, &overflow);
+    assert_eq!(result, Err(Ok(WineTokenError::BatchTotalOverflow)));
+    let result = test.token.try_transfer_batch(&test.user, &overflow);
+    assert_eq!(result, Err(Ok(WineTokenError::BatchTotalOverflow)));
+}
+
+#[test]
+fn transfer_batch_moves_tokens() {
+    let test = WineTokenTest::setup();
+    test.token.mint(&test.admin, &test.user, &100);
+
+    let recipients = vec![
+        &test.env,
+        (test.receiver.clone(), 30i128),
+        (test.spender.clone(), 20i128),
+    ];
+    test.token.transfer_batch(&test.user, &recipients);
+    // One transfer event per recipient
+    assert_eq!(test.env.events().all().len(), 2);
+
+    assert_eq!(test.token.balance(&test.user), 50);
+    assert_eq!(test.token.balance(&test.receiver), 30);
+    assert_eq!(test.token.balance(&test.spender), 20);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #111)")]
+fn transfer_batch_checks_total_balance() {
+    let test = WineTokenTest::setup();
+    test.token.mint(&test.admin, &test.user, &100);
+
+    let recipients = vec![
+        &test.env,
+        (test.receiver.clone(), 60i128),
+        (test.spender.clone(), 41i128),
+    ];
+    test.token.transfer_batch(&test.user, &recipients);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #140)")]
+fn transfer_batch_rejects_frozen_recipient() {
+    let test = WineTokenTest::setup();
+    test.token.mint(&test.admin, &test.user, &100);
+    test.token.freeze(&test.spender);
+
+    let recipients = vec![
+        &test.env,
+        (test.receiver.clone(), 30i128),
+        (test.spender.clone(), 20i128),
+    ];
+    test.token.transfer_batch(&test.user, &recipients);
+}
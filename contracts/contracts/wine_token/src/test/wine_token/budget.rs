@@ -0,0 +1,211 @@
+extern crate std;
+
+use soroban_sdk::testutils::cost_estimate::CostEstimate;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, Env, String, Vec};
+
+use crate::test::{
+    create_compliant_wine_token, create_wine_lot_metadata, create_wine_token, WineTokenTest,
+};
+
+// Per-transaction network limits (protocol 22 network settings). The read
+// entry limit covers the whole footprint, including the entries written.
+const TX_MAX_CPU_INSTRUCTIONS: u64 = 100_000_000;
+const TX_MAX_MEMORY_BYTES: u64 = 40 * 1024 * 1024;
+const TX_MAX_READ_ENTRIES: u32 = 40;
+const TX_MAX_WRITE_ENTRIES: u32 = 25;
+const TX_MAX_READ_BYTES: u32 = 200_000;
+const TX_MAX_WRITE_BYTES: u32 = 132_096;
+
+// The contract code entry, which a natively registered contract never reads
+const CODE_ENTRIES: u32 = 1;
+
+fn recipients(test: &WineTokenTest, size: u32) -> Vec<(Address, i128)> {
+    let mut recipients = Vec::new(&test.env);
+    for _ in 0..size {
+        recipients.push_back((Address::generate(&test.env), 1i128));
+    }
+    recipients
+}
+
+// Common case: no compliance registry, no frozen account and no snapshot
+#[test]
+fn budget() {
+    let test = WineTokenTest::setup();
+    let max = test.token.max_batch_size();
+
+    for size in [1, max / 2, max] {
+        let batch = recipients(&test, size);
+        test.env.cost_estimate().budget().reset_default();
+        test.token.mint_batch(&test.admin, &batch);
+
+        let cpu = test.env.cost_estimate().budget().cpu_instruction_cost();
+        let mem = test.env.cost_estimate().budget().memory_bytes_cost();
+        std::println!(
+            "mint_batch({:>2})                                          | cpu: {},      mem: {}",
+            size,
+            cpu,
+            mem
+        );
+        assert!(cpu < TX_MAX_CPU_INSTRUCTIONS);
+        assert!(mem < TX_MAX_MEMORY_BYTES);
+    }
+
+    let sizes = [1, max / 2, max];
+    test.token
+        .mint(&test.admin, &test.user, &(sizes.iter().sum::<u32>() as i128));
+    for size in sizes {
+        let batch = recipients(&test, size);
+        test.env.cost_estimate().budget().reset_default();
+        test.token.transfer_batch(&test.user, &batch);
+
+        let cpu = test.env.cost_estimate().budget().cpu_instruction_cost();
+        let mem = test.env.cost_estimate().budget().memory_bytes_cost();
+        std::println!(
+            "transfer_batch({:>2})                                      | cpu: {},      mem: {}",
+            size,
+            cpu,
+            mem
+        );
+        assert!(cpu < TX_MAX_CPU_INSTRUCTIONS);
+        assert!(mem < TX_MAX_MEMORY_BYTES);
+    }
+}
+
+struct Footprint {
+    read_entries: u32,
+    write_entries: u32,
+    read_bytes: u32,
+    write_bytes: u32,
+}
+
+impl Footprint {
+    fn last_invocation(cost: CostEstimate) -> Self {
+        let resources = cost.resources();
+        Footprint {
+            read_entries: resources.read_entries + resources.write_entries + CODE_ENTRIES,
+            write_entries: resources.write_entries,
+            read_bytes: resources.read_bytes,
+            write_bytes: resources.write_bytes,
+        }
+    }
+
+    fn fits(&self) -> bool {
+        self.read_entries <= TX_MAX_READ_ENTRIES
+            && self.write_entries <= TX_MAX_WRITE_ENTRIES
+            && self.read_bytes <= TX_MAX_READ_BYTES
+            && self.write_bytes <= TX_MAX_WRITE_BYTES
+    }
+
+    /// Adds the cost of one more recipient, measured against a single-recipient run.
+    fn with_one_more(&self, single: &Footprint, size: u32) -> Self {
+        let step = |full: u32, one: u32| full + (full - one) / (size - 1);
+        Footprint {
+            read_entries: step(self.read_entries, single.read_entries),
+            write_entries: step(self.write_entries, single.write_entries),
+            read_bytes: step(self.read_bytes, single.read_bytes),
+            write_bytes: step(self.write_bytes, single.write_bytes),
+        }
+    }
+
+    fn print(&self, label: &str) {
+        std::println!(
+            "{:<24} | read entries: {:>2}, write entries: {:>2}, read bytes: {:>5}, write bytes: {:>5}",
+            label,
+            self.read_entries,
+            self.write_entries,
+            self.read_bytes,
+            self.write_bytes
+        );
+    }
+}
+
+// Checks that change the footprint of each recipient in a batch
+#[derive(Clone, Copy, Debug)]
+struct Mode {
+    frozen: bool,
+    compliance: bool,
+    snapshots: bool,
+}
+
+const MODES: [Mode; 8] = [
+    Mode { frozen: false, compliance: false, snapshots: false },
+    Mode { frozen: true, compliance: false, snapshots: false },
+    Mode { frozen: false, compliance: true, snapshots: false },
+    Mode { frozen: true, compliance: true, snapshots: false },
+    Mode { frozen: false, compliance: false, snapshots: true },
+    Mode { frozen: true, compliance: false, snapshots: true },
+    Mode { frozen: false, compliance: true, snapshots: true },
+    Mode { frozen: true, compliance: true, snapshots: true },
+];
+
+// Worst case per recipient in a mode: with a compliance registry each holder
+// is in its own country, and with snapshots one is pending for holders that
+// already have checkpoints, so every recipient reads its allowlist, country
+// rule and last checkpoint, and writes its balance, a new checkpoint and the
+// count. Returns the mint and transfer footprints and the token's batch cap.
+fn worst_case_footprints(mode: Mode, size: u32) -> (Footprint, Footprint, u32) {
+    let env = Env::default();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+    let (token, registry) = if mode.compliance {
+        let (token, registry) = create_compliant_wine_token(&env, &admin);
+        (token, Some(registry))
+    } else {
+        let metadata = create_wine_lot_metadata(&env, 1000);
+        (create_wine_token(&env, &admin, 0, metadata), None)
+    };
+    if mode.frozen {
+        token.freeze(&Address::generate(&env));
+    }
+
+    let user = Address::generate(&env);
+    if let Some(registry) = &registry {
+        registry.allow_account(&admin, &user, &String::from_str(&env, "Argentina"));
+    }
+    token.mint(&admin, &user, &100);
+    let mut holders = Vec::new(&env);
+    for i in 0..size {
+        let holder = Address::generate(&env);
+        if let Some(registry) = &registry {
+            let country = String::from_str(&env, &std::format!("Country {}", i));
+            registry.allow_account(&admin, &holder, &country);
+        }
+        holders.push_back((holder, 1i128));
+    }
+    if mode.snapshots {
+        token.mint_batch(&admin, &holders);
+        token.snapshot();
+        for (holder, _) in holders.iter() {
+            token.transfer(&user, &holder, &1);
+        }
+        token.snapshot();
+    }
+
+    token.mint_batch(&admin, &holders);
+    let mint = Footprint::last_invocation(env.cost_estimate());
+    if mode.snapshots {
+        token.snapshot();
+    }
+    token.transfer_batch(&user, &holders);
+    let transfer = Footprint::last_invocation(env.cost_estimate());
+    (mint, transfer, token.max_batch_size())
+}
+
+#[test]
+fn ledger_footprint() {
+    for mode in MODES {
+        let (mint_one, transfer_one, max) = worst_case_footprints(mode, 1);
+        let (mint, transfer, _) = worst_case_footprints(mode, max);
+        std::println!("{:?}", mode);
+        mint.print(&std::format!("mint_batch({})", max));
+        transfer.print(&std::format!("transfer_batch({})", max));
+        assert!(mint.fits());
+        assert!(transfer.fits());
+
+        // The cap is the largest batch that fits: one more recipient would not
+        let mint_next = mint.with_one_more(&mint_one, max);
+        let transfer_next = transfer.with_one_more(&transfer_one, max);
+        assert!(!mint_next.fits() || !transfer_next.fits());
+    }
+}
@@ -0,0 +1,167 @@
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{vec, Address, Env, String};
+
+use crate::test::{
+    create_compliant_wine_token, create_wine_lot_metadata, ComplianceRegistryClient,
+    WineTokenTest,
+};
+use crate::{WineToken, WineTokenClient};
+
+fn setup<'a>() -> (Env, Address, WineTokenClient<'a>, ComplianceRegistryClient<'a>) {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let (token, registry) = create_compliant_wine_token(&env, &admin);
+    (env, admin, token, registry)
+}
+
+#[test]
+fn compliance_is_off_by_default() {
+    let test = WineTokenTest::setup();
+
+    assert_eq!(test.token.compliance(), None);
+    test.token.mint(&test.admin, &test.user, &100);
+    test.token.transfer(&test.user, &test.receiver, &10);
+    assert_eq!(test.token.balance(&test.receiver), 10);
+}
+
+#[test]
+fn allowlisted_accounts_can_mint_and_transfer() {
+    let (env, admin, token, registry) = setup();
+    let user = Address::generate(&env);
+    let receiver = Address::generate(&env);
+
+    assert_eq!(token.compliance(), Some(registry.address.clone()));
+    registry.allow_account(&admin, &user, &String::from_str(&env, "Argentina"));
+    registry.allow_account(&admin, &receiver, &String::from_str(&env, "Chile"));
+
+    token.mint(&admin, &user, &100);
+    token.transfer(&user, &receiver, &10);
+    assert_eq!(token.balance(&receiver), 10);
+}
+
+#[test]
+fn mint_to_unlisted_account_fails() {
+    let (env, admin, token, _) = setup();
+    let user = Address::generate(&env);
+
+    let result = token.try_mint(&admin, &user, &100);
+    assert!(result.is_err());
+    assert_eq!(token.balance(&user), 0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #142)")]
+fn transfer_to_unlisted_account_fails() {
+    let (env, admin, token, registry) = setup();
+    let user = Address::generate(&env);
+    let receiver = Address::generate(&env);
+
+    registry.allow_account(&admin, &user, &String::from_str(&env, "Argentina"));
+    token.mint(&admin, &user, &100);
+    token.transfer(&user, &receiver, &10);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #142)")]
+fn disallowed_account_cannot_send() {
+    let (env, admin, token, registry) = setup();
+    let user = Address::generate(&env);
+    let receiver = Address::generate(&env);
+    let argentina = String::from_str(&env, "Argentina");
+
+    registry.allow_account(&admin, &user, &argentina);
+    registry.allow_account(&admin, &receiver, &argentina);
+    token.mint(&admin, &user, &100);
+
+    registry.disallow_account(&admin, &user);
+    token.transfer(&user, &receiver, &10);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #143)")]
+fn blocked_country_cannot_receive() {
+    let (env, admin, token, registry) = setup();
+    let user = Address::generate(&env);
+    let receiver = Address::generate(&env);
+    let atlantis = String::from_str(&env, "Atlantis");
+
+    registry.allow_account(&admin, &user, &String::from_str(&env, "Argentina"));
+    registry.allow_account(&admin, &receiver, &atlantis);
+    token.mint(&admin, &user, &100);
+
+    registry.set_country_blocked(&admin, &String::from_str(&env, "Argentina"), &atlantis, &true);
+    token.transfer(&user, &receiver, &10);
+}
+
+#[test]
+fn country_rules_follow_the_lot_origin() {
+    let (env, admin, token, registry) = setup();
+    let user = Address::generate(&env);
+    let receiver = Address::generate(&env);
+    let atlantis = String::from_str(&env, "Atlantis");
+
+    // A French lot sharing the registry, next to the Argentine lot from setup
+    let mut metadata = create_wine_lot_metadata(&env, 1000);
+    metadata.country = String::from_str(&env, "France");
+    let args = (
+        admin.clone(),
+        0u32,
+        String::from_str(&env, "Bordeaux 2024"),
+        String::from_str(&env, "BDX24"),
+        metadata,
+        Some(registry.address.clone()),
+    );
+    let french = WineTokenClient::new(&env, &env.register(WineToken, args));
+
+    registry.allow_account(&admin, &user, &String::from_str(&env, "Argentina"));
+    registry.allow_account(&admin, &receiver, &atlantis);
+    registry.set_country_blocked(&admin, &String::from_str(&env, "Argentina"), &atlantis, &true);
+
+    token.mint(&admin, &user, &100);
+    french.mint(&admin, &user, &100);
+
+    assert!(token.try_transfer(&user, &receiver, &10).is_err());
+    french.transfer(&user, &receiver, &10);
+    assert_eq!(token.balance(&receiver), 0);
+    assert_eq!(french.balance(&receiver), 10);
+}
+
+#[test]
+fn unblocking_a_country_restores_transfers() {
+    let (env, admin, token, registry) = setup();
+    let user = Address::generate(&env);
+    let receiver = Address::generate(&env);
+    let argentina = String::from_str(&env, "Argentina");
+    let atlantis = String::from_str(&env, "Atlantis");
+
+    registry.allow_account(&admin, &user, &argentina);
+    registry.allow_account(&admin, &receiver, &atlantis);
+    token.mint(&admin, &user, &100);
+
+    registry.set_country_blocked(&admin, &argentina, &atlantis, &true);
+    registry.set_country_blocked(&admin, &argentina, &atlantis, &false);
+    token.transfer(&user, &receiver, &10);
+    assert_eq!(token.balance(&receiver), 10);
+}
+
+#[test]
+fn batches_reject_any_unlisted_recipient() {
+    let (env, admin, token, registry) = setup();
+    let user = Address::generate(&env);
+    let listed = Address::generate(&env);
+    let unlisted = Address::generate(&env);
+    let argentina = String::from_str(&env, "Argentina");
+
+    registry.allow_account(&admin, &user, &argentina);
+    registry.allow_account(&admin, &listed, &argentina);
+
+    let recipients = vec![&env, (listed.clone(), 10), (unlisted.clone(), 10)];
+    assert!(token.try_mint_batch(&admin, &recipients).is_err());
+
+    token.mint(&admin, &user, &100);
+    assert!(token.try_transfer_batch(&user, &recipients).is_err());
+    assert_eq!(token.balance(&user), 100);
+    assert_eq!(token.balance(&listed), 0);
+}
@@ -0,0 +1,96 @@
+use common::models::{BlendComponent, WineLotDetails, WINE_LOT_DETAILS_VERSION};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{vec, Address, BytesN, Env, String};
+
+use crate::test::WineTokenTest;
+use crate::{Role, WineTokenError};
+
+fn create_wine_lot_details(e: &Env) -> WineLotDetails {
+    WineLotDetails {
+        version: WINE_LOT_DETAILS_VERSION,
+        appellation: Some(String::from_str(e, "Lujan de Cuyo DOC")),
+        abv_bps: 1450,
+        blend: vec![
+            e,
+            BlendComponent {
+                varietal: String::from_str(e, "Malbec"),
+                bps: 8500,
+            },
+            BlendComponent {
+                varietal: String::from_str(e, "Cabernet Franc"),
+                bps: 1500,
+            },
+        ],
+        barrel_regime: Some(String::from_str(e, "18 months French oak")),
+        lab_analysis_hash: Some(BytesN::from_array(e, &[1u8; 32])),
+        certificate_hashes: vec![e, BytesN::from_array(e, &[2u8; 32])],
+    }
+}
+
+#[test]
+fn set_and_get_details() {
+    let test = WineTokenTest::setup();
+    assert_eq!(test.token.get_wine_lot_details(), None);
+
+    let details = create_wine_lot_details(&test.env);
+    test.token.set_wine_lot_details(&test.admin, &details);
+    assert_eq!(test.token.get_wine_lot_details(), Some(details));
+}
+
+#[test]
+fn blend_must_sum_to_full_share() {
+    let test = WineTokenTest::setup();
+
+    let mut details = create_wine_lot_details(&test.env);
+    details.blend.set(
+        1,
+        BlendComponent {
+            varietal: String::from_str(&test.env, "Cabernet Franc"),
+            bps: 1400,
+        },
+    );
+    let result = test.token.try_set_wine_lot_details(&test.admin, &details);
+    assert_eq!(result, Err(Ok(WineTokenError::InvalidBlend)));
+
+    details.blend = vec![&test.env];
+    let result = test.token.try_set_wine_lot_details(&test.admin, &details);
+    assert_eq!(result, Err(Ok(WineTokenError::InvalidBlend)));
+}
+
+#[test]
+fn abv_must_be_in_range() {
+    let test = WineTokenTest::setup();
+
+    let mut details = create_wine_lot_details(&test.env);
+    details.abv_bps = 0;
+    let result = test.token.try_set_wine_lot_details(&test.admin, &details);
+    assert_eq!(result, Err(Ok(WineTokenError::InvalidAbv)));
+
+    details.abv_bps = 2501;
+    let result = test.token.try_set_wine_lot_details(&test.admin, &details);
+    assert_eq!(result, Err(Ok(WineTokenError::InvalidAbv)));
+}
+
+#[test]
+fn unknown_details_version_is_rejected() {
+    let test = WineTokenTest::setup();
+
+    let mut details = create_wine_lot_details(&test.env);
+    details.version = WINE_LOT_DETAILS_VERSION + 1;
+    let result = test.token.try_set_wine_lot_details(&test.admin, &details);
+    assert_eq!(result, Err(Ok(WineTokenError::UnsupportedDetailsVersion)));
+}
+
+#[test]
+fn metadata_editor_can_set_details() {
+    let test = WineTokenTest::setup();
+    let editor = Address::generate(&test.env);
+    let details = create_wine_lot_details(&test.env);
+
+    let result = test.token.try_set_wine_lot_details(&editor, &details);
+    assert_eq!(result, Err(Ok(WineTokenError::Unauthorized)));
+
+    test.token.grant_role(&Role::MetadataEditor, &editor);
+    test.token.set_wine_lot_details(&editor, &details);
+    assert_eq!(test.token.get_wine_lot_details(), Some(details));
+}
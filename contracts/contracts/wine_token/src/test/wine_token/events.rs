@@ -0,0 +1,212 @@
+use common::models::{LotStatus, Role};
+use soroban_sdk::testutils::{Address as _, Events};
+use soroban_sdk::{symbol_short, vec, Address, FromVal, IntoVal, String};
+
+use crate::events::{FreezeEvent, PauseEvent, RoleChangedEvent, StatusUpdateEvent};
+use crate::test::WineTokenTest;
+
+#[test]
+fn mint_event() {
+    let test = WineTokenTest::setup();
+
+    test.token.mint(&test.admin, &test.user, &100);
+
+    let events = test.env.events().all();
+    assert_eq!(
+        events,
+        vec![
+            &test.env,
+            (
+                test.token.address.clone(),
+                (symbol_short!("mint"), test.admin.clone(), test.user.clone()).into_val(&test.env),
+                100i128.into_val(&test.env),
+            ),
+        ]
+    );
+}
+
+#[test]
+fn transfer_event() {
+    let test = WineTokenTest::setup();
+    test.token.mint(&test.admin, &test.user, &100);
+
+    test.token.transfer(&test.user, &test.receiver, &40);
+
+    let events = test.env.events().all();
+    assert_eq!(events.len(), 1);
+    let (_, topics, data) = events.last().unwrap();
+    assert_eq!(
+        topics,
+        (symbol_short!("transfer"), test.user.clone(), test.receiver.clone()).into_val(&test.env)
+    );
+    assert_eq!(i128::from_val(&test.env, &data), 40);
+}
+
+#[test]
+fn batch_events_match_single_transfers() {
+    let test = WineTokenTest::setup();
+    let recipients = vec![
+        &test.env,
+        (test.user.clone(), 60i128),
+        (test.receiver.clone(), 40i128),
+    ];
+
+    test.token.mint_batch(&test.admin, &recipients);
+    let (_, topics, data) = test.env.events().all().last().unwrap();
+    assert_eq!(
+        topics,
+        (symbol_short!("mint"), test.admin.clone(), test.receiver.clone()).into_val(&test.env)
+    );
+    assert_eq!(i128::from_val(&test.env, &data), 40);
+
+    let recipients = vec![&test.env, (test.spender.clone(), 25i128)];
+    test.token.transfer_batch(&test.user, &recipients);
+    let (_, topics, data) = test.env.events().all().last().unwrap();
+    assert_eq!(
+        topics,
+        (symbol_short!("transfer"), test.user.clone(), test.spender.clone()).into_val(&test.env)
+    );
+    assert_eq!(i128::from_val(&test.env, &data), 25);
+}
+
+#[test]
+fn burn_and_clawback_events() {
+    let test = WineTokenTest::setup();
+    test.token.mint(&test.admin, &test.user, &100);
+
+    test.token.burn(&test.user, &10);
+    let (_, topics, data) = test.env.events().all().last().unwrap();
+    assert_eq!(
+        topics,
+        (symbol_short!("burn"), test.user.clone()).into_val(&test.env)
+    );
+    assert_eq!(i128::from_val(&test.env, &data), 10);
+
+    test.token.clawback(&test.user, &20);
+    let (_, topics, data) = test.env.events().all().last().unwrap();
+    assert_eq!(
+        topics,
+        (symbol_short!("clawback"), test.admin.clone(), test.user.clone()).into_val(&test.env)
+    );
+    assert_eq!(i128::from_val(&test.env, &data), 20);
+}
+
+#[test]
+fn status_update_event() {
+    let test = WineTokenTest::setup();
+    let cellar = Some(String::from_str(&test.env, "Mendoza Cellar"));
+
+    test.token.set_status(&test.admin, &LotStatus::Harvested, &None);
+    test.token
+        .set_status(&test.admin, &LotStatus::Fermented, &cellar);
+
+    let events = test.env.events().all();
+    assert_eq!(events.len(), 1);
+    let (_, topics, data) = events.last().unwrap();
+    assert_eq!(
+        topics,
+        (symbol_short!("status"), test.admin.clone()).into_val(&test.env)
+    );
+    let event: StatusUpdateEvent = FromVal::from_val(&test.env, &data);
+    assert_eq!(event.status, LotStatus::Fermented);
+    assert_eq!(event.index, 1);
+    assert_eq!(event.location, cellar);
+    assert_eq!(event.handler, test.admin);
+    assert_eq!(event.handler_key, None);
+}
+
+#[test]
+fn admin_changed_event() {
+    let test = WineTokenTest::setup();
+    let new_admin = Address::generate(&test.env);
+
+    test.token.propose_admin(&new_admin, &0);
+    test.token.accept_admin();
+
+    let (_, topics, data) = test.env.events().all().last().unwrap();
+    assert_eq!(
+        topics,
+        (symbol_short!("set_admin"), test.admin.clone()).into_val(&test.env)
+    );
+    assert_eq!(Address::from_val(&test.env, &data), new_admin);
+}
+
+#[test]
+fn freeze_events() {
+    let test = WineTokenTest::setup();
+
+    test.token.freeze(&test.user);
+    let (_, topics, data) = test.env.events().all().last().unwrap();
+    assert_eq!(
+        topics,
+        (symbol_short!("freeze"), test.user.clone()).into_val(&test.env)
+    );
+    let event: FreezeEvent = FromVal::from_val(&test.env, &data);
+    assert_eq!(
+        event,
+        FreezeEvent {
+            account: test.user.clone(),
+            frozen: true,
+        }
+    );
+
+    test.token.unfreeze(&test.user);
+    let (_, topics, data) = test.env.events().all().last().unwrap();
+    assert_eq!(
+        topics,
+        (symbol_short!("unfreeze"), test.user.clone()).into_val(&test.env)
+    );
+    let event: FreezeEvent = FromVal::from_val(&test.env, &data);
+    assert!(!event.frozen);
+}
+
+#[test]
+fn recall_publishes_pause_event() {
+    let test = WineTokenTest::setup();
+
+    test.token
+        .set_status(&test.admin, &LotStatus::Recalled, &None);
+    let events = test.env.events().all();
+    assert_eq!(events.len(), 2);
+    let (_, topics, data) = events.get(0).unwrap();
+    assert_eq!(topics, (symbol_short!("pause"),).into_val(&test.env));
+    let event: PauseEvent = FromVal::from_val(&test.env, &data);
+    assert!(event.paused);
+
+    test.token.unpause();
+    let (_, topics, data) = test.env.events().all().last().unwrap();
+    assert_eq!(topics, (symbol_short!("unpause"),).into_val(&test.env));
+    let event: PauseEvent = FromVal::from_val(&test.env, &data);
+    assert!(!event.paused);
+}
+
+#[test]
+fn role_events() {
+    let test = WineTokenTest::setup();
+
+    test.token.grant_role(&Role::Minter, &test.user);
+    let (_, topics, data) = test.env.events().all().last().unwrap();
+    assert_eq!(
+        topics,
+        (symbol_short!("grant"), test.user.clone()).into_val(&test.env)
+    );
+    let event: RoleChangedEvent = FromVal::from_val(&test.env, &data);
+    assert_eq!(
+        event,
+        RoleChangedEvent {
+            role: Role::Minter,
+            account: test.user.clone(),
+            granted: true,
+        }
+    );
+
+    test.token.revoke_role(&Role::Minter, &test.user);
+    let (_, topics, data) = test.env.events().all().last().unwrap();
+    assert_eq!(
+        topics,
+        (symbol_short!("revoke"), test.user.clone()).into_val(&test.env)
+    );
+    let event: RoleChangedEvent = FromVal::from_val(&test.env, &data);
+    assert_eq!(event.role, Role::Minter);
+    assert!(!event.granted);
+}
@@ -0,0 +1,198 @@
+use common::models::LotStatus;
+use soroban_sdk::vec;
+
+use crate::test::WineTokenTest;
+
+#[test]
+fn freeze_and_unfreeze() {
+    let test = WineTokenTest::setup();
+
+    test.token.freeze(&test.user);
+    assert!(test.token.is_frozen(&test.user));
+
+    test.token.unfreeze(&test.user);
+    assert!(!test.token.is_frozen(&test.user));
+}
+
+#[test]
+fn repeated_freezes_are_counted_once() {
+    let test = WineTokenTest::setup();
+    let max = test.token.max_batch_size();
+    test.token.mint(&test.admin, &test.user, &100);
+
+    test.token.freeze(&test.receiver);
+    test.token.freeze(&test.receiver);
+    test.token.unfreeze(&test.receiver);
+    test.token.unfreeze(&test.receiver);
+
+    // Nothing is frozen any more, so batches are back to their full size
+    assert_eq!(test.token.max_batch_size(), max);
+    test.token.transfer(&test.user, &test.receiver, &10);
+    assert_eq!(test.token.balance(&test.receiver), 10);
+
+    test.token.freeze(&test.spender);
+    test.token.freeze(&test.receiver);
+    test.token.unfreeze(&test.spender);
+    assert!(test.token.try_transfer(&test.user, &test.receiver, &10).is_err());
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #140)")]
+fn frozen_sender_cannot_transfer() {
+    let test = WineTokenTest::setup();
+
+    test.token.mint(&test.admin, &test.user, &100);
+    test.token.freeze(&test.user);
+    test.token.transfer(&test.user, &test.receiver, &10);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #140)")]
+fn frozen_receiver_cannot_receive() {
+    let test = WineTokenTest::setup();
+
+    test.token.mint(&test.admin, &test.user, &100);
+    test.token.freeze(&test.receiver);
+    test.token.transfer(&test.user, &test.receiver, &10);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #140)")]
+fn frozen_account_cannot_burn() {
+    let test = WineTokenTest::setup();
+
+    test.token.mint(&test.admin, &test.user, &100);
+    test.token.freeze(&test.user);
+    test.token.burn(&test.user, &10);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #140)")]
+fn frozen_account_cannot_be_spent_from() {
+    let test = WineTokenTest::setup();
+    let expiration_ledger = test.env.ledger().sequence() + 1000;
+
+    test.token.mint(&test.admin, &test.user, &100);
+    test.token
+        .approve(&test.user, &test.spender, &50, &expiration_ledger);
+    test.token.freeze(&test.user);
+    test.token
+        .transfer_from(&test.spender, &test.user, &test.receiver, &10);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #141)")]
+fn recall_pauses_transfers() {
+    let test = WineTokenTest::setup();
+
+    test.token.mint(&test.admin, &test.user, &100);
+    test.token
+        .set_status(&test.admin, &LotStatus::Harvested, &None);
+    test.token
+        .set_status(&test.admin, &LotStatus::Recalled, &None);
+
+    assert!(test.token.is_paused());
+    test.token.transfer(&test.user, &test.receiver, &10);
+}
+
+#[test]
+fn unpause_resumes_transfers_after_recall() {
+    let test = WineTokenTest::setup();
+
+    test.token.mint(&test.admin, &test.user, &100);
+    test.token
+        .set_status(&test.admin, &LotStatus::Recalled, &None);
+    assert!(test.token.try_transfer(&test.user, &test.receiver, &10).is_err());
+
+    test.token.unpause();
+    assert!(!test.token.is_paused());
+    assert_eq!(test.token.get_status(), Some(LotStatus::Recalled));
+    test.token.transfer(&test.user, &test.receiver, &10);
+    assert_eq!(test.token.balance(&test.receiver), 10);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #141)")]
+fn admin_can_pause_without_recall() {
+    let test = WineTokenTest::setup();
+
+    test.token.mint(&test.admin, &test.user, &100);
+    test.token.pause();
+    assert!(test.token.is_paused());
+    assert_eq!(test.token.get_status(), None);
+    test.token.burn(&test.user, &10);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #140)")]
+fn frozen_spender_cannot_use_allowance() {
+    let test = WineTokenTest::setup();
+    let expiration_ledger = test.env.ledger().sequence() + 1000;
+
+    test.token.mint(&test.admin, &test.user, &100);
+    test.token
+        .approve(&test.user, &test.spender, &50, &expiration_ledger);
+    test.token.freeze(&test.spender);
+    test.token
+        .transfer_from(&test.spender, &test.user, &test.receiver, &10);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #140)")]
+fn frozen_spender_cannot_burn_from() {
+    let test = WineTokenTest::setup();
+    let expiration_ledger = test.env.ledger().sequence() + 1000;
+
+    test.token.mint(&test.admin, &test.user, &100);
+    test.token
+        .approve(&test.user, &test.spender, &50, &expiration_ledger);
+    test.token.freeze(&test.spender);
+    test.token.burn_from(&test.spender, &test.user, &10);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #140)")]
+fn frozen_account_cannot_receive_mint() {
+    let test = WineTokenTest::setup();
+
+    test.token.freeze(&test.user);
+    test.token.mint(&test.admin, &test.user, &100);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #141)")]
+fn recall_pauses_mints() {
+    let test = WineTokenTest::setup();
+
+    test.token
+        .set_status(&test.admin, &LotStatus::Recalled, &None);
+    test.token.mint(&test.admin, &test.user, &100);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #141)")]
+fn recall_pauses_batch_mints() {
+    let test = WineTokenTest::setup();
+
+    test.token
+        .set_status(&test.admin, &LotStatus::Recalled, &None);
+    test.token.mint_batch(
+        &test.admin,
+        &vec![&test.env, (test.user.clone(), 100i128)],
+    );
+}
+
+#[test]
+fn clawback_from_frozen_account_during_recall() {
+    let test = WineTokenTest::setup();
+
+    test.token.mint(&test.admin, &test.user, &100);
+    test.token.freeze(&test.user);
+    test.token
+        .set_status(&test.admin, &LotStatus::Recalled, &None);
+
+    test.token.clawback(&test.user, &60);
+
+    assert_eq!(test.token.balance(&test.user), 40);
+    assert_eq!(test.token.total_supply(), 40);
+}
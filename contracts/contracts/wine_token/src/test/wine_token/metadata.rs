@@ -0,0 +1,108 @@
+use soroban_sdk::testutils::{Address as _, Events};
+use soroban_sdk::{symbol_short, vec, Address, FromVal, IntoVal, String};
+
+use crate::events::MetadataUpdatedEvent;
+use crate::models::MetadataChange;
+use crate::test::WineTokenTest;
+use crate::{Role, WineTokenError};
+
+#[test]
+fn update_metadata_bumps_version_and_keeps_history() {
+    let test = WineTokenTest::setup();
+    let original = test.token.get_wine_lot_metadata();
+    assert_eq!(test.token.metadata_version(), 1);
+
+    let mut updated = original.clone();
+    updated.winery_name = String::from_str(&test.env, "Catena Zapata");
+    updated.bottle_count = 950;
+
+    let version = test.token.update_wine_lot_metadata(&test.admin, &updated);
+    assert_eq!(version, 2);
+    assert_eq!(test.token.metadata_version(), 2);
+    assert_eq!(test.token.get_wine_lot_metadata(), updated);
+    assert_eq!(test.token.get_wine_lot_metadata_version(&1), Some(original));
+    assert_eq!(test.token.get_wine_lot_metadata_version(&2), Some(updated));
+    assert_eq!(test.token.get_wine_lot_metadata_version(&3), None);
+    assert_eq!(test.token.max_supply(), 950);
+}
+
+#[test]
+fn update_metadata_emits_typed_diff() {
+    let test = WineTokenTest::setup();
+    let original = test.token.get_wine_lot_metadata();
+
+    let mut updated = original.clone();
+    updated.bottle_count = 990;
+    updated.description = None;
+    test.token.update_wine_lot_metadata(&test.admin, &updated);
+
+    let expected = vec![
+        &test.env,
+        MetadataChange::BottleCount(1000, 990),
+        MetadataChange::Description(original.description, None),
+    ];
+    let event = test.env.events().all().last().unwrap();
+    assert_eq!(
+        event.1,
+        (symbol_short!("metadata"), test.admin.clone()).into_val(&test.env)
+    );
+    let event: MetadataUpdatedEvent = FromVal::from_val(&test.env, &event.2);
+    assert_eq!(event.version, 2);
+    assert_eq!(event.changes, expected);
+}
+
+#[test]
+fn lot_id_and_vintage_are_immutable() {
+    let test = WineTokenTest::setup();
+    let original = test.token.get_wine_lot_metadata();
+
+    let mut updated = original.clone();
+    updated.lot_id = String::from_str(&test.env, "MAL-2024-002");
+    let result = test.token.try_update_wine_lot_metadata(&test.admin, &updated);
+    assert_eq!(result, Err(Ok(WineTokenError::ImmutableMetadataField)));
+
+    let mut updated = original.clone();
+    updated.vintage = 2023;
+    let result = test.token.try_update_wine_lot_metadata(&test.admin, &updated);
+    assert_eq!(result, Err(Ok(WineTokenError::ImmutableMetadataField)));
+}
+
+#[test]
+fn unchanged_metadata_is_rejected() {
+    let test = WineTokenTest::setup();
+    let original = test.token.get_wine_lot_metadata();
+
+    let result = test.token.try_update_wine_lot_metadata(&test.admin, &original);
+    assert_eq!(result, Err(Ok(WineTokenError::MetadataUnchanged)));
+}
+
+#[test]
+fn bottle_count_cannot_drop_below_minted() {
+    let test = WineTokenTest::setup();
+    test.token.mint(&test.admin, &test.user, &900);
+
+    let mut updated = test.token.get_wine_lot_metadata();
+    updated.bottle_count = 899;
+    let result = test.token.try_update_wine_lot_metadata(&test.admin, &updated);
+    assert_eq!(result, Err(Ok(WineTokenError::BottleCountBelowMinted)));
+
+    updated.bottle_count = 900;
+    test.token.update_wine_lot_metadata(&test.admin, &updated);
+    assert_eq!(test.token.max_supply(), 900);
+}
+
+#[test]
+fn metadata_editor_role_required() {
+    let test = WineTokenTest::setup();
+    let editor = Address::generate(&test.env);
+
+    let mut updated = test.token.get_wine_lot_metadata();
+    updated.region = String::from_str(&test.env, "Lujan de Cuyo");
+
+    let result = test.token.try_update_wine_lot_metadata(&editor, &updated);
+    assert_eq!(result, Err(Ok(WineTokenError::Unauthorized)));
+
+    test.token.grant_role(&Role::MetadataEditor, &editor);
+    test.token.update_wine_lot_metadata(&editor, &updated);
+    assert_eq!(test.token.get_wine_lot_metadata().region, updated.region);
+}
@@ -0,0 +1,17 @@
+mod admin;
+mod allowance;
+mod batch;
+mod budget;
+mod compliance;
+mod details;
+mod events;
+mod freeze;
+mod metadata;
+mod relayed_status;
+mod roles;
+mod snapshot;
+mod status;
+mod status_history;
+mod total_supply;
+mod upgrade;
+mod vault;
@@ -0,0 +1,50 @@
+use common::models::LotStatus;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, BytesN, String};
+
+use crate::test::WineTokenTest;
+use crate::{Role, WineTokenError};
+
+#[test]
+fn relayed_status_records_handler_key() {
+    let test = WineTokenTest::setup();
+    let relay = Address::generate(&test.env);
+    let key = BytesN::from_array(&test.env, &[1u8; 32]);
+    let location = Some(String::from_str(&test.env, "Scanner 4"));
+    test.token.grant_role(&Role::StatusUpdater, &relay);
+
+    test.token
+        .set_relayed_status(&relay, &LotStatus::Harvested, &location, &key);
+
+    assert_eq!(test.token.get_status(), Some(LotStatus::Harvested));
+    let record = test.token.get_status_history(&0, &1).get(0).unwrap();
+    assert_eq!(record.handler, relay);
+    assert_eq!(record.handler_key, Some(key));
+    assert_eq!(record.location, location);
+}
+
+#[test]
+fn relay_needs_status_updater_role() {
+    let test = WineTokenTest::setup();
+    let relay = Address::generate(&test.env);
+    let key = BytesN::from_array(&test.env, &[1u8; 32]);
+
+    let result = test
+        .token
+        .try_set_relayed_status(&relay, &LotStatus::Harvested, &None, &key);
+    assert_eq!(result, Err(Ok(WineTokenError::Unauthorized)));
+}
+
+#[test]
+fn relayed_status_follows_lifecycle() {
+    let test = WineTokenTest::setup();
+    let relay = Address::generate(&test.env);
+    let key = BytesN::from_array(&test.env, &[1u8; 32]);
+    test.token.grant_role(&Role::StatusUpdater, &relay);
+
+    let result = test
+        .token
+        .try_set_relayed_status(&relay, &LotStatus::Bottled, &None, &key);
+    assert_eq!(result, Err(Ok(WineTokenError::InvalidStatusTransition)));
+    assert_eq!(test.token.get_status(), None);
+}
@@ -0,0 +1,97 @@
+use common::models::LotStatus;
+use soroban_sdk::testutils::{Address as _, MockAuth, MockAuthInvoke};
+use soroban_sdk::{Address, IntoVal};
+
+use crate::test::WineTokenTest;
+use crate::{Role, WineTokenError};
+
+#[test]
+fn admin_holds_every_role() {
+    let test = WineTokenTest::setup();
+
+    assert!(test.token.has_role(&Role::Admin, &test.admin));
+    assert!(test.token.has_role(&Role::Minter, &test.admin));
+    assert!(test.token.has_role(&Role::StatusUpdater, &test.admin));
+    assert!(test.token.has_role(&Role::Certifier, &test.admin));
+    assert!(!test.token.has_role(&Role::Minter, &test.user));
+}
+
+#[test]
+fn minter_can_mint() {
+    let test = WineTokenTest::setup();
+    let minter = Address::generate(&test.env);
+
+    let result = test.token.try_mint(&minter, &test.user, &10);
+    assert_eq!(result, Err(Ok(WineTokenError::Unauthorized)));
+
+    test.token.grant_role(&Role::Minter, &minter);
+    test.token.mint(&minter, &test.user, &10);
+    assert_eq!(test.token.balance(&test.user), 10);
+
+    // A minter cannot update the lot status
+    let result = test
+        .token
+        .try_set_status(&minter, &LotStatus::Harvested, &None);
+    assert_eq!(result, Err(Ok(WineTokenError::Unauthorized)));
+}
+
+#[test]
+fn status_updater_is_recorded_as_handler() {
+    let test = WineTokenTest::setup();
+    let warehouse = Address::generate(&test.env);
+
+    test.token.grant_role(&Role::StatusUpdater, &warehouse);
+    test.token
+        .set_status(&warehouse, &LotStatus::Harvested, &None);
+
+    let record = test.token.get_status_history(&0, &1).get(0).unwrap();
+    assert_eq!(record.handler, warehouse);
+}
+
+#[test]
+fn revoked_role_loses_access() {
+    let test = WineTokenTest::setup();
+    let minter = Address::generate(&test.env);
+
+    test.token.grant_role(&Role::Minter, &minter);
+    test.token.revoke_role(&Role::Minter, &minter);
+
+    assert!(!test.token.has_role(&Role::Minter, &minter));
+    let result = test.token.try_mint(&minter, &test.user, &10);
+    assert_eq!(result, Err(Ok(WineTokenError::Unauthorized)));
+
+    let result = test.token.try_revoke_role(&Role::Minter, &minter);
+    assert_eq!(result, Err(Ok(WineTokenError::RoleNotFound)));
+}
+
+#[test]
+fn admin_role_is_not_grantable() {
+    let test = WineTokenTest::setup();
+
+    let result = test.token.try_grant_role(&Role::Admin, &test.user);
+    assert_eq!(result, Err(Ok(WineTokenError::RoleNotGrantable)));
+
+    let result = test.token.try_revoke_role(&Role::Admin, &test.admin);
+    assert_eq!(result, Err(Ok(WineTokenError::RoleNotGrantable)));
+}
+
+#[test]
+fn grant_requires_admin_auth() {
+    let test = WineTokenTest::setup();
+    let minter = Address::generate(&test.env);
+
+    let result = test
+        .token
+        .mock_auths(&[MockAuth {
+            address: &minter,
+            invoke: &MockAuthInvoke {
+                contract: &test.token.address,
+                fn_name: "grant_role",
+                args: (Role::Minter, minter.clone()).into_val(&test.env),
+                sub_invokes: &[],
+            },
+        }])
+        .try_grant_role(&Role::Minter, &minter);
+
+    assert!(result.is_err());
+}
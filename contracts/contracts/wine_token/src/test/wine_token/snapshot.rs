@@ -0,0 +1,119 @@
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::Address;
+
+use crate::test::WineTokenTest;
+use crate::WineTokenError;
+
+#[test]
+fn snapshot_ids_increase() {
+    let test = WineTokenTest::setup();
+
+    assert_eq!(test.token.current_snapshot_id(), 0);
+    assert_eq!(test.token.snapshot(), 1);
+    assert_eq!(test.token.snapshot(), 2);
+    assert_eq!(test.token.current_snapshot_id(), 2);
+}
+
+#[test]
+fn unknown_snapshot_is_rejected() {
+    let test = WineTokenTest::setup();
+
+    assert_eq!(
+        test.token.try_balance_of_at(&test.user, &0),
+        Err(Ok(WineTokenError::SnapshotNotFound))
+    );
+    test.token.snapshot();
+    assert_eq!(
+        test.token.try_total_supply_at(&2),
+        Err(Ok(WineTokenError::SnapshotNotFound))
+    );
+}
+
+#[test]
+fn balances_are_frozen_at_snapshot() {
+    let test = WineTokenTest::setup();
+
+    test.token.mint(&test.admin, &test.user, &100);
+    let first = test.token.snapshot();
+
+    test.token.transfer(&test.user, &test.receiver, &30);
+    test.token.mint(&test.admin, &test.receiver, &50);
+    let second = test.token.snapshot();
+
+    test.token.burn(&test.receiver, &20);
+
+    assert_eq!(test.token.balance_of_at(&test.user, &first), 100);
+    assert_eq!(test.token.balance_of_at(&test.receiver, &first), 0);
+    assert_eq!(test.token.total_supply_at(&first), 100);
+
+    assert_eq!(test.token.balance_of_at(&test.user, &second), 70);
+    assert_eq!(test.token.balance_of_at(&test.receiver, &second), 80);
+    assert_eq!(test.token.total_supply_at(&second), 150);
+
+    assert_eq!(test.token.balance(&test.receiver), 60);
+    assert_eq!(test.token.total_supply(), 130);
+}
+
+#[test]
+fn untouched_balances_use_the_live_value() {
+    let test = WineTokenTest::setup();
+    let holder = Address::generate(&test.env);
+
+    test.token.mint(&test.admin, &holder, &40);
+    let first = test.token.snapshot();
+    let second = test.token.snapshot();
+
+    assert_eq!(test.token.balance_of_at(&holder, &first), 40);
+    assert_eq!(test.token.balance_of_at(&holder, &second), 40);
+}
+
+#[test]
+fn skipped_snapshots_resolve_to_the_next_checkpoint() {
+    let test = WineTokenTest::setup();
+
+    test.token.mint(&test.admin, &test.user, &100);
+    let first = test.token.snapshot();
+    let second = test.token.snapshot();
+    let third = test.token.snapshot();
+
+    // Only the first change after `third` is checkpointed
+    test.token.transfer(&test.user, &test.receiver, &10);
+    test.token.transfer(&test.user, &test.receiver, &10);
+
+    assert_eq!(test.token.balance_of_at(&test.user, &first), 100);
+    assert_eq!(test.token.balance_of_at(&test.user, &second), 100);
+    assert_eq!(test.token.balance_of_at(&test.user, &third), 100);
+
+    let fourth = test.token.snapshot();
+    test.token.transfer(&test.user, &test.receiver, &10);
+    assert_eq!(test.token.balance_of_at(&test.user, &third), 100);
+    assert_eq!(test.token.balance_of_at(&test.user, &fourth), 80);
+    assert_eq!(test.token.balance_of_at(&test.receiver, &fourth), 20);
+    assert_eq!(test.token.balance(&test.user), 70);
+}
+
+#[test]
+fn many_snapshots_resolve_and_keep_transfers_flat() {
+    let test = WineTokenTest::setup();
+
+    test.token.mint(&test.admin, &test.user, &1_000);
+    let mut ids = [0u32; 40];
+    for id in ids.iter_mut() {
+        *id = test.token.snapshot();
+        test.token.transfer(&test.user, &test.receiver, &10);
+    }
+    let writes = test.env.cost_estimate().resources().write_entries;
+
+    for _ in 0..60 {
+        test.token.snapshot();
+        test.token.transfer(&test.user, &test.receiver, &10);
+    }
+    assert_eq!(test.env.cost_estimate().resources().write_entries, writes);
+
+    for (n, id) in ids.iter().enumerate() {
+        let sent = 10 * n as i128;
+        assert_eq!(test.token.balance_of_at(&test.user, id), 1_000 - sent);
+        assert_eq!(test.token.balance_of_at(&test.receiver, id), sent);
+    }
+    assert_eq!(test.token.total_supply_at(&ids[39]), 1_000);
+}
@@ -0,0 +1,65 @@
+use common::models::LotStatus;
+use soroban_sdk::String;
+
+use crate::test::WineTokenTest;
+use crate::WineTokenError;
+
+#[test]
+fn full_lifecycle() {
+    let test = WineTokenTest::setup();
+    assert_eq!(test.token.get_status(), None);
+
+    let lifecycle = [
+        LotStatus::Harvested,
+        LotStatus::Fermented,
+        LotStatus::Aged,
+        LotStatus::Bottled,
+        LotStatus::Shipped,
+        LotStatus::Available,
+        LotStatus::SoldOut,
+    ];
+    for status in lifecycle {
+        test.token.set_status(&test.admin, &status, &None);
+        assert_eq!(test.token.get_status(), Some(status));
+    }
+}
+
+#[test]
+fn first_status_must_be_harvested() {
+    let test = WineTokenTest::setup();
+
+    let result = test.token.try_set_status(&test.admin, &LotStatus::Bottled, &None);
+    assert_eq!(result, Err(Ok(WineTokenError::InvalidStatusTransition)));
+    assert_eq!(test.token.get_status(), None);
+}
+
+#[test]
+fn cannot_skip_or_reverse_steps() {
+    let test = WineTokenTest::setup();
+    let location = Some(String::from_str(&test.env, "Mendoza Winery"));
+
+    test.token.set_status(&test.admin, &LotStatus::Harvested, &location);
+    test.token.set_status(&test.admin, &LotStatus::Fermented, &location);
+
+    let skipped = test.token.try_set_status(&test.admin, &LotStatus::Bottled, &None);
+    assert_eq!(skipped, Err(Ok(WineTokenError::InvalidStatusTransition)));
+
+    let reversed = test.token.try_set_status(&test.admin, &LotStatus::Harvested, &None);
+    assert_eq!(reversed, Err(Ok(WineTokenError::InvalidStatusTransition)));
+
+    let repeated = test.token.try_set_status(&test.admin, &LotStatus::Fermented, &None);
+    assert_eq!(repeated, Err(Ok(WineTokenError::InvalidStatusTransition)));
+}
+
+#[test]
+fn recall_from_any_state_is_terminal() {
+    let test = WineTokenTest::setup();
+
+    test.token.set_status(&test.admin, &LotStatus::Harvested, &None);
+    test.token.set_status(&test.admin, &LotStatus::Fermented, &None);
+    test.token.set_status(&test.admin, &LotStatus::Recalled, &None);
+    assert_eq!(test.token.get_status(), Some(LotStatus::Recalled));
+
+    let result = test.token.try_set_status(&test.admin, &LotStatus::Aged, &None);
+    assert_eq!(result, Err(Ok(WineTokenError::InvalidStatusTransition)));
+}
@@ -0,0 +1,81 @@
+use common::models::LotStatus;
+use soroban_sdk::testutils::Ledger;
+use soroban_sdk::String;
+
+use crate::test::WineTokenTest;
+
+#[test]
+fn history_is_empty_initially() {
+    let test = WineTokenTest::setup();
+
+    assert_eq!(test.token.status_count(), 0);
+    assert_eq!(test.token.get_status_history(&0, &10).len(), 0);
+}
+
+#[test]
+fn records_every_status_change() {
+    let test = WineTokenTest::setup();
+    let cellar = Some(String::from_str(&test.env, "Mendoza Cellar"));
+
+    test.env.ledger().with_mut(|li| {
+        li.timestamp = 1_000;
+        li.sequence_number = 10;
+    });
+    test.token.set_status(&test.admin, &LotStatus::Harvested, &None);
+
+    test.env.ledger().with_mut(|li| {
+        li.timestamp = 2_000;
+        li.sequence_number = 20;
+    });
+    test.token.set_status(&test.admin, &LotStatus::Fermented, &cellar);
+
+    assert_eq!(test.token.status_count(), 2);
+
+    let history = test.token.get_status_history(&0, &10);
+    assert_eq!(history.len(), 2);
+
+    let first = history.get(0).unwrap();
+    assert_eq!(first.status, LotStatus::Harvested);
+    assert_eq!(first.location, None);
+    assert_eq!(first.handler, test.admin);
+    assert_eq!(first.timestamp, 1_000);
+    assert_eq!(first.sequence, 10);
+
+    let second = history.get(1).unwrap();
+    assert_eq!(second.status, LotStatus::Fermented);
+    assert_eq!(second.location, cellar);
+    assert_eq!(second.timestamp, 2_000);
+    assert_eq!(second.sequence, 20);
+}
+
+#[test]
+fn history_is_paginated() {
+    let test = WineTokenTest::setup();
+
+    test.token.set_status(&test.admin, &LotStatus::Harvested, &None);
+    test.token.set_status(&test.admin, &LotStatus::Fermented, &None);
+    test.token.set_status(&test.admin, &LotStatus::Aged, &None);
+    test.token.set_status(&test.admin, &LotStatus::Bottled, &None);
+
+    let page = test.token.get_status_history(&1, &2);
+    assert_eq!(page.len(), 2);
+    assert_eq!(page.get(0).unwrap().status, LotStatus::Fermented);
+    assert_eq!(page.get(1).unwrap().status, LotStatus::Aged);
+
+    let tail = test.token.get_status_history(&3, &10);
+    assert_eq!(tail.len(), 1);
+    assert_eq!(tail.get(0).unwrap().status, LotStatus::Bottled);
+
+    assert_eq!(test.token.get_status_history(&4, &10).len(), 0);
+    assert_eq!(test.token.get_status_history(&u32::MAX, &u32::MAX).len(), 0);
+}
+
+#[test]
+fn rejected_transition_is_not_recorded() {
+    let test = WineTokenTest::setup();
+
+    test.token.set_status(&test.admin, &LotStatus::Harvested, &None);
+    let _ = test.token.try_set_status(&test.admin, &LotStatus::SoldOut, &None);
+
+    assert_eq!(test.token.status_count(), 1);
+}
@@ -0,0 +1,63 @@
+use soroban_sdk::{testutils::Address as _, Address, Env};
+
+use crate::test::{create_wine_lot_metadata, create_wine_token, WineTokenTest};
+use crate::WineTokenError;
+
+#[test]
+fn mint_tracks_total_supply() {
+    let test = WineTokenTest::setup();
+
+    test.token.mint(&test.admin, &test.user, &400);
+    test.token.mint(&test.admin, &test.receiver, &100);
+
+    assert_eq!(test.token.total_supply(), 500);
+    assert_eq!(test.token.max_supply(), 1000);
+}
+
+#[test]
+fn burn_reduces_total_supply() {
+    let test = WineTokenTest::setup();
+
+    test.token.mint(&test.admin, &test.user, &400);
+    test.token.burn(&test.user, &150);
+
+    assert_eq!(test.token.total_supply(), 250);
+}
+
+#[test]
+fn mint_up_to_cap() {
+    let test = WineTokenTest::setup();
+
+    test.token.mint(&test.admin, &test.user, &1000);
+
+    let result = test.token.try_mint(&test.admin, &test.user, &1);
+    assert_eq!(result, Err(Ok(WineTokenError::SupplyCapExceeded)));
+    assert_eq!(test.token.total_supply(), 1000);
+}
+
+#[test]
+fn burned_tokens_do_not_free_cap() {
+    let test = WineTokenTest::setup();
+
+    test.token.mint(&test.admin, &test.user, &1000);
+    test.token.burn(&test.user, &10);
+
+    let result = test.token.try_mint(&test.admin, &test.user, &10);
+    assert_eq!(result, Err(Ok(WineTokenError::SupplyCapExceeded)));
+}
+
+#[test]
+fn cap_scales_with_decimals() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let user = Address::generate(&e);
+    let token = create_wine_token(&e, &admin, 7, create_wine_lot_metadata(&e, 12));
+
+    assert_eq!(token.max_supply(), 12 * 10_000_000);
+    token.mint(&admin, &user, &(12 * 10_000_000));
+
+    let result = token.try_mint(&admin, &user, &1);
+    assert_eq!(result, Err(Ok(WineTokenError::SupplyCapExceeded)));
+}
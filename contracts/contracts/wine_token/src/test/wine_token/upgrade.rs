@@ -0,0 +1,103 @@
+use common::models::LotStatus;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, BytesN};
+
+use crate::storage::DataKey;
+use crate::test::WineTokenTest;
+use crate::upgrade::CODE_VERSION;
+use crate::{Role, WineTokenError};
+
+// Rewinds the token's recorded code version
+fn set_code_version(test: &WineTokenTest, version: Option<u32>) {
+    test.env.as_contract(&test.token.address, || {
+        let storage = test.env.storage().instance();
+        match version {
+            Some(version) => storage.set(&DataKey::CodeVersion, &version),
+            None => storage.remove(&DataKey::CodeVersion),
+        }
+    });
+}
+
+#[test]
+fn new_tokens_record_code_version() {
+    let test = WineTokenTest::setup();
+
+    assert_eq!(test.token.code_version(), CODE_VERSION);
+}
+
+#[test]
+fn migrate_is_a_no_op_when_current() {
+    let test = WineTokenTest::setup();
+
+    assert_eq!(test.token.migrate(&test.admin), CODE_VERSION);
+    assert_eq!(test.token.code_version(), CODE_VERSION);
+}
+
+#[test]
+fn baseline_tokens_have_no_migration() {
+    let test = WineTokenTest::setup();
+    set_code_version(&test, None);
+    assert_eq!(test.token.code_version(), 1);
+
+    assert_eq!(
+        test.token.try_migrate(&test.admin),
+        Err(Ok(WineTokenError::UnsupportedMigration))
+    );
+    assert_eq!(test.token.code_version(), 1);
+}
+
+#[test]
+fn newer_versions_are_not_migrated_back() {
+    let test = WineTokenTest::setup();
+    set_code_version(&test, Some(CODE_VERSION + 1));
+
+    assert_eq!(
+        test.token.try_migrate(&test.admin),
+        Err(Ok(WineTokenError::UnsupportedMigration))
+    );
+}
+
+#[test]
+fn recalled_tokens_migrate_to_paused() {
+    let test = WineTokenTest::setup();
+    test.token.set_status(&test.admin, &LotStatus::Recalled, &None);
+
+    // Rewind to version 2, which derived the pause from the status alone
+    set_code_version(&test, Some(2));
+    test.env.as_contract(&test.token.address, || {
+        test.env.storage().instance().remove(&DataKey::Paused);
+    });
+    assert!(!test.token.is_paused());
+
+    assert_eq!(test.token.migrate(&test.admin), CODE_VERSION);
+    assert!(test.token.is_paused());
+}
+
+#[test]
+fn upgrade_requires_upgrader_role() {
+    let test = WineTokenTest::setup();
+    let factory = Address::generate(&test.env);
+    let wasm_hash = BytesN::from_array(&test.env, &[1u8; 32]);
+
+    assert_eq!(
+        test.token.try_upgrade(&factory, &wasm_hash),
+        Err(Ok(WineTokenError::Unauthorized))
+    );
+    assert_eq!(
+        test.token.try_migrate(&factory),
+        Err(Ok(WineTokenError::Unauthorized))
+    );
+
+    test.token.grant_role(&Role::Upgrader, &factory);
+    assert_eq!(test.token.migrate(&factory), CODE_VERSION);
+}
+
+#[test]
+#[should_panic(expected = "Error(Storage, MissingValue)")]
+fn upgrade_to_unknown_wasm_fails() {
+    let test = WineTokenTest::setup();
+
+    // Native tests can't upload WASM, so the switch itself fails in the host
+    test.token
+        .upgrade(&test.admin, &BytesN::from_array(&test.env, &[1u8; 32]));
+}
@@ -0,0 +1,53 @@
+use soroban_sdk::testutils::{Address as _, MockAuth, MockAuthInvoke};
+use soroban_sdk::{Address, IntoVal};
+
+use crate::test::WineTokenTest;
+
+#[test]
+fn vault_is_unset_by_default() {
+    let test = WineTokenTest::setup();
+
+    assert_eq!(test.token.vault(), None);
+}
+
+#[test]
+fn admin_links_vault() {
+    let test = WineTokenTest::setup();
+    let vault = Address::generate(&test.env);
+
+    test.token.set_vault(&vault);
+    assert_eq!(test.token.vault(), Some(vault));
+}
+
+#[test]
+fn linking_again_replaces_vault() {
+    let test = WineTokenTest::setup();
+    let first = Address::generate(&test.env);
+    let second = Address::generate(&test.env);
+
+    test.token.set_vault(&first);
+    test.token.set_vault(&second);
+    assert_eq!(test.token.vault(), Some(second));
+}
+
+#[test]
+fn set_vault_requires_admin_auth() {
+    let test = WineTokenTest::setup();
+    let vault = Address::generate(&test.env);
+
+    let result = test
+        .token
+        .mock_auths(&[MockAuth {
+            address: &test.user,
+            invoke: &MockAuthInvoke {
+                contract: &test.token.address,
+                fn_name: "set_vault",
+                args: (vault.clone(),).into_val(&test.env),
+                sub_invokes: &[],
+            },
+        }])
+        .try_set_vault(&vault);
+
+    assert!(result.is_err());
+    assert_eq!(test.token.vault(), None);
+}
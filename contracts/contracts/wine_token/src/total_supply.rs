@@ -0,0 +1,56 @@
+use soroban_sdk::Env;
+
+use crate::snapshot::checkpoint_total_supply;
+use crate::storage::{read_metadata, read_wine_lot_metadata, DataKey};
+
+/// Maximum number of tokens that can ever be minted: one whole token per bottle.
+pub fn read_max_supply(e: &Env) -> i128 {
+    max_supply_for(e, read_wine_lot_metadata(e).bottle_count)
+}
+
+pub fn max_supply_for(e: &Env, bottle_count: u32) -> i128 {
+    let unit = 10i128.pow(read_metadata(e).decimal);
+    (bottle_count as i128)
+        .checked_mul(unit)
+        .expect("Integer overflow occurred while computing max supply.")
+}
+
+pub fn read_total_supply(e: &Env) -> i128 {
+    let key = DataKey::TotalSupply;
+    e.storage().instance().get(&key).unwrap_or(0)
+}
+
+fn write_total_supply(e: &Env, amount: &i128) {
+    checkpoint_total_supply(e, read_total_supply(e));
+    let key = DataKey::TotalSupply;
+    e.storage().instance().set(&key, amount);
+}
+
+pub fn read_total_minted(e: &Env) -> i128 {
+    let key = DataKey::TotalMinted;
+    e.storage().instance().get(&key).unwrap_or(0)
+}
+
+/// Records a mint in both the circulating supply and the cumulative minted amount.
+pub fn increase_total_supply(e: &Env, amount: i128) {
+    let total_supply = read_total_supply(e);
+    let new_total_supply = total_supply
+        .checked_add(amount)
+        .expect("Integer overflow occurred while increasing total supply.");
+    write_total_supply(e, &new_total_supply);
+
+    let total_minted = read_total_minted(e)
+        .checked_add(amount)
+        .expect("Integer overflow occurred while increasing total minted.");
+    e.storage()
+        .instance()
+        .set(&DataKey::TotalMinted, &total_minted);
+}
+
+pub fn decrease_total_supply(e: &Env, amount: i128) {
+    let total_supply = read_total_supply(e);
+    if total_supply < amount {
+        panic!("insufficient total supply");
+    }
+    write_total_supply(e, &(total_supply - amount));
+}
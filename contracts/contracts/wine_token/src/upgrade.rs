@@ -0,0 +1,66 @@
+use common::models::LotStatus;
+use soroban_sdk::{panic_with_error, Env};
+
+use crate::error::WineTokenError;
+use crate::freeze::write_paused;
+use crate::storage::{read_status, DataKey};
+
+/// Storage layout version of this build. Bump it together with a new entry in
+/// `MIGRATIONS` whenever stored data needs rewriting after an upgrade.
+pub const CODE_VERSION: u32 = 3;
+
+/// Version of the tokens deployed before `upgrade` existed. They can't switch
+/// to new code at all, so no migration starts from it; those lots have to be
+/// redeployed through the factory.
+const BASELINE_VERSION: u32 = 1;
+
+/// Code version whose storage layout the token currently holds. Every token
+/// with an `upgrade` entrypoint records one at construction.
+pub fn read_code_version(e: &Env) -> u32 {
+    e.storage()
+        .instance()
+        .get(&DataKey::CodeVersion)
+        .unwrap_or(BASELINE_VERSION)
+}
+
+pub fn write_code_version(e: &Env, version: u32) {
+    e.storage().instance().set(&DataKey::CodeVersion, &version);
+}
+
+/// Version 2 paused transfers whenever the lot was recalled. Version 3 keeps
+/// an admin-controlled pause flag, which lots recalled before the upgrade start with.
+fn migrate_v2_to_v3(e: &Env) {
+    if read_status(e) == Some(LotStatus::Recalled) {
+        write_paused(e, true);
+    }
+}
+
+/// A migration and the version it starts from; it brings stored data to the
+/// next version.
+type Migration = (u32, fn(&Env));
+
+/// Data migrations keyed by the version they start from.
+const MIGRATIONS: [Migration; 1] = [(2, migrate_v2_to_v3)];
+
+/// Runs every pending migration step and records the new version.
+///
+/// # Returns
+/// * The code version the token was migrated from
+///
+/// # Panics
+/// * `WineTokenError::UnsupportedMigration` - If the stored version is newer than
+///   `CODE_VERSION`, or a version on the way has no migration
+pub fn run_migrations(e: &Env) -> u32 {
+    let from = read_code_version(e);
+    if from > CODE_VERSION {
+        panic_with_error!(e, WineTokenError::UnsupportedMigration);
+    }
+    for version in from..CODE_VERSION {
+        match MIGRATIONS.iter().find(|(start, _)| *start == version) {
+            Some((_, migrate)) => migrate(e),
+            None => panic_with_error!(e, WineTokenError::UnsupportedMigration),
+        }
+    }
+    write_code_version(e, CODE_VERSION);
+    from
+}
@@ -19,6 +19,24 @@ echo "Factory ID: $WINE_FACTORY_ID"
 echo "Admin: $ADMIN_ADDRESS"
 echo ""
 
+# Register and verify the winery (fails harmlessly if already registered)
+echo "Registering winery..."
+stellar contract invoke \
+  --id "$WINE_FACTORY_ID" \
+  --source-account "$ACCOUNT_NAME" \
+  --network "$NETWORK" \
+  -- register_winery \
+  --winery "$ADMIN_ADDRESS" \
+  --name "Bodega Catena Zapata" \
+  --country "Argentina" 2>/dev/null || true
+stellar contract invoke \
+  --id "$WINE_FACTORY_ID" \
+  --source-account "$ACCOUNT_NAME" \
+  --network "$NETWORK" \
+  -- verify_winery \
+  --winery "$ADMIN_ADDRESS" 2>/dev/null || true
+echo ""
+
 # Create the wine token
 echo "Creating Malbec Reserve 2024 token..."
 echo ""
@@ -29,6 +47,7 @@ INVOKE_OUTPUT=$(stellar contract invoke \
   --source-account "$ACCOUNT_NAME" \
   --network "$NETWORK" \
   -- create_wine_token \
+  --creator "$ADMIN_ADDRESS" \
   --admin "$ADMIN_ADDRESS" \
   --decimal 0 \
   --name "Malbec Reserve 2024" \
@@ -94,6 +113,7 @@ echo "  --id $TOKEN_ADDRESS \\"
 echo "  --source-account $ACCOUNT_NAME \\"
 echo "  --network $NETWORK \\"
 echo "  -- mint \\"
+echo "  --minter \$(stellar keys address $ACCOUNT_NAME) \\"
 echo "  --to <BUYER_ADDRESS> \\"
 echo "  --amount 100"
 echo ""
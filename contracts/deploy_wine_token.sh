@@ -175,6 +175,7 @@ echo "  --id $FACTORY_ID \\"
 echo "  --source-account $ACCOUNT_NAME \\"
 echo "  --network $NETWORK \\"
 echo "  -- create_wine_token \\"
+echo "  --creator $ADMIN_ADDRESS \\"
 echo "  --admin $ADMIN_ADDRESS \\"
 echo "  --decimal 0 \\"
 echo "  --name \"Malbec Reserve 2024\" \\"
@@ -199,7 +200,8 @@ echo "  --id \$TOKEN_ADDRESS \\"
 echo "  --source-account $ACCOUNT_NAME \\"
 echo "  --network $NETWORK \\"
 echo "  -- set_status \\"
-echo "  --status \"harvested\""
+echo "  --updater \$(stellar keys address $ACCOUNT_NAME) \\"
+echo "  --status Harvested"
 echo ""
 echo "# Get wine lot status (NEW - read from chain, use TOKEN_ADDRESS):"
 echo "stellar contract invoke \\"
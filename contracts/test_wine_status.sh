@@ -87,7 +87,8 @@ stellar contract invoke \
   --source-account "$ACCOUNT_NAME" \
   --network "$NETWORK" \
   -- set_status \
-  --status "harvested"
+  --updater "$(stellar keys address "$ACCOUNT_NAME")" \
+  --status Harvested
 
 echo ""
 echo -e "${GREEN}✓ Status set to 'harvested'${NC}"
@@ -121,7 +122,8 @@ stellar contract invoke \
   --source-account "$ACCOUNT_NAME" \
   --network "$NETWORK" \
   -- set_status \
-  --status "fermented"
+  --updater "$(stellar keys address "$ACCOUNT_NAME")" \
+  --status Fermented
 
 echo ""
 echo -e "${GREEN}✓ Status updated to 'fermented'${NC}"
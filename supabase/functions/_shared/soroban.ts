@@ -3,7 +3,7 @@
 // Using esm.sh for ESM compatibility with ?bundle flag to ensure all exports are available
 // Based on error logs, esm.sh exports: rpc (SorobanRpc namespace), contract (Contract class), default (main SDK)
 import * as StellarSdkModule from "https://esm.sh/@stellar/stellar-sdk@14.2.0?bundle";
-import { BASE_FEE, getStellarNetwork } from "./utils.ts";
+import { BASE_FEE, getStellarNetwork, LotStatus } from "./utils.ts";
 
 // esm.sh exports: { default: SDK, rpc: SorobanRpc namespace, contract: Contract class, ... }
 // Access the default export for main SDK components
@@ -152,15 +152,50 @@ export function addressVal(value: string) {
 }
 
 export function optionStringVal(value: string | null | undefined) {
-  // Option<String> in Soroban is represented as ScVal.scvVec
-  // None = empty vec, Some(value) = vec with one element
+  // Option<String> in Soroban is represented as ScVal.scvVoid for None
+  // and as the plain string value for Some(value). Used for the `location`
+  // argument of WineToken::set_status and the `location` field of the status
+  // relay's SignedStatusPayload, both declared as Option<String>.
   if (value === null || value === undefined) {
-    // None - empty vector
-    return xdr.ScVal.scvVec([]);
-  } else {
-    // Some(value) - vector with one string element
-    return xdr.ScVal.scvVec([stringVal(value)]);
+    return xdr.ScVal.scvVoid();
+  }
+  return stringVal(value);
+}
+
+// LotStatus variant names in the contracts, keyed by the status names stored in the database
+const LOT_STATUS_VARIANTS: Record<LotStatus, string> = {
+  harvested: "Harvested",
+  fermented: "Fermented",
+  aged: "Aged",
+  bottled: "Bottled",
+  shipped: "Shipped",
+  available: "Available",
+  sold_out: "SoldOut",
+  recalled: "Recalled",
+};
+
+/**
+ * Whether `status` names a LotStatus the contracts accept, so callers can
+ * reject bad input before building a transaction
+ */
+export function isLotStatus(status: unknown): status is LotStatus {
+  return typeof status === "string" && Object.hasOwn(LOT_STATUS_VARIANTS, status);
+}
+
+export function lotStatusVal(status: string) {
+  // Unit enum variants are represented as a vec holding the variant symbol
+  const variant = LOT_STATUS_VARIANTS[status.toLowerCase() as LotStatus];
+  if (!variant) {
+    throw new Error(
+      `Unsupported lot status "${status}". Expected one of ${Object.keys(LOT_STATUS_VARIANTS).join(", ")}`,
+    );
   }
+  return xdr.ScVal.scvVec([xdr.ScVal.scvSymbol(variant)]);
+}
+
+function lotStatusFromVariant(variant: string): string {
+  const entry = Object.entries(LOT_STATUS_VARIANTS).find(([, v]) => v === variant);
+  return entry ? entry[0] : variant;
 }
 
 export function eventTypeVal(event: string) {
@@ -386,7 +421,9 @@ function objectToScValMap(obj: Record<string, any>): any {
 }
 
 /**
- * Create a new wine token via the factory contract
+ * Create a new wine token via the factory contract.
+ * The signer must be the winery (`tokenAdmin`) or one of its registered creators.
+ * Pass a `complianceRegistry` address to restrict the lot to its allowlist.
  */
 export async function createWineToken(
   factoryId: string,
@@ -396,6 +433,7 @@ export async function createWineToken(
   name: string,
   symbol: string,
   metadata: WineLotMetadata,
+  complianceRegistry: string | null = null,
 ): Promise<{ hash: string; tokenAddress: string }> {
   // Convert metadata object to ScVal map manually
   const metadataScVal = objectToScValMap({
@@ -414,11 +452,13 @@ export async function createWineToken(
     contractId: factoryId,
     method: "create_wine_token",
     args: [
+      addressVal(Keypair.fromSecret(adminSecret).publicKey()),
       addressVal(tokenAdmin),
       u32Val(decimal),
       stringVal(name),
       stringVal(symbol),
       metadataScVal,
+      complianceRegistry ? addressVal(complianceRegistry) : xdr.ScVal.scvVoid(),
     ],
     signerSecret: adminSecret,
   });
@@ -458,6 +498,8 @@ export async function mintWineTokens(
     contractId: tokenAddress,
     method: "mint",
     args: [
+      // The signer authorizes the mint as admin or Minter
+      addressVal(Keypair.fromSecret(adminSecret).publicKey()),
       addressVal(recipientAddress),
       amountScVal,
     ],
@@ -596,23 +638,26 @@ export async function getTokenSymbol(
  * This creates an immutable blockchain record of the status change
  * 
  * The contract method signature is:
- * set_status(status: String, location: Option<String>, previous_status: Option<String>)
+ * set_status(updater: Address, status: LotStatus, location: Option<String>)
+ *
+ * The contract enforces the lot lifecycle and derives the previous status
+ * from its own storage, so invalid transitions are rejected on-chain.
  */
 export async function updateLotStatusOnChain(
   tokenAddress: string,
   adminSecret: string,
   status: string,
   location?: string,
-  previousStatus?: string,
 ): Promise<{ hash: string }> {
   try {
     const result = await invokeSorobanContract({
       contractId: tokenAddress,
       method: "set_status",
       args: [
-        stringVal(status),
+        // The signer authorizes the update as admin or StatusUpdater
+        addressVal(Keypair.fromSecret(adminSecret).publicKey()),
+        lotStatusVal(status),
         optionStringVal(location),
-        optionStringVal(previousStatus),
       ],
       signerSecret: adminSecret,
     });
@@ -639,17 +684,16 @@ export async function getLotStatusFromChain(
       signerSecret: anySecret,
     });
 
-    // get_status returns Option<String>, which is a Vec
-    // If empty vec, return null (None)
-    // If vec has one element, return that string (Some(value))
+    // get_status returns Option<LotStatus>
+    // None decodes to null/undefined, Some(variant) decodes to ["Variant"]
     const returnValue = result.returnValue;
     if (!returnValue || (Array.isArray(returnValue) && returnValue.length === 0)) {
       return null;
     }
     if (Array.isArray(returnValue) && returnValue.length > 0) {
-      return returnValue[0] as string;
+      return lotStatusFromVariant(returnValue[0] as string);
     }
-    return returnValue as string | null;
+    return lotStatusFromVariant(returnValue as string);
   } catch (error) {
     console.error("Failed to get status from chain:", error);
     throw error;
@@ -665,9 +709,90 @@ export async function emitLotStatusEvent(
   adminSecret: string,
   status: string,
   location?: string,
-  previousStatus?: string,
 ): Promise<{ hash: string }> {
-  return updateLotStatusOnChain(tokenAddress, adminSecret, status, location, previousStatus);
+  return updateLotStatusOnChain(tokenAddress, adminSecret, status, location);
+}
+
+/**
+ * Build the SignedStatusPayload struct accepted by the status relay's submit_signed_status
+ *
+ * Structs are encoded as a map keyed by field name, with keys in sorted order.
+ */
+export function signedStatusPayloadVal(
+  relayAddress: string,
+  status: string,
+  location: string | undefined,
+  nonce: bigint,
+) {
+  const field = (name: string, val: any) =>
+    new xdr.ScMapEntry({ key: xdr.ScVal.scvSymbol(name), val });
+  return xdr.ScVal.scvMap([
+    field("location", optionStringVal(location)),
+    field("nonce", xdr.ScVal.scvU64(xdr.Uint64.fromString(nonce.toString()))),
+    field("relay", addressVal(relayAddress)),
+    field("status", lotStatusVal(status)),
+  ]);
+}
+
+/**
+ * Get the nonce the next signed status update from a handler key must carry
+ */
+export async function getHandlerNonce(
+  relayAddress: string,
+  anySecret: string,
+  handlerPublicKey: Uint8Array,
+): Promise<bigint> {
+  const result = await invokeSorobanContract({
+    contractId: relayAddress,
+    method: "handler_nonce",
+    args: [xdr.ScVal.scvBytes(handlerPublicKey)],
+    signerSecret: anySecret,
+  });
+  return BigInt(result.returnValue as any);
+}
+
+/**
+ * Submit a status update signed by a registered handler key
+ *
+ * The handler key only signs the payload; the relayer pays for and submits the
+ * transaction, so handlers do not need a funded Stellar account. Updates go
+ * through the lot's StatusRelay contract, which holds the handler keys and
+ * records the status on the lot token.
+ *
+ * The contract method signature is:
+ * submit_signed_status(payload: SignedStatusPayload, public_key: BytesN<32>, signature: BytesN<64>)
+ */
+export async function submitSignedStatusOnChain(
+  relayAddress: string,
+  relayerSecret: string,
+  handlerSecret: string,
+  status: string,
+  location?: string,
+): Promise<{ hash: string }> {
+  try {
+    const handler = Keypair.fromSecret(handlerSecret);
+    const publicKey = handler.rawPublicKey();
+    const nonce = await getHandlerNonce(relayAddress, relayerSecret, publicKey);
+
+    const payload = signedStatusPayloadVal(relayAddress, status, location, nonce);
+    const signature = handler.sign(payload.toXDR());
+
+    const result = await invokeSorobanContract({
+      contractId: relayAddress,
+      method: "submit_signed_status",
+      args: [
+        payload,
+        xdr.ScVal.scvBytes(publicKey),
+        xdr.ScVal.scvBytes(signature),
+      ],
+      signerSecret: relayerSecret,
+    });
+
+    return { hash: result.hash };
+  } catch (error) {
+    console.error("Signed status submission failed:", error);
+    throw error;
+  }
 }
 
 // ============================================================================
@@ -8,7 +8,7 @@ import {
   LOT_STATUS,
   LotStatus,
 } from "../_shared/utils.ts";
-import { updateLotStatusOnChain } from "../_shared/soroban.ts";
+import { isLotStatus, updateLotStatusOnChain } from "../_shared/soroban.ts";
 
 interface StatusUpdateRequest {
   token_id?: string;
@@ -91,9 +91,10 @@ Deno.serve(async (req: Request) => {
       );
     }
 
-    // Validate status value
-    const validStatuses = Object.values(LOT_STATUS);
-    if (!validStatuses.includes(status)) {
+    // Validate status value against the statuses the contract accepts, so bad
+    // input is rejected here instead of failing the on-chain call
+    if (!isLotStatus(status)) {
+      const validStatuses = Object.values(LOT_STATUS);
       return new Response(
         JSON.stringify({
           error: `Invalid status. Must be one of: ${validStatuses.join(", ")}`,
@@ -105,6 +106,17 @@ Deno.serve(async (req: Request) => {
       );
     }
 
+    // The contract takes the location as Option<String>
+    if (location !== undefined && location !== null && typeof location !== "string") {
+      return new Response(
+        JSON.stringify({ error: "location must be a string" }),
+        {
+          status: 400,
+          headers: { ...corsHeaders, "Content-Type": "application/json" },
+        },
+      );
+    }
+
     // Look up token - support both token_id (UUID) and token_address (contract address)
     let tokenData;
     let tokenError;
@@ -200,27 +212,48 @@ Deno.serve(async (req: Request) => {
 
     const previous_status = previousEvent?.status || null;
 
-    // Update status on-chain for immutable proof
-    let transaction_hash = null;
+    // Update status on-chain first; the contract enforces lifecycle transitions,
+    // so only record the event once the chain has accepted it
+    if (!adminWalletSecret) {
+      return new Response(
+        JSON.stringify({
+          error: "Admin wallet secret not available to sign the status update",
+        }),
+        {
+          status: 400,
+          headers: { ...corsHeaders, "Content-Type": "application/json" },
+        },
+      );
+    }
 
-    if (adminWalletSecret && tokenData.token_address) {
-      try {
-        const { hash } = await updateLotStatusOnChain(
-          tokenData.token_address,
-          adminWalletSecret,
-          status,
-          location || undefined,
-          previous_status || undefined,
-        );
-        transaction_hash = hash;
-        console.log(`Status updated on-chain: ${hash}`);
-      } catch (blockchainError) {
-        console.error("Blockchain status update failed:", blockchainError);
-        // Continue anyway - database record is primary, blockchain is proof
-        // But log the error for debugging
-      }
-    } else {
-      console.warn("Cannot update status on-chain: missing admin wallet secret or token address");
+    let transaction_hash: string;
+    try {
+      const { hash } = await updateLotStatusOnChain(
+        tokenData.token_address,
+        adminWalletSecret,
+        status,
+        location || undefined,
+      );
+      transaction_hash = hash;
+      console.log(`Status updated on-chain: ${hash}`);
+    } catch (blockchainError) {
+      console.error("Blockchain status update failed:", blockchainError);
+      const message = blockchainError instanceof Error
+        ? blockchainError.message
+        : String(blockchainError);
+      // WineTokenError::InvalidStatusTransition
+      const invalidTransition = message.includes("Error(Contract, #100)");
+      return new Response(
+        JSON.stringify({
+          error: invalidTransition
+            ? `Invalid status transition from ${previous_status ?? "none"} to ${status}`
+            : "Failed to update status on-chain",
+        }),
+        {
+          status: invalidTransition ? 409 : 502,
+          headers: { ...corsHeaders, "Content-Type": "application/json" },
+        },
+      );
     }
 
     // Insert status event into database